@@ -0,0 +1,87 @@
+pub fn format_number(number: i32) -> String {
+    let chars = number.to_string();
+    let mut chars = chars.chars().rev();
+    let mut reverse = (0..)
+        .map(|_| chars.by_ref().take(3).collect::<String>())
+        .take_while(|s| !s.is_empty())
+        .map(|s| s.chars().rev().collect::<String>())
+        .collect::<Vec<_>>();
+    reverse.reverse();
+
+    reverse.join(",")
+}
+
+pub fn find_floor(instructions: String) -> i32 {
+    let mut floor = 0;
+    for line in instructions.lines() {
+        for ch in line.chars() {
+            match ch {
+                '(' => floor += 1,
+                ')' => floor -= 1,
+                _ => panic!("Invalid character: {}", ch),
+            }
+        }
+    }
+    floor
+}
+
+pub fn get_part_one(input: &str) -> i32 {
+    find_floor(input.to_owned())
+}
+
+pub fn get_part_two(input: &str) -> Result<usize, String> {
+    input
+        .trim()
+        .chars()
+        .scan(0, |floor, ch| {
+            *floor += match ch {
+                '(' => 1,
+                ')' => -1,
+                _ => 0,
+            };
+            Some(*floor)
+        })
+        .position(|floor| floor == -1)
+        .map(|position| position + 1)
+        .ok_or_else(|| "Santa never enters the basement".to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zero() {
+        assert_eq!(find_floor(String::from("(())")), 0);
+        assert_eq!(find_floor(String::from("()()")), 0);
+    }
+
+    #[test]
+    fn test_three() {
+        assert_eq!(find_floor(String::from("(((")), 3);
+        assert_eq!(find_floor(String::from("(()(()(")), 3);
+        assert_eq!(find_floor(String::from("))(((((")), 3);
+    }
+
+    #[test]
+    fn test_minus_one() {
+        assert_eq!(find_floor(String::from("())")), -1);
+        assert_eq!(find_floor(String::from("))(")), -1);
+    }
+
+    #[test]
+    fn test_minus_three() {
+        assert_eq!(find_floor(String::from(")))")), -3);
+        assert_eq!(find_floor(String::from(")())())")), -3);
+    }
+
+    #[test]
+    fn test_format_number() {
+        assert_eq!(format_number(1), "1");
+        assert_eq!(format_number(123), "123");
+        assert_eq!(format_number(1234), "1,234");
+        assert_eq!(format_number(123456), "123,456");
+        assert_eq!(format_number(12345678), "12,345,678");
+        assert_eq!(format_number(1234567890), "1,234,567,890");
+    }
+}
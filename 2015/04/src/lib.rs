@@ -0,0 +1,33 @@
+fn get_nonce(secret_key: String, prefix_count: usize) -> usize {
+    for nonce in 1.. {
+        let input = format!("{}{}", &secret_key, nonce.to_string().as_str());
+        let digest = format!("{:x}", md5::compute(input.as_bytes()));
+        if digest.starts_with(&"0".repeat(prefix_count)) {
+            return nonce;
+        }
+    }
+    0
+}
+
+pub fn get_part_one(input: &str) -> usize {
+    get_nonce(String::from(input.trim()), 5)
+}
+
+pub fn get_part_two(input: &str) -> usize {
+    get_nonce(String::from(input.trim()), 6)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_abcdef() {
+        assert_eq!(get_nonce(String::from("abcdef"), 5), 609043);
+    }
+
+    #[test]
+    fn test_pqrstuv() {
+        assert_eq!(get_nonce(String::from("pqrstuv"), 5), 1048970);
+    }
+}
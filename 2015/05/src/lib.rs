@@ -0,0 +1,97 @@
+use fancy_regex::Regex as FRegex;
+use regex::Regex;
+
+fn is_string_nice(string: &str) -> bool {
+    let vowels = Regex::new(r"[aeiou]").unwrap();
+    let disallowed = FRegex::new(r"(ab|cd|pq|xy)").unwrap();
+    let twice_in_a_row = FRegex::new(r"(.)\1").unwrap();
+
+    vowels.find_iter(string).count() >= 3
+        && disallowed.find(string).unwrap().is_none()
+        && twice_in_a_row.find(string).unwrap().is_some()
+}
+
+fn is_string_nice_new_rules(string: &str) -> bool {
+    let two_letters_repeated = FRegex::new(r"(..).*\1").unwrap();
+    let one_letter_repeated_with_one_between = FRegex::new(r"(.).\1").unwrap();
+
+    two_letters_repeated.find(string).unwrap().is_some()
+        && one_letter_repeated_with_one_between
+            .find(string)
+            .unwrap()
+            .is_some()
+}
+
+fn count_nice_strings(input: &str) -> usize {
+    input
+        .lines()
+        .map(|line| is_string_nice(line))
+        .filter(|nn| *nn)
+        .count()
+}
+
+fn count_nice_strings_new_rules(input: String) -> usize {
+    input
+        .lines()
+        .map(|line| is_string_nice_new_rules(line))
+        .filter(|nn| *nn)
+        .count()
+}
+
+pub fn get_part_one(input: &str) -> usize {
+    count_nice_strings(input)
+}
+
+pub fn get_part_two(input: &str) -> usize {
+    count_nice_strings_new_rules(input.to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ugknbfddgicrmopn() {
+        assert_eq!(is_string_nice("ugknbfddgicrmopn"), true);
+    }
+
+    #[test]
+    fn test_aaa() {
+        assert_eq!(is_string_nice("aaa"), true);
+    }
+
+    #[test]
+    fn test_jchzalrnumimnmhp() {
+        assert_eq!(is_string_nice("jchzalrnumimnmhp"), false);
+    }
+
+    #[test]
+    fn test_haegwjzuvuyypxyu() {
+        assert_eq!(is_string_nice("haegwjzuvuyypxyu"), false);
+    }
+
+    #[test]
+    fn test_dvszwmarrgswjxmb() {
+        assert_eq!(is_string_nice("dvszwmarrgswjxmb"), false);
+    }
+
+    #[test]
+    fn test_qjhvhtzxzqqjkmpb() {
+        assert_eq!(is_string_nice_new_rules("qjhvhtzxzqqjkmpb"), true);
+    }
+
+    #[test]
+    fn test_xxyxx() {
+        assert_eq!(is_string_nice_new_rules("xxyxx"), true);
+    }
+
+    #[test]
+    fn test_uurcxstgmygtbstg() {
+        assert_eq!(is_string_nice_new_rules("uurcxstgmygtbstg"), false);
+    }
+
+    #[test]
+    fn test_ieodomkazucvgmuy() {
+        assert_eq!(is_string_nice_new_rules("ieodomkazucvgmuy"), false);
+    }
+}
@@ -0,0 +1,57 @@
+use itertools::Itertools;
+
+fn look_and_say(source: &str) -> String {
+    source
+        .chars()
+        .group_by(|&c| c)
+        .into_iter()
+        .map(|(c, group)| format!("{}{}", group.count(), c))
+        .collect()
+}
+
+fn get_length_after(input: &str, times: usize) -> usize {
+    let mut output = String::from(input.trim());
+    for _ in 0..times {
+        output = look_and_say(&output);
+    }
+
+    output.len()
+}
+
+pub fn get_part_one(input: &str) -> usize {
+    get_length_after(input, 40)
+}
+
+pub fn get_part_two(input: &str) -> usize {
+    get_length_after(input, 50)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_1() {
+        assert_eq!("11", look_and_say("1"));
+    }
+
+    #[test]
+    fn test_11() {
+        assert_eq!("21", look_and_say("11"));
+    }
+
+    #[test]
+    fn test_21() {
+        assert_eq!("1211", look_and_say("21"));
+    }
+
+    #[test]
+    fn test_1211() {
+        assert_eq!("111221", look_and_say("1211"));
+    }
+
+    #[test]
+    fn test_111221() {
+        assert_eq!("312211", look_and_say("111221"));
+    }
+}
@@ -0,0 +1,104 @@
+use regex::Regex;
+use std::collections::HashMap;
+
+static MFCSAM: &str = r#"children: 3
+cats: 7
+samoyeds: 2
+pomeranians: 3
+akitas: 0
+vizslas: 0
+goldfish: 5
+trees: 3
+cars: 2
+perfumes: 1"#;
+
+fn get_mfcsam() -> HashMap<String, u8> {
+    MFCSAM
+        .lines()
+        .map(|line| {
+            let detection = line.split_whitespace().collect::<Vec<&str>>();
+            (
+                detection[0][..(detection[0].len() - 1)].to_string(),
+                detection[1].parse::<u8>().unwrap(),
+            )
+        })
+        .collect()
+}
+
+fn get_sues(input: &str) -> HashMap<String, HashMap<String, u8>> {
+    let re = Regex::new(r#"^Sue (\d+): (\w+): (\d+), (\w+): (\d+), (\w+): (\d+)$"#).unwrap();
+
+    input
+        .trim()
+        .lines()
+        .map(|line| {
+            let captures = re.captures(line).unwrap();
+            let mut facts = HashMap::new();
+            facts.insert(captures[2].to_string(), captures[3].parse::<u8>().unwrap());
+            facts.insert(captures[4].to_string(), captures[5].parse::<u8>().unwrap());
+            facts.insert(captures[6].to_string(), captures[7].parse::<u8>().unwrap());
+            (captures[1].to_string(), facts)
+        })
+        .collect()
+}
+
+fn get_sue(input: &str) -> String {
+    let mfcsam = get_mfcsam();
+    let sues = get_sues(&input);
+
+    let detected = sues
+        .iter()
+        .find(|&(_, facts)| {
+            facts
+                .iter()
+                .map(|(k, v)| mfcsam.get(k).unwrap() == v)
+                .all(|x| x)
+        })
+        .unwrap();
+
+    detected.0.clone()
+}
+
+fn get_retroencabulated_sue(input: &str) -> String {
+    let mfcsam = get_mfcsam();
+    let sues = get_sues(&input);
+
+    let cats = String::from("cats");
+    let trees = String::from("trees");
+    let pomeranians = String::from("pomeranians");
+    let goldfish = String::from("goldfish");
+
+    let detected = sues
+        .iter()
+        .find(|&(_, facts)| {
+            facts
+                .iter()
+                .map(|(k, v)| match k {
+                    k if *k == cats => v > mfcsam.get(k).unwrap(),
+                    k if *k == trees => v > mfcsam.get(k).unwrap(),
+                    k if *k == pomeranians => v < mfcsam.get(k).unwrap(),
+                    k if *k == goldfish => v < mfcsam.get(k).unwrap(),
+                    _ => mfcsam.get(k).unwrap() == v,
+                })
+                .all(|x| x)
+        })
+        .unwrap();
+
+    detected.0.clone()
+}
+
+pub fn get_part_one(input: &str) -> String {
+    get_sue(input)
+}
+
+pub fn get_part_two(input: &str) -> String {
+    get_retroencabulated_sue(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {}
+}
@@ -0,0 +1,108 @@
+use aoc_grid::Grid;
+
+fn get_grid(input: &str) -> Grid<bool> {
+    Grid::parse(input.trim(), |c| match c {
+        '.' => Ok(false),
+        '#' => Ok(true),
+        _ => Err(format!("Invalid character: {}", c)),
+    })
+    .unwrap()
+}
+
+fn get_lit_neighbour_count(light: (usize, usize), grid: &Grid<bool>) -> usize {
+    grid.neighbours8(light)
+        .filter(|neighbour| grid[*neighbour])
+        .count()
+}
+
+fn get_lit_count(grid: &Grid<bool>) -> usize {
+    grid.values().filter(|&state| *state).count()
+}
+
+fn animate_grid(grid: &Grid<bool>) -> Grid<bool> {
+    let mut next = grid.clone();
+    for (light, &state) in grid.iter() {
+        next[light] = matches!(
+            (state, get_lit_neighbour_count(light, grid)),
+            (true, 2) | (_, 3)
+        );
+    }
+
+    next
+}
+
+fn stick_lights(grid: &mut Grid<bool>) {
+    let (width, height) = (grid.width() - 1, grid.height() - 1);
+
+    for light in [(0, 0), (width, 0), (0, height), (width, height)] {
+        grid[light] = true;
+    }
+}
+
+#[allow(dead_code)]
+fn display_grid(grid: &Grid<bool>) {
+    println!("{}\n", grid.map(|&lit| if lit { '#' } else { '.' }));
+}
+
+pub fn get_part_one(input: &str) -> usize {
+    let mut grid = get_grid(input);
+    for _ in 0..100 {
+        grid = animate_grid(&grid);
+    }
+
+    get_lit_count(&grid)
+}
+
+pub fn get_part_two(input: &str) -> usize {
+    let mut grid = get_grid(input);
+    stick_lights(&mut grid);
+    for _ in 0..100 {
+        grid = animate_grid(&grid);
+        stick_lights(&mut grid);
+    }
+
+    get_lit_count(&grid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let input = r#".#.#.#
+...##.
+#....#
+..#...
+#.#..#
+####.."#;
+        let mut grid = get_grid(&input);
+
+        for _ in 0..4 {
+            grid = animate_grid(&grid);
+        }
+
+        assert_eq!(4, get_lit_count(&grid));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = r#".#.#.#
+...##.
+#....#
+..#...
+#.#..#
+####.."#;
+        let mut grid = get_grid(&input);
+        stick_lights(&mut grid);
+        display_grid(&grid);
+
+        for _ in 0..5 {
+            grid = animate_grid(&grid);
+            stick_lights(&mut grid);
+            display_grid(&grid);
+        }
+
+        assert_eq!(17, get_lit_count(&grid));
+    }
+}
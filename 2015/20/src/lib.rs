@@ -0,0 +1,51 @@
+use std::num::ParseIntError;
+
+/// Build up a list of houses as a `Vec` of presents contained therein.
+///
+/// As a house will have at least ten times its number in presents,
+/// we can realistically looking at numbers up to 1/10th of our input.
+fn find_first_house_with_present_count(presents: usize) -> usize {
+    let mut houses = vec![0; presents / 10];
+
+    for elf in 1..(presents / 10) {
+        let mut house = elf;
+        while house < (presents / 10) {
+            houses[house] += elf * 10;
+            house += elf;
+        }
+    }
+    houses.iter().position(|&p| p >= presents).unwrap()
+}
+
+fn find_first_house_with_new_present_count(presents: usize) -> usize {
+    let mut houses = vec![0; presents / 10];
+
+    for elf in 1..(presents / 11) {
+        let mut house = elf;
+        let mut visited = 0;
+        while house < (presents / 11) && visited < 50 {
+            houses[house] += elf * 11;
+            house += elf;
+            visited += 1;
+        }
+    }
+    houses.iter().position(|&p| p >= presents).unwrap()
+}
+
+pub fn get_part_one(input: &str) -> Result<usize, ParseIntError> {
+    Ok(find_first_house_with_present_count(input.trim().parse()?))
+}
+
+pub fn get_part_two(input: &str) -> Result<usize, ParseIntError> {
+    Ok(find_first_house_with_new_present_count(
+        input.trim().parse()?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {}
+}
@@ -0,0 +1,263 @@
+use std::cmp::max;
+
+use aoc_search::{dijkstra, State};
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+enum SpellName {
+    MagicMissile,
+    Drain,
+    Shield,
+    Poison,
+    Recharge,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+struct Spell {
+    name: SpellName,
+    cost: i16,
+    damage: i16,
+    hit_points: i16,
+    armour: i16,
+    duration: i16,
+    mana: i16,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+struct Attacker {
+    hit_points: i16,
+    damage: i16,
+    armour: i16,
+    mana: i16,
+}
+
+/// The fight at the start of the player's turn, or once the boss is dead.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+struct Round {
+    player: Attacker,
+    boss: Attacker,
+    effects: Vec<Spell>,
+    won: bool,
+}
+
+fn get_spells() -> Vec<Spell> {
+    vec![
+        Spell {
+            name: SpellName::MagicMissile,
+            cost: 53,
+            damage: 4,
+            hit_points: 0,
+            armour: 0,
+            duration: 0,
+            mana: 0,
+        },
+        Spell {
+            name: SpellName::Drain,
+            cost: 73,
+            damage: 2,
+            hit_points: 2,
+            armour: 0,
+            duration: 0,
+            mana: 0,
+        },
+        Spell {
+            name: SpellName::Shield,
+            cost: 113,
+            damage: 0,
+            hit_points: 0,
+            armour: 7,
+            duration: 6,
+            mana: 0,
+        },
+        Spell {
+            name: SpellName::Poison,
+            cost: 173,
+            damage: 3,
+            hit_points: 0,
+            armour: 0,
+            duration: 6,
+            mana: 0,
+        },
+        Spell {
+            name: SpellName::Recharge,
+            cost: 229,
+            damage: 0,
+            hit_points: 0,
+            armour: 0,
+            duration: 5,
+            mana: 101,
+        },
+    ]
+}
+
+impl Attacker {
+    fn new() -> Self {
+        Attacker {
+            hit_points: 0,
+            damage: 0,
+            armour: 0,
+            mana: 0,
+        }
+    }
+
+    fn player() -> Self {
+        Attacker {
+            hit_points: 50,
+            mana: 500,
+            ..Attacker::new()
+        }
+    }
+
+    fn boss(input: &str) -> Self {
+        let input = input
+            .trim()
+            .lines()
+            .map(|line| {
+                let data = line.trim().split_whitespace().collect::<Vec<&str>>();
+                data.last().unwrap().parse::<i16>().unwrap()
+            })
+            .collect::<Vec<i16>>();
+
+        Attacker {
+            hit_points: input[0],
+            damage: input[1],
+            ..Attacker::new()
+        }
+    }
+}
+
+impl Round {
+    fn apply_effects(&mut self) {
+        let mut armour = 0;
+        for i in 0..self.effects.len() {
+            self.effects[i].duration -= 1;
+            self.boss.hit_points -= self.effects[i].damage;
+            self.player.mana += self.effects[i].mana;
+            armour = max(armour, self.effects[i].armour);
+        }
+        self.player.armour = armour;
+        self.effects.retain(|effect| effect.duration > 0);
+    }
+
+    fn cast_spell(&mut self, spell: &Spell) {
+        if spell.duration > 0 {
+            self.effects.push(*spell);
+        } else {
+            self.boss.hit_points -= spell.damage;
+            self.player.hit_points += spell.hit_points;
+            self.player.armour = max(self.player.armour, spell.armour);
+        }
+        self.player.mana -= spell.cost;
+    }
+
+    fn win(mut self) -> Self {
+        self.won = true;
+        self
+    }
+
+    /// Plays out casting `spell` and the boss's turn that follows, giving
+    /// the next round unless the player died.
+    fn play(&self, spell: &Spell) -> Option<Self> {
+        let mut round = self.clone();
+
+        round.cast_spell(spell);
+        // If boss dead, win.
+        if round.boss.hit_points <= 0 {
+            return Some(round.win());
+        }
+
+        // -- Boss turn --
+        round.apply_effects();
+        // If boss dead, win.
+        if round.boss.hit_points <= 0 {
+            return Some(round.win());
+        }
+        round.player.hit_points -= max(round.boss.damage - round.player.armour, 1);
+
+        // If player dead, lose.
+        match round.player.hit_points {
+            hit_points if hit_points <= 0 => None,
+            _ => Some(round),
+        }
+    }
+}
+
+impl State for Round {
+    /// Whether the game is on hard.
+    type Context = bool;
+
+    /// Every spell the player could cast, at the cost of its mana.
+    fn successors(&self, &hard: &bool) -> Vec<(Self, usize)> {
+        let mut round = self.clone();
+
+        // -- Player turn --
+        // If this brings you to or below 0 hit points, you lose.
+        if hard {
+            round.player.hit_points -= 1;
+            if round.player.hit_points <= 0 {
+                return Vec::new();
+            }
+        }
+
+        round.apply_effects();
+        // If boss dead, win.
+        if round.boss.hit_points <= 0 {
+            return vec![(round.win(), 0)];
+        }
+
+        //  If you cannot afford to cast any spell, you lose.
+        get_spells()
+            .iter()
+            .filter(|spell| spell.cost <= round.player.mana)
+            .filter(|spell| !round.effects.iter().any(|effect| effect.name == spell.name))
+            .filter_map(|spell| Some((round.play(spell)?, spell.cost as usize)))
+            .collect()
+    }
+
+    fn is_goal(&self, _: &bool) -> bool {
+        self.won
+    }
+}
+
+fn get_least_mana(player: Attacker, boss: Attacker, hard: bool) -> Option<usize> {
+    let round = Round {
+        player,
+        boss,
+        effects: Vec::new(),
+        won: false,
+    };
+
+    dijkstra(round, &hard).cost()
+}
+
+pub fn get_part_one(input: &str) -> Result<usize, String> {
+    get_least_mana(Attacker::player(), Attacker::boss(input), false)
+        .ok_or_else(|| String::from("The boss can't be beaten"))
+}
+
+pub fn get_part_two(input: &str) -> Result<usize, String> {
+    get_least_mana(Attacker::player(), Attacker::boss(input), true)
+        .ok_or_else(|| String::from("The boss can't be beaten"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let player = Attacker {
+            hit_points: 10,
+            mana: 250,
+            ..Attacker::new()
+        };
+
+        assert_eq!(
+            Some(226),
+            get_least_mana(player, Attacker::boss("13\n8"), false)
+        );
+        assert_eq!(
+            Some(641),
+            get_least_mana(player, Attacker::boss("14\n8"), false)
+        );
+    }
+}
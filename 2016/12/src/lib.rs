@@ -0,0 +1,39 @@
+use std::str::FromStr;
+
+use assembunny::{Assembunny, Register};
+
+fn get_register_a(input: &str, c: isize) -> isize {
+    let mut assembunny = Assembunny::from_str(input).unwrap();
+    assembunny.set(Register::C, c);
+    assembunny.run();
+
+    assembunny.get(Register::A)
+}
+
+pub fn get_part_one(input: &str) -> isize {
+    get_register_a(input, 0)
+}
+
+pub fn get_part_two(input: &str) -> isize {
+    get_register_a(input, 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = r#"cpy 41 a
+inc a
+inc a
+dec a
+jnz a 2
+dec a"#;
+
+        let mut assembunny = Assembunny::from_str(input).unwrap();
+        assembunny.run();
+
+        assert_eq!(42, assembunny.get(Register::A));
+    }
+}
@@ -0,0 +1,54 @@
+use std::cmp::Ordering;
+
+fn get_winner(elf_count: &str) -> usize {
+    let elf_count = elf_count.trim().parse::<usize>().unwrap();
+
+    let nearest_power_of_2 = 2_usize.pow((elf_count as f64).log2().floor() as u32);
+    let l = elf_count - nearest_power_of_2;
+
+    (2 * l) + 1
+}
+
+fn get_winner_opposite(elf_count: &str) -> usize {
+    let elf_count = elf_count.trim().parse::<usize>().unwrap();
+
+    let nearest_power_of_3 = 3_usize.pow((elf_count as f64).log(3.0).floor() as u32);
+    let l = elf_count - nearest_power_of_3;
+
+    if l == 0 {
+        nearest_power_of_3
+    } else {
+        match l.cmp(&nearest_power_of_3) {
+            Ordering::Less => l,
+            Ordering::Greater => nearest_power_of_3 + (l - nearest_power_of_3) * 2,
+            _ => panic!("Errrr…"),
+        }
+    }
+}
+
+pub fn get_part_one(input: &str) -> usize {
+    get_winner(input)
+}
+
+pub fn get_part_two(input: &str) -> usize {
+    get_winner_opposite(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_5() {
+        let input = "5";
+
+        assert_eq!(get_winner(&input), 3);
+    }
+
+    #[test]
+    fn test_5_opposite() {
+        let input = "5";
+
+        assert_eq!(get_winner_opposite(&input), 2);
+    }
+}
@@ -0,0 +1,37 @@
+use std::str::FromStr;
+
+use assembunny::{Assembunny, Register};
+
+fn get_value_for_safe(input: &str, a: isize) -> isize {
+    let mut assembunny = Assembunny::from_str(input).unwrap();
+    assembunny.set(Register::A, a);
+    assembunny.run();
+
+    assembunny.get(Register::A)
+}
+
+pub fn get_part_one(input: &str) -> isize {
+    get_value_for_safe(input, 7)
+}
+
+pub fn get_part_two(input: &str) -> isize {
+    get_value_for_safe(input, 12)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = r#"cpy 2 a
+tgl a
+tgl a
+tgl a
+cpy 1 a
+dec a
+dec a"#;
+
+        assert_eq!(3, get_value_for_safe(input, 0));
+    }
+}
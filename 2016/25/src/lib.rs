@@ -0,0 +1,56 @@
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use assembunny::{Assembunny, Register, State};
+
+/// Whether the program transmits `0, 1, 0, 1…` forever, which it must if
+/// it gets back to a state it was in when it last sent the same signal.
+fn is_clock_signal(mut assembunny: Assembunny) -> bool {
+    let mut seen = HashSet::new();
+    let mut expected = 0;
+
+    loop {
+        match assembunny.run() {
+            State::Output(signal) if signal == expected => {
+                if !seen.insert((assembunny.clone(), expected)) {
+                    return true;
+                }
+                expected = 1 - expected;
+            }
+            _ => return false,
+        }
+    }
+}
+
+fn get_lowest_clock_signal_input(input: &str) -> isize {
+    let assembunny = Assembunny::from_str(input).unwrap();
+
+    (1..)
+        .find(|&a| {
+            let mut assembunny = assembunny.clone();
+            assembunny.set(Register::A, a);
+
+            is_clock_signal(assembunny)
+        })
+        .unwrap()
+}
+
+pub fn get_part_one(input: &str) -> isize {
+    get_lowest_clock_signal_input(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = r#"jnz a 2
+out 1
+out 0
+out 1
+jnz 1 -2"#;
+
+        assert_eq!(1, get_lowest_clock_signal_input(input));
+    }
+}
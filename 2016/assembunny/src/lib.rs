@@ -0,0 +1,393 @@
+#![deny(clippy::expect_used, clippy::unwrap_used)]
+
+use std::fmt;
+use std::str::FromStr;
+
+use aoc_error::{parse_lines, Context, Error, Result};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Register {
+    A,
+    B,
+    C,
+    D,
+}
+
+impl Register {
+    fn index(&self) -> usize {
+        *self as usize
+    }
+}
+
+impl FromStr for Register {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "a" => Ok(Register::A),
+            "b" => Ok(Register::B),
+            "c" => Ok(Register::C),
+            "d" => Ok(Register::D),
+            _ => Err(Error::invalid_input(format!("unknown register {:?}", s))),
+        }
+    }
+}
+
+impl fmt::Display for Register {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Register::A => 'a',
+            Register::B => 'b',
+            Register::C => 'c',
+            Register::D => 'd',
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Operand {
+    Register(Register),
+    Value(isize),
+}
+
+impl FromStr for Operand {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.parse() {
+            Ok(value) => Ok(Operand::Value(value)),
+            Err(_) => Ok(Operand::Register(s.parse()?)),
+        }
+    }
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operand::Register(register) => write!(f, "{}", register),
+            Operand::Value(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+/// Every operand is kept general, as `tgl` can turn a valid instruction
+/// into one that isn't (such as `cpy 1 2`); those are skipped when run.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Instruction {
+    Cpy(Operand, Operand),
+    Inc(Operand),
+    Dec(Operand),
+    Jnz(Operand, Operand),
+    Tgl(Operand),
+    Out(Operand),
+}
+
+impl Instruction {
+    /// What `tgl` turns this instruction into.
+    pub fn toggled(&self) -> Self {
+        match *self {
+            Instruction::Inc(x) => Instruction::Dec(x),
+            Instruction::Dec(x) | Instruction::Tgl(x) | Instruction::Out(x) => Instruction::Inc(x),
+            Instruction::Jnz(x, y) => Instruction::Cpy(x, y),
+            Instruction::Cpy(x, y) => Instruction::Jnz(x, y),
+        }
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut parts = s.split_whitespace();
+        let name = parts.next().context("empty instruction")?;
+        let operands = parts.map(Operand::from_str).collect::<Result<Vec<_>>>()?;
+
+        let instruction = match (name, operands.as_slice()) {
+            ("cpy", [x, y @ Operand::Register(_)]) => Instruction::Cpy(*x, *y),
+            ("inc", [x @ Operand::Register(_)]) => Instruction::Inc(*x),
+            ("dec", [x @ Operand::Register(_)]) => Instruction::Dec(*x),
+            ("jnz", [x, y]) => Instruction::Jnz(*x, *y),
+            ("tgl", [x]) => Instruction::Tgl(*x),
+            ("out", [x]) => Instruction::Out(*x),
+            _ => return Err(Error::invalid_input(format!("invalid instruction {:?}", s))),
+        };
+
+        Ok(instruction)
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Instruction::Cpy(x, y) => write!(f, "cpy {} {}", x, y),
+            Instruction::Inc(x) => write!(f, "inc {}", x),
+            Instruction::Dec(x) => write!(f, "dec {}", x),
+            Instruction::Jnz(x, y) => write!(f, "jnz {} {}", x, y),
+            Instruction::Tgl(x) => write!(f, "tgl {}", x),
+            Instruction::Out(x) => write!(f, "out {}", x),
+        }
+    }
+}
+
+/// Why the computer stopped running.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum State {
+    Halted,
+    Output(isize),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Assembunny {
+    program: Vec<Instruction>,
+    registers: [isize; 4],
+    pointer: isize,
+    optimise: bool,
+}
+
+impl FromStr for Assembunny {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(Assembunny::new(parse_lines(
+            s.trim(),
+            Instruction::from_str,
+        )?))
+    }
+}
+
+impl Assembunny {
+    pub fn new(program: Vec<Instruction>) -> Self {
+        Assembunny {
+            program,
+            registers: [0; 4],
+            pointer: 0,
+            optimise: true,
+        }
+    }
+
+    /// Turns the recognition of addition and multiplication loops on or
+    /// off; it's on by default.
+    pub fn set_optimise(&mut self, optimise: bool) {
+        self.optimise = optimise;
+    }
+
+    pub fn program(&self) -> &[Instruction] {
+        &self.program
+    }
+
+    pub fn pointer(&self) -> isize {
+        self.pointer
+    }
+
+    pub fn registers(&self) -> [isize; 4] {
+        self.registers
+    }
+
+    pub fn get(&self, register: Register) -> isize {
+        self.registers[register.index()]
+    }
+
+    pub fn set(&mut self, register: Register, value: isize) {
+        self.registers[register.index()] = value;
+    }
+
+    fn value(&self, operand: Operand) -> isize {
+        match operand {
+            Operand::Register(register) => self.get(register),
+            Operand::Value(value) => value,
+        }
+    }
+
+    /// Executes a single instruction, or a whole loop if it's one that
+    /// can be computed directly.
+    ///
+    /// Returns `None` if the computer can carry on; otherwise the `State`
+    /// that stopped it.
+    pub fn step(&mut self) -> Option<State> {
+        let Some(&instruction) = usize::try_from(self.pointer)
+            .ok()
+            .and_then(|pointer| self.program.get(pointer))
+        else {
+            return Some(State::Halted);
+        };
+
+        if self.optimise && self.accelerate() {
+            return None;
+        }
+
+        let mut state = None;
+        match instruction {
+            Instruction::Cpy(x, Operand::Register(y)) => self.set(y, self.value(x)),
+            Instruction::Inc(Operand::Register(x)) => self.registers[x.index()] += 1,
+            Instruction::Dec(Operand::Register(x)) => self.registers[x.index()] -= 1,
+            Instruction::Jnz(x, y) => {
+                if self.value(x) != 0 {
+                    self.pointer += self.value(y);
+                    return None;
+                }
+            }
+            Instruction::Tgl(x) => {
+                let target = self.pointer + self.value(x);
+                if let Some(toggled) = usize::try_from(target)
+                    .ok()
+                    .and_then(|target| self.program.get_mut(target))
+                {
+                    *toggled = toggled.toggled();
+                }
+            }
+            Instruction::Out(x) => state = Some(State::Output(self.value(x))),
+            // Left invalid by a `tgl`.
+            Instruction::Cpy(_, Operand::Value(_))
+            | Instruction::Inc(Operand::Value(_))
+            | Instruction::Dec(Operand::Value(_)) => {}
+        }
+        self.pointer += 1;
+
+        state
+    }
+
+    /// Recognises the loops that only exist to add or multiply, applying
+    /// their effect in one go:
+    ///
+    /// ```text
+    /// inc a       cpy b c
+    /// dec c       inc a
+    /// jnz c -2    dec c
+    ///             jnz c -2
+    ///             dec d
+    ///             jnz d -5
+    /// ```
+    ///
+    /// Loops whose counters start at zero or below (and so would wrap
+    /// round rather than finish) are left to run as written.
+    fn accelerate(&mut self) -> bool {
+        use Instruction::{Cpy, Dec, Inc, Jnz};
+        use Operand::{Register as R, Value as V};
+
+        let Some(window) = usize::try_from(self.pointer)
+            .ok()
+            .and_then(|pointer| self.program.get(pointer..))
+        else {
+            return false;
+        };
+
+        match *window {
+            [Cpy(source, R(c)), Inc(R(a)), Dec(R(c2)), Jnz(R(c3), V(-2)), Dec(R(d)), Jnz(R(d2), V(-5)), ..]
+                if c == c2
+                    && c == c3
+                    && d == d2
+                    && a != c
+                    && a != d
+                    && c != d
+                    && ![R(a), R(c), R(d)].contains(&source)
+                    && self.value(source) > 0
+                    && self.get(d) > 0 =>
+            {
+                self.registers[a.index()] += self.value(source) * self.get(d);
+                self.set(c, 0);
+                self.set(d, 0);
+                self.pointer += 6;
+
+                true
+            }
+            [Inc(R(a)), Dec(R(c)), Jnz(R(c2), V(-2)), ..]
+            | [Dec(R(c)), Inc(R(a)), Jnz(R(c2), V(-2)), ..]
+                if c == c2 && a != c && self.get(c) > 0 =>
+            {
+                self.registers[a.index()] += self.get(c);
+                self.set(c, 0);
+                self.pointer += 3;
+
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Runs until the program produces an output or halts.
+    pub fn run(&mut self) -> State {
+        loop {
+            if let Some(state) = self.step() {
+                return state;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MULTIPLY: &str = r#"cpy 6 b
+cpy 7 d
+cpy b c
+inc a
+dec c
+jnz c -2
+dec d
+jnz d -5
+out a"#;
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        let program = "cpy 41 a\ninc a\ndec b\njnz a -2\ntgl c\nout d";
+        let assembunny = Assembunny::from_str(program)?;
+
+        assert_eq!(
+            program,
+            assembunny
+                .program()
+                .iter()
+                .map(Instruction::to_string)
+                .collect::<Vec<_>>()
+                .join("\n"),
+        );
+        assert_eq!(
+            Err(Some(aoc_error::Location::line(2))),
+            Assembunny::from_str("inc a\ninc 1").map_err(|error| error.location()),
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_run() -> Result<()> {
+        let mut assembunny = Assembunny::from_str("cpy 41 a\ninc a\ninc a\ndec a\njnz a 2\ndec a")?;
+
+        assert_eq!(State::Halted, assembunny.run());
+        assert_eq!(42, assembunny.get(Register::A));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_toggle() -> Result<()> {
+        let mut assembunny =
+            Assembunny::from_str("cpy 2 a\ntgl a\ntgl a\ntgl a\ncpy 1 a\ndec a\ndec a")?;
+
+        assert_eq!(State::Halted, assembunny.run());
+        assert_eq!(3, assembunny.get(Register::A));
+        assert_eq!(
+            Instruction::Jnz(Operand::Value(1), Operand::Register(Register::A)),
+            assembunny.program()[4],
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_accelerate() -> Result<()> {
+        let mut optimised = Assembunny::from_str(MULTIPLY)?;
+        let mut unoptimised = optimised.clone();
+        unoptimised.set_optimise(false);
+
+        assert_eq!(State::Output(42), optimised.run());
+        assert_eq!(State::Output(42), unoptimised.run());
+        assert_eq!(unoptimised.registers(), optimised.registers());
+        assert_eq!(unoptimised.pointer(), optimised.pointer());
+
+        Ok(())
+    }
+}
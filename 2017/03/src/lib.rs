@@ -1,5 +1,6 @@
 use num::complex::Complex;
 use std::collections::HashMap;
+use std::num::ParseIntError;
 
 fn get_neighbours(square: (i32, i32)) -> Vec<(i32, i32)> {
     let (x, y) = square;
@@ -57,6 +58,16 @@ pub fn get_manhattan_distance(n: i32) -> i32 {
     distance_from_centre + upper
 }
 
+pub fn get_part_one(input: &str) -> Result<i32, ParseIntError> {
+    Ok(get_manhattan_distance(input.trim().parse()?))
+}
+
+pub fn get_part_two(input: &str) -> Result<i32, ParseIntError> {
+    Ok(get_first_square_spiral_sum_greater_than(
+        input.trim().parse()?,
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
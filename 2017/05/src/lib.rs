@@ -40,6 +40,14 @@ pub fn get_stranger_steps_to_exit(mut offsets: Vec<isize>) -> usize {
     steps
 }
 
+pub fn get_part_one(input: &str) -> usize {
+    get_steps_to_exit(get_offsets(input))
+}
+
+pub fn get_part_two(input: &str) -> usize {
+    get_stranger_steps_to_exit(get_offsets(input))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
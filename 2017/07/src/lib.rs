@@ -153,6 +153,18 @@ impl Tower {
     }
 }
 
+pub fn get_part_one(input: &str) -> Result<String, String> {
+    Tower::from_str(input)
+        .map(|tower| tower.get_bottom_program())
+        .map_err(|_| String::from("Invalid tower"))
+}
+
+pub fn get_part_two(input: &str) -> Result<usize, String> {
+    Tower::from_str(input)
+        .map(|tower| tower.get_corrected_wrong_weight())
+        .map_err(|_| String::from("Invalid tower"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
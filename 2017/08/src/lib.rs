@@ -36,6 +36,19 @@ pub fn get_cpu(input: &str) -> Cpu {
     Cpu { registers, highest }
 }
 
+pub fn get_part_one(input: &str) -> Result<isize, String> {
+    get_cpu(input)
+        .registers
+        .values()
+        .max()
+        .copied()
+        .ok_or_else(|| String::from("No registers"))
+}
+
+pub fn get_part_two(input: &str) -> isize {
+    get_cpu(input).highest
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
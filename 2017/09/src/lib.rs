@@ -55,6 +55,14 @@ pub fn get_stream_for_input(input: &str) -> Stream {
     stream
 }
 
+pub fn get_part_one(input: &str) -> usize {
+    get_stream_for_input(input).score
+}
+
+pub fn get_part_two(input: &str) -> usize {
+    get_stream_for_input(input).garbage
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,17 +1,14 @@
 use std::fs;
 
-use ::day09::*;
+use ::aoc_2017_09::*;
 
 fn main() {
     let input = fs::read_to_string("input.txt").expect("Error reading input.txt");
 
-    println!(
-        "What is the total score…? {}",
-        get_stream_for_input(&input).score,
-    );
+    println!("What is the total score…? {}", get_part_one(&input));
 
     println!(
         "How many non-canceled characters are within the garbage…? {}",
-        get_stream_for_input(&input).garbage,
+        get_part_two(&input),
     );
 }
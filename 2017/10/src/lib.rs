@@ -74,6 +74,20 @@ pub fn get_ascii_lengths(input: &str) -> Vec<usize> {
     ascii_codes
 }
 
+pub fn get_part_one(input: &str) -> usize {
+    let mut knot_hash = KnotHash::new(0, 255);
+    knot_hash.apply_lengths(&get_lengths(input));
+
+    knot_hash.get_product_of_first_two_numbers()
+}
+
+pub fn get_part_two(input: &str) -> String {
+    let mut knot_hash = KnotHash::new(0, 255);
+    knot_hash.generate_sparse_hash(&get_ascii_lengths(input));
+
+    knot_hash.get_dense_hash()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
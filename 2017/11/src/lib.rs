@@ -26,6 +26,18 @@ pub fn get_steps(position: Complex<isize>) -> isize {
     (position.re.abs() + position.im.abs() + (position.re + position.im).abs()) / 2
 }
 
+pub fn get_part_one(input: &str) -> isize {
+    let (position, _) = read_path(input);
+
+    get_steps(position)
+}
+
+pub fn get_part_two(input: &str) -> isize {
+    let (_, max) = read_path(input);
+
+    max
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -36,6 +36,14 @@ pub fn get_group_count(input: &str) -> usize {
     groups.iter().collect::<HashSet<_>>().len()
 }
 
+pub fn get_part_one(input: &str) -> usize {
+    get_group_size_for("0", input)
+}
+
+pub fn get_part_two(input: &str) -> usize {
+    get_group_count(input)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
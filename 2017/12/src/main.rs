@@ -1,17 +1,17 @@
 use std::fs;
 
-use ::day12::*;
+use ::aoc_2017_12::*;
 
 fn main() {
     let input = fs::read_to_string("input.txt").expect("Error reading input.txt");
 
     println!(
         "How many programs are in the group that contains program ID 0? {}",
-        get_group_size_for("0", &input),
+        get_part_one(&input),
     );
 
     println!(
         "How many groups are there in total? {}",
-        get_group_count(&input),
+        get_part_two(&input),
     );
 }
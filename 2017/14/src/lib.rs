@@ -64,6 +64,14 @@ pub fn get_region_count(key: &str) -> usize {
     count
 }
 
+pub fn get_part_one(input: &str) -> usize {
+    get_used_square_count(input.trim())
+}
+
+pub fn get_part_two(input: &str) -> usize {
+    get_region_count(input.trim())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -95,6 +95,18 @@ fn get_starting_value(line: &str) -> usize {
         .unwrap()
 }
 
+pub fn get_part_one(input: &str) -> Result<usize, String> {
+    Generators::from_str(input)
+        .map(|mut generators| generators.get_judgement(40_000_000))
+        .map_err(|_| String::from("Invalid generators"))
+}
+
+pub fn get_part_two(input: &str) -> Result<usize, String> {
+    Generators::from_str(input)
+        .map(|mut generators| generators.get_picky_judgement(5_000_000))
+        .map_err(|_| String::from("Invalid generators"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
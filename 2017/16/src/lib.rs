@@ -94,6 +94,14 @@ pub fn perform_dances(dancers: usize, input: &str, times: usize) -> String {
     dancers.into_iter().collect()
 }
 
+pub fn get_part_one(input: &str) -> String {
+    perform_dance(input)
+}
+
+pub fn get_part_two(input: &str) -> String {
+    perform_dances(16, input, 1_000_000_000)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,17 +1,17 @@
 use std::fs;
 
-use ::day16::*;
+use ::aoc_2017_16::*;
 
 fn main() {
     let input = fs::read_to_string("input.txt").expect("Error reading input.txt");
 
     println!(
         "In what order are the programs standing…? {}",
-        perform_dance(&input),
+        get_part_one(&input),
     );
 
     println!(
         "In what order are the programs standing…? {}",
-        perform_dances(16, &input, 1_000_000_000),
+        get_part_two(&input),
     );
 }
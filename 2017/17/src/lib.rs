@@ -43,6 +43,14 @@ impl Spinlock {
     }
 }
 
+pub fn get_part_one(input: &str) -> usize {
+    Spinlock::from_str(input.trim(), 2017).get_value_after_last_inserted()
+}
+
+pub fn get_part_two(input: &str) -> usize {
+    Spinlock::from_str(input.trim(), 50_000_000).get_value_after_zero()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,19 +1,11 @@
 use std::fs;
 
-use ::day17::*;
+use ::aoc_2017_17::*;
 
 fn main() {
     let input = fs::read_to_string("input.txt").expect("Error reading input.txt");
 
-    let mut spinlock = Spinlock::from_str(&input.trim(), 2017);
-    println!(
-        "What is the value after 2017…? {}",
-        spinlock.get_value_after_last_inserted(),
-    );
+    println!("What is the value after 2017…? {}", get_part_one(&input));
 
-    let mut spinlock = Spinlock::from_str(&input.trim(), 50_000_000);
-    println!(
-        "What is the value after 0…? {}",
-        spinlock.get_value_after_zero(),
-    );
+    println!("What is the value after 0…? {}", get_part_two(&input));
 }
@@ -180,6 +180,22 @@ impl FromStr for Instruction {
     }
 }
 
+fn duet(input: &str) -> (Program, Program) {
+    let mut zero = Program::new(input, 0);
+    let mut one = Program::new(input, 1);
+    Program::duet(&mut zero, &mut one);
+
+    (zero, one)
+}
+
+pub fn get_part_one(input: &str) -> isize {
+    duet(input).0.first_non_zero_recv
+}
+
+pub fn get_part_two(input: &str) -> usize {
+    duet(input).1.sent_count
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
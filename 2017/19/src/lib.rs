@@ -75,6 +75,21 @@ impl RoutingDiagram {
     }
 }
 
+fn follow(input: &str) -> Result<RoutingDiagram, String> {
+    let mut routing_diagram = RoutingDiagram::from_str(input)?;
+    routing_diagram.follow_diagram();
+
+    Ok(routing_diagram)
+}
+
+pub fn get_part_one(input: &str) -> Result<String, String> {
+    follow(input).map(|mut routing_diagram| routing_diagram.get_letters())
+}
+
+pub fn get_part_two(input: &str) -> Result<usize, String> {
+    follow(input).map(|mut routing_diagram| routing_diagram.get_steps())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
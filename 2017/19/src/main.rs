@@ -1,18 +1,14 @@
 use std::fs;
-use std::str::FromStr;
 
-use ::day19::*;
+use ::aoc_2017_19::*;
 
 fn main() {
     let input = fs::read_to_string("input.txt").expect("Error reading input.txt");
 
-    let mut routing_diagram = RoutingDiagram::from_str(&input).unwrap();
-    routing_diagram.follow_diagram();
-
     println!(
         "What letters will it see…? {}",
-        routing_diagram.get_letters(),
+        get_part_one(&input).unwrap(),
     );
 
-    println!("How many steps…? {}", routing_diagram.get_steps(),);
+    println!("How many steps…? {}", get_part_two(&input).unwrap());
 }
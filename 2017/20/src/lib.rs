@@ -131,6 +131,14 @@ impl Buffer {
     }
 }
 
+pub fn get_part_one(input: &str) -> Result<usize, String> {
+    Buffer::from_str(input).map(|mut buffer| buffer.get_closest_to_zero())
+}
+
+pub fn get_part_two(input: &str) -> Result<usize, String> {
+    Buffer::from_str(input).map(|mut buffer| buffer.get_particle_count())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
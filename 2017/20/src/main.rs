@@ -1,20 +1,17 @@
 use std::fs;
-use std::str::FromStr;
 
-use ::day20::*;
+use ::aoc_2017_20::*;
 
 fn main() {
     let input = fs::read_to_string("input.txt").expect("Error reading input.txt");
 
-    let mut buffer = Buffer::from_str(&input).unwrap();
     println!(
         "Which particle will stay closest to position <0,0,0> …? {}",
-        buffer.get_closest_to_zero(),
+        get_part_one(&input).unwrap(),
     );
 
-    let mut buffer = Buffer::from_str(&input).unwrap();
     println!(
         "How many particles are left…? {}",
-        buffer.get_particle_count(),
+        get_part_two(&input).unwrap(),
     );
 }
@@ -246,6 +246,21 @@ impl FromStr for Rules {
     }
 }
 
+fn get_on_count_after(input: &str, iterations: usize) -> Result<usize, String> {
+    let rules = Rules::from_str(input)?;
+    let image = (0..iterations).fold(Image::new(), |image, _| image.get_iteration(&rules));
+
+    Ok(image.get_on_count())
+}
+
+pub fn get_part_one(input: &str) -> Result<usize, String> {
+    get_on_count_after(input, 5)
+}
+
+pub fn get_part_two(input: &str) -> Result<usize, String> {
+    get_on_count_after(input, 18)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
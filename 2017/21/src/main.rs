@@ -1,28 +1,17 @@
 use std::fs;
 
-use ::day21::*;
-use std::str::FromStr;
+use ::aoc_2017_21::*;
 
 fn main() {
     let input = fs::read_to_string("input.txt").expect("Error reading input.txt");
 
-    let rules = Rules::from_str(&input).unwrap();
-
-    let mut image = Image::new();
-    for _ in 0..5 {
-        image = image.get_iteration(&rules);
-    }
     println!(
         "How many pixels stay on after 5 iterations? {}",
-        image.get_on_count(),
+        get_part_one(&input).unwrap(),
     );
 
-    let mut image = Image::new();
-    for _ in 0..18 {
-        image = image.get_iteration(&rules);
-    }
     println!(
         "How many pixels stay on after 18 iterations? {}",
-        image.get_on_count(),
+        get_part_two(&input).unwrap(),
     );
 }
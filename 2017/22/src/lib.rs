@@ -171,6 +171,20 @@ impl Virus {
     }
 }
 
+pub fn get_part_one(input: &str) -> Result<usize, String> {
+    let mut cluster = Cluster::from_str(input)?;
+    cluster.bursts_of_activity(10_000);
+
+    Ok(cluster.get_infections())
+}
+
+pub fn get_part_two(input: &str) -> Result<usize, String> {
+    let mut cluster = Cluster::from_str(input)?;
+    cluster.evolved_bursts_of_activity(10_000_000);
+
+    Ok(cluster.get_infections())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
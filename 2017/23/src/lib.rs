@@ -137,3 +137,15 @@ impl FromStr for Instruction {
         })
     }
 }
+
+pub fn get_part_one(input: &str) -> usize {
+    let mut program = Program::new(input);
+    program.run();
+
+    program.mul_count
+}
+
+/// The program was decompiled by hand, so doesn't need the input.
+pub fn get_part_two(_: &str) -> usize {
+    Program::part_two()
+}
@@ -72,6 +72,14 @@ pub fn get_strongest_longest_bridge(bridges: &mut Vec<Vec<Component>>) -> usize
     get_strength(bridges.last().unwrap())
 }
 
+pub fn get_part_one(input: &str) -> usize {
+    get_strongest_bridge(&mut get_bridges(&get_components(input)))
+}
+
+pub fn get_part_two(input: &str) -> usize {
+    get_strongest_longest_bridge(&mut get_bridges(&get_components(input)))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -112,6 +112,11 @@ peg::parser! {
     }
 }
 
+pub fn get_part_one(input: &str) -> Result<usize, String> {
+    TuringMachine::from_str(input)
+        .map(|mut turing_machine| turing_machine.get_diagnostic_checksum())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
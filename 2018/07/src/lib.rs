@@ -109,6 +109,14 @@ impl Instructions {
     }
 }
 
+pub fn get_part_one(input: &str) -> Result<String, String> {
+    Instructions::from_str(input).map(|instructions| instructions.get_steps_order())
+}
+
+pub fn get_part_two(input: &str) -> Result<usize, String> {
+    Instructions::from_str(input).map(|mut instructions| instructions.get_duration(5))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,20 +1,17 @@
 use std::fs;
 
-use ::day07::*;
-use std::str::FromStr;
+use ::aoc_2018_07::*;
 
 fn main() {
     let input = fs::read_to_string("input.txt").expect("Error reading input.txt");
 
-    let mut instructions = Instructions::from_str(&input).unwrap();
-
     println!(
         "In what order should the steps in your instructions be completed? {}",
-        instructions.get_steps_order(),
+        get_part_one(&input).unwrap(),
     );
 
     println!(
         "…how long will it take to complete all of the steps? {}",
-        instructions.get_duration(5),
+        get_part_two(&input).unwrap(),
     );
 }
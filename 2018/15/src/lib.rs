@@ -244,6 +244,14 @@ pub fn get_outcome_no_losses(input: &str) -> usize {
     score
 }
 
+pub fn get_part_one(input: &str) -> Result<usize, String> {
+    Cave::from_str(input).map(|mut cave| cave.get_outcome())
+}
+
+pub fn get_part_two(input: &str) -> usize {
+    get_outcome_no_losses(input)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
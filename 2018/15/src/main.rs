@@ -1,14 +1,11 @@
 use std::fs;
-use std::str::FromStr;
 
-use ::day15::*;
+use ::aoc_2018_15::*;
 
 fn main() {
     let input = fs::read_to_string("input.txt").expect("Error reading input.txt");
 
-    let mut cave = Cave::from_str(&input).unwrap();
+    println!("What is the outcome…? {}", get_part_one(&input).unwrap());
 
-    println!("What is the outcome…? {}", cave.get_outcome(),);
-
-    println!("…what is the outcome…? {}", get_outcome_no_losses(&input));
+    println!("…what is the outcome…? {}", get_part_two(&input));
 }
@@ -1,6 +1,8 @@
 use std::collections::HashSet;
 use std::str::FromStr;
 
+pub use elfcode::OpCode;
+
 #[derive(Debug, Eq, PartialEq)]
 pub struct InputFile {
     samples: Vec<Sample>,
@@ -22,114 +24,22 @@ pub struct Instruction {
     output: usize,
 }
 
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
-pub enum OpCode {
-    Addr,
-    Addi,
-    Mulr,
-    Muli,
-    Banr,
-    Bani,
-    Borr,
-    Bori,
-    Setr,
-    Seti,
-    Gtir,
-    Gtri,
-    Gtrr,
-    Eqir,
-    Eqri,
-    Eqrr,
-}
-
 impl Sample {
     fn is_opcode(&self, opcode: &OpCode) -> bool {
-        self.get_result(opcode) == self.after[self.opcodes.output]
+        self.get_result(opcode) == self.after.get(self.opcodes.output).copied()
     }
 
-    fn get_result(&self, opcode: &OpCode) -> usize {
-        match opcode {
-            OpCode::Addr => self.before[self.opcodes.input_a] + self.before[self.opcodes.input_b],
-            OpCode::Addi => self.before[self.opcodes.input_a] + self.opcodes.input_b,
-            OpCode::Mulr => self.before[self.opcodes.input_a] * self.before[self.opcodes.input_b],
-            OpCode::Muli => self.before[self.opcodes.input_a] * self.opcodes.input_b,
-            OpCode::Banr => self.before[self.opcodes.input_a] & self.before[self.opcodes.input_b],
-            OpCode::Bani => self.before[self.opcodes.input_a] & self.opcodes.input_b,
-            OpCode::Borr => self.before[self.opcodes.input_a] | self.before[self.opcodes.input_b],
-            OpCode::Bori => self.before[self.opcodes.input_a] | self.opcodes.input_b,
-            OpCode::Setr => self.before[self.opcodes.input_a],
-            OpCode::Seti => self.opcodes.input_a,
-            OpCode::Gtir => {
-                if self.opcodes.input_a > self.before[self.opcodes.input_b] {
-                    1
-                } else {
-                    0
-                }
-            }
-            OpCode::Gtri => {
-                if self.before[self.opcodes.input_a] > self.opcodes.input_b {
-                    1
-                } else {
-                    0
-                }
-            }
-            OpCode::Gtrr => {
-                if self.before[self.opcodes.input_a] > self.before[self.opcodes.input_b] {
-                    1
-                } else {
-                    0
-                }
-            }
-            OpCode::Eqir => {
-                if self.opcodes.input_a == self.before[self.opcodes.input_b] {
-                    1
-                } else {
-                    0
-                }
-            }
-            OpCode::Eqri => {
-                if self.before[self.opcodes.input_a] == self.opcodes.input_b {
-                    1
-                } else {
-                    0
-                }
-            }
-            OpCode::Eqrr => {
-                if self.before[self.opcodes.input_a] == self.before[self.opcodes.input_b] {
-                    1
-                } else {
-                    0
-                }
-            }
-        }
+    fn get_result(&self, opcode: &OpCode) -> Option<usize> {
+        opcode.apply(self.opcodes.input_a, self.opcodes.input_b, &self.before)
     }
 }
 
 impl InputFile {
-    const OPCODES: [OpCode; 16] = [
-        OpCode::Addr,
-        OpCode::Addi,
-        OpCode::Mulr,
-        OpCode::Muli,
-        OpCode::Banr,
-        OpCode::Bani,
-        OpCode::Borr,
-        OpCode::Bori,
-        OpCode::Setr,
-        OpCode::Seti,
-        OpCode::Gtir,
-        OpCode::Gtri,
-        OpCode::Gtrr,
-        OpCode::Eqir,
-        OpCode::Eqri,
-        OpCode::Eqrr,
-    ];
-
     pub fn get_multiple_match_count(&self) -> usize {
         self.samples
             .iter()
             .map(|sample| {
-                InputFile::OPCODES
+                OpCode::ALL
                     .iter()
                     .filter(|opcode| sample.is_opcode(opcode))
                     .count()
@@ -139,11 +49,11 @@ impl InputFile {
     }
 
     pub fn get_opcodes(&self) -> Vec<OpCode> {
-        let mut todo = InputFile::OPCODES.iter().collect::<HashSet<_>>();
+        let mut todo = OpCode::ALL.iter().collect::<HashSet<_>>();
         let mut done = [None; 16];
 
         self.samples.iter().for_each(|sample| {
-            let matching_opcodes = InputFile::OPCODES
+            let matching_opcodes = OpCode::ALL
                 .iter()
                 .filter(|&opcode| !done.contains(&Some(opcode)))
                 .filter(|opcode| sample.is_opcode(opcode))
@@ -168,7 +78,7 @@ impl InputFile {
                 after: Vec::new(),
             };
 
-            let result = sample.get_result(&opcodes[instruction.opcode]);
+            let result = sample.get_result(&opcodes[instruction.opcode]).unwrap();
             registers[instruction.output] = result;
         });
 
@@ -237,6 +147,16 @@ impl FromStr for InputFile {
     }
 }
 
+pub fn get_part_one(input: &str) -> Result<usize, String> {
+    InputFile::from_str(input).map(|input_file| input_file.get_multiple_match_count())
+}
+
+pub fn get_part_two(input: &str) -> Result<usize, String> {
+    let input_file = InputFile::from_str(input)?;
+
+    Ok(input_file.get_register_zero_after_execution(&input_file.get_opcodes()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -140,6 +140,14 @@ impl Grid {
     }
 }
 
+pub fn get_part_one(input: &str) -> Result<usize, String> {
+    Grid::from_str(input).map(|mut grid| grid.get_square_metres_water().0)
+}
+
+pub fn get_part_two(input: &str) -> Result<usize, String> {
+    Grid::from_str(input).map(|mut grid| grid.get_square_metres_water().1)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,16 +1,17 @@
 use std::fs;
-use std::str::FromStr;
 
-use ::day17::*;
+use ::aoc_2018_17::*;
 
 fn main() {
     let input = fs::read_to_string("input.txt").expect("Error reading input.txt");
 
-    let mut grid = Grid::from_str(&input).unwrap();
+    println!(
+        "How many tiles can the water reach…? {}",
+        get_part_one(&input).unwrap(),
+    );
 
-    let (total, rest) = grid.get_square_metres_water();
-
-    println!("How many tiles can the water reach…? {}", total,);
-
-    println!("How many water tiles are left…? {}", rest,);
+    println!(
+        "How many water tiles are left…? {}",
+        get_part_two(&input).unwrap(),
+    );
 }
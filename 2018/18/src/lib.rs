@@ -1,9 +1,10 @@
 use std::collections::HashMap;
+use std::fmt;
 use std::str::FromStr;
 
-type Position = (isize, isize);
+use aoc_grid::{Grid, Point};
 
-#[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
 enum Acre {
     OpenGround,
     Trees,
@@ -12,115 +13,90 @@ enum Acre {
 
 #[derive(Debug, Eq, PartialEq)]
 pub struct LumberCollectionArea {
-    acres: HashMap<Position, Acre>,
+    acres: Grid<Acre>,
 }
 
 impl FromStr for LumberCollectionArea {
     type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let acres = s
-            .trim()
-            .lines()
-            .enumerate()
-            .flat_map(|(y, line)| {
-                line.chars().enumerate().map(move |(x, ch)| {
-                    (
-                        (x as isize, y as isize),
-                        match ch {
-                            '.' => Acre::OpenGround,
-                            '|' => Acre::Trees,
-                            '#' => Acre::Lumberyard,
-                            _ => panic!(r#"¯\_(ツ)_/¯"#),
-                        },
-                    )
-                })
-            })
-            .collect();
+        let acres = Grid::parse(s.trim(), |ch| match ch {
+            '.' => Ok(Acre::OpenGround),
+            '|' => Ok(Acre::Trees),
+            '#' => Ok(Acre::Lumberyard),
+            _ => Err(r#"¯\_(ツ)_/¯"#),
+        })
+        .map_err(|error| error.to_string())?;
 
         Ok(Self { acres })
     }
 }
 
-impl ToString for LumberCollectionArea {
-    fn to_string(&self) -> String {
-        let max = self.acres.keys().max().unwrap();
-
-        (0..=max.1)
-            .map(|y| {
-                (0..=max.0)
-                    .map(|x| match self.acres.get(&(x, y)).unwrap() {
-                        Acre::OpenGround => '.',
-                        Acre::Trees => '|',
-                        Acre::Lumberyard => '#',
-                    })
-                    .collect::<String>()
-            })
-            .collect::<Vec<_>>()
-            .join("\n")
+impl fmt::Display for LumberCollectionArea {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let area = self.acres.map(|acre| match acre {
+            Acre::OpenGround => '.',
+            Acre::Trees => '|',
+            Acre::Lumberyard => '#',
+        });
+
+        write!(f, "{}", area)
     }
 }
 
 impl LumberCollectionArea {
-    fn get_neighbours(&self, position: &Position) -> Vec<&Acre> {
-        (-1..=1)
-            .flat_map(|dy| (-1..=1).map(move |dx| (position.0 + dx, position.1 + dy)))
-            .filter(|(x, y)| *x >= 0 && *y >= 0)
-            .filter(|neighbour| *neighbour != *position)
-            .filter_map(|acre| self.acres.get(&acre))
+    fn get_neighbours(&self, position: &Point) -> Vec<&Acre> {
+        self.acres
+            .neighbours8(*position)
+            .map(|neighbour| &self.acres[neighbour])
             .collect()
     }
 
     fn get_next_minute(&self) -> Self {
-        let acres = self
-            .acres
-            .iter()
-            .map(|(position, contents)| {
-                let acre = match *contents {
-                    Acre::OpenGround => {
-                        let trees = self
-                            .get_neighbours(&position)
-                            .iter()
-                            .filter(|acre| matches!(*acre, Acre::Trees))
-                            .count();
-                        match trees {
-                            count if count >= 3 => Acre::Trees,
-                            _ => Acre::OpenGround,
-                        }
+        let mut acres = self.acres.clone();
+        for (position, contents) in self.acres.iter() {
+            let acre = match *contents {
+                Acre::OpenGround => {
+                    let trees = self
+                        .get_neighbours(&position)
+                        .iter()
+                        .filter(|acre| matches!(*acre, Acre::Trees))
+                        .count();
+                    match trees {
+                        count if count >= 3 => Acre::Trees,
+                        _ => Acre::OpenGround,
                     }
-                    Acre::Trees => {
-                        let lumberyards = self
-                            .get_neighbours(&position)
-                            .iter()
-                            .filter(|acre| matches!(*acre, Acre::Lumberyard))
-                            .count();
-                        match lumberyards {
-                            count if count >= 3 => Acre::Lumberyard,
-                            _ => Acre::Trees,
-                        }
+                }
+                Acre::Trees => {
+                    let lumberyards = self
+                        .get_neighbours(&position)
+                        .iter()
+                        .filter(|acre| matches!(*acre, Acre::Lumberyard))
+                        .count();
+                    match lumberyards {
+                        count if count >= 3 => Acre::Lumberyard,
+                        _ => Acre::Trees,
                     }
-                    Acre::Lumberyard => {
-                        let trees = self
-                            .get_neighbours(&position)
-                            .iter()
-                            .filter(|acre| matches!(*acre, Acre::Trees))
-                            .count();
-                        let lumberyards = self
-                            .get_neighbours(&position)
-                            .iter()
-                            .filter(|acre| matches!(*acre, Acre::Lumberyard))
-                            .count();
-                        match (trees, lumberyards) {
-                            (trees, lumberyards) if trees >= 1 && lumberyards >= 1 => {
-                                Acre::Lumberyard
-                            }
-                            _ => Acre::OpenGround,
-                        }
+                }
+                Acre::Lumberyard => {
+                    let trees = self
+                        .get_neighbours(&position)
+                        .iter()
+                        .filter(|acre| matches!(*acre, Acre::Trees))
+                        .count();
+                    let lumberyards = self
+                        .get_neighbours(&position)
+                        .iter()
+                        .filter(|acre| matches!(*acre, Acre::Lumberyard))
+                        .count();
+                    match (trees, lumberyards) {
+                        (trees, lumberyards) if trees >= 1 && lumberyards >= 1 => Acre::Lumberyard,
+                        _ => Acre::OpenGround,
                     }
-                };
-                (*position, acre)
-            })
-            .collect();
+                }
+            };
+            acres[position] = acre;
+        }
 
         Self { acres }
     }
@@ -171,6 +147,15 @@ impl LumberCollectionArea {
     }
 }
 
+pub fn get_part_one(input: &str) -> Result<usize, String> {
+    LumberCollectionArea::from_str(input).map(|area| area.get_resource_value_after_minutes(10))
+}
+
+pub fn get_part_two(input: &str) -> Result<usize, String> {
+    LumberCollectionArea::from_str(input)
+        .map(|area| area.get_resource_value_after_minutes(1_000_000_000))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,20 +1,17 @@
 use std::fs;
 
-use ::day18::*;
-use std::str::FromStr;
+use ::aoc_2018_18::*;
 
 fn main() {
     let input = fs::read_to_string("input.txt").expect("Error reading input.txt");
 
-    let area = LumberCollectionArea::from_str(&input).unwrap();
-
     println!(
         "What will the total resource value of the lumber collection area be after 10 minutes? {}",
-        area.get_resource_value_after_minutes(10),
+        get_part_one(&input).unwrap(),
     );
 
     println!(
         "What will the total resource value of the lumber collection area be after 1000000000 minutes? {}",
-        area.get_resource_value_after_minutes(1_000_000_000),
+        get_part_two(&input).unwrap(),
     );
 }
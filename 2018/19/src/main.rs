@@ -1,17 +1,17 @@
 use std::fs;
 
-use ::day19::*;
-use std::str::FromStr;
+use ::aoc_2018_19::*;
 
 fn main() {
     let input = fs::read_to_string("input.txt").expect("Error reading input.txt");
 
-    let mut cpu = Cpu::from_str(&input).unwrap();
-    cpu.execute();
-    println!("What value is left in register 0…? {}", cpu.registers[0]);
+    println!(
+        "What value is left in register 0…? {}",
+        get_part_one(&input).unwrap()
+    );
 
     println!(
         "What value is left in register 0…? {}",
-        cpu.execute_part_two()
+        get_part_two(&input).unwrap()
     );
 }
@@ -139,6 +139,14 @@ impl Map {
     }
 }
 
+pub fn get_part_one(input: &str) -> Result<usize, String> {
+    Map::from_str(input).map(|map| map.get_most_doors())
+}
+
+pub fn get_part_two(input: &str) -> Result<usize, String> {
+    Map::from_str(input).map(|map| map.get_rooms_over_1000_doors_away())
+}
+
 #[cfg(test)]
 mod tests {
     use parameterized::parameterized;
@@ -1,229 +1,81 @@
 use std::collections::HashSet;
 use std::str::FromStr;
 
-pub struct Cpu {
-    pub registers: [usize; 6],
-    instructions: Vec<Instruction>,
-    instruction_pointer: usize,
-}
-
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
-pub struct Instruction {
-    opcode: OpCode,
-    input_a: usize,
-    input_b: usize,
-    output: usize,
-}
-
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
-pub enum OpCode {
-    Addr,
-    Addi,
-    Mulr,
-    Muli,
-    Banr,
-    Bani,
-    Borr,
-    Bori,
-    Setr,
-    Seti,
-    Gtir,
-    Gtri,
-    Gtrr,
-    Eqir,
-    Eqri,
-    Eqrr,
-}
-
-impl FromStr for Cpu {
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match wrist_device::cpu(&s.trim()) {
-            Ok(input) => Ok(input),
-            Err(_) => Err(r#"¯\_(ツ)_/¯"#.to_string()),
-        }
+use elfcode::accelerate::Division;
+use elfcode::{Cpu, OpCode, State};
+
+/// The only place the program reads register `0` is an `eqrr` deciding
+/// whether to halt: each time that's about to run, the register it's
+/// compared against holds a value that would halt the program.
+fn get_halting_value(cpu: &Cpu) -> Option<usize> {
+    match cpu.instruction()? {
+        instruction if instruction.opcode != OpCode::Eqrr => None,
+        instruction if instruction.a == 0 => Some(cpu.registers()[instruction.b]),
+        instruction if instruction.b == 0 => Some(cpu.registers()[instruction.a]),
+        _ => None,
     }
 }
 
-impl Cpu {
-    fn get_instruction_pointer(&self) -> usize {
-        self.registers[self.instruction_pointer]
-    }
-
-    /// Execute instructions to completion.
-    ///
-    /// For *Part One*, the instructions make an `eqrr`, comparing the
-    /// values of register `0` to that of register `5`, to determine
-    /// whether or not to continue: the value in the latter is
-    /// therefore our answer.
-    pub fn get_register_zero_halt(&mut self) -> Result<usize, String> {
-        while self.get_instruction_pointer() < self.instructions.len() {
-            let instruction = self.instructions[self.get_instruction_pointer()];
-
-            if matches!(instruction.opcode, OpCode::Eqrr) {
-                return Ok(self.registers[instruction.input_a]);
-            }
-
-            let output = self.get_output(&instruction);
-            self.registers[instruction.output] = output;
-            self.registers[self.instruction_pointer] += 1;
+/// Every value that would halt the program, in the order the program
+/// checks them, until they start to repeat.
+fn get_halting_values(input: &str) -> Result<Vec<usize>, String> {
+    let mut cpu = Cpu::from_str(input).map_err(|error| error.to_string())?;
+    cpu.add_accelerator(Division);
+
+    let mut seen = HashSet::new();
+    let mut values = Vec::new();
+    while let State::Hooked = cpu.run_until(|cpu| get_halting_value(cpu).is_some()) {
+        let value = get_halting_value(&cpu).ok_or("No halting value")?;
+        if !seen.insert(value) {
+            return Ok(values);
         }
-
-        Err("".to_owned())
+        values.push(value);
+        cpu.step();
     }
 
-    /// Execute instructions to completion.
-    ///
-    /// For *Part Two*, the pattern for *Part One* holds true. However,
-    /// this time we're interested not in that initial value for
-    /// register `5` but the value before it finally loops.
-    pub fn execute_part_two(&mut self) -> Result<usize, String> {
-        let mut seen = HashSet::new();
-        let mut previous = 0;
-
-        while self.get_instruction_pointer() < self.instructions.len() {
-            let instruction = self.instructions[self.get_instruction_pointer()];
-
-            if matches!(instruction.opcode, OpCode::Eqrr) {
-                if !seen.insert(self.registers[instruction.input_a]) {
-                    return Ok(previous);
-                }
-                previous = self.registers[instruction.input_a];
-            }
-
-            let output = self.get_output(&instruction);
-            self.registers[instruction.output] = output;
-            self.registers[self.instruction_pointer] += 1;
-        }
+    Err("The program halted by itself.".to_owned())
+}
 
-        Err("".to_owned())
-    }
+pub fn get_part_one(input: &str) -> Result<usize, String> {
+    let mut cpu = Cpu::from_str(input).map_err(|error| error.to_string())?;
 
-    fn get_output(&self, instruction: &Instruction) -> usize {
-        match instruction.opcode {
-            OpCode::Addr => {
-                self.registers[instruction.input_a] + self.registers[instruction.input_b]
-            }
-            OpCode::Addi => self.registers[instruction.input_a] + instruction.input_b,
-            OpCode::Mulr => {
-                self.registers[instruction.input_a] * self.registers[instruction.input_b]
-            }
-            OpCode::Muli => self.registers[instruction.input_a] * instruction.input_b,
-            OpCode::Banr => {
-                self.registers[instruction.input_a] & self.registers[instruction.input_b]
-            }
-            OpCode::Bani => self.registers[instruction.input_a] & instruction.input_b,
-            OpCode::Borr => {
-                self.registers[instruction.input_a] | self.registers[instruction.input_b]
-            }
-            OpCode::Bori => self.registers[instruction.input_a] | instruction.input_b,
-            OpCode::Setr => self.registers[instruction.input_a],
-            OpCode::Seti => instruction.input_a,
-            OpCode::Gtir => {
-                if instruction.input_a > self.registers[instruction.input_b] {
-                    1
-                } else {
-                    0
-                }
-            }
-            OpCode::Gtri => {
-                if self.registers[instruction.input_a] > instruction.input_b {
-                    1
-                } else {
-                    0
-                }
-            }
-            OpCode::Gtrr => {
-                if self.registers[instruction.input_a] > self.registers[instruction.input_b] {
-                    1
-                } else {
-                    0
-                }
-            }
-            OpCode::Eqir => {
-                if instruction.input_a == self.registers[instruction.input_b] {
-                    1
-                } else {
-                    0
-                }
-            }
-            OpCode::Eqri => {
-                if self.registers[instruction.input_a] == instruction.input_b {
-                    1
-                } else {
-                    0
-                }
-            }
-            OpCode::Eqrr => {
-                if self.registers[instruction.input_a] == self.registers[instruction.input_b] {
-                    1
-                } else {
-                    0
-                }
-            }
-        }
+    match cpu.run_until(|cpu| get_halting_value(cpu).is_some()) {
+        State::Hooked => get_halting_value(&cpu).ok_or_else(|| "No halting value".to_owned()),
+        _ => Err("The program halted by itself.".to_owned()),
     }
 }
 
-peg::parser! {
-    grammar wrist_device() for str {
-        rule _() = [' ' | '\n']*
-
-        rule integer() -> usize
-            = n:$(['0'..='9']+) {? n.parse().or(Err("Invalid integer.")) }
-
-        rule opcode() -> OpCode
-            = o:$("addr" / "addi" / "mulr" / "muli" / "banr" / "bani" / "borr" / "bori" / "setr" / "seti" / "gtir" / "gtri" / "gtrr" / "eqir" / "eqri" / "eqrr")
-                {
-                    match o {
-                        "addr" => OpCode::Addr,
-                        "addi" => OpCode::Addi,
-                        "mulr" => OpCode::Mulr,
-                        "muli" => OpCode::Muli,
-                        "banr" => OpCode::Banr,
-                        "bani" => OpCode::Bani,
-                        "borr" => OpCode::Borr,
-                        "bori" => OpCode::Bori,
-                        "setr" => OpCode::Setr,
-                        "seti" => OpCode::Seti,
-                        "gtir" => OpCode::Gtir,
-                        "gtri" => OpCode::Gtri,
-                        "gtrr" => OpCode::Gtrr,
-                        "eqir" => OpCode::Eqir,
-                        "eqri" => OpCode::Eqri,
-                        "eqrr" => OpCode::Eqrr,
-                        _ => panic!(r#"¯\_(ツ)_/¯"#),
-                    }
-                }
-
-        rule instructions() -> Instruction
-            = opcode:opcode()
-              _
-              ints:integer() ++ _
-                {
-                    Instruction {
-                        opcode,
-                        input_a: ints[0],
-                        input_b: ints[1],
-                        output: ints[2],
-                    }
-                }
+pub fn get_part_two(input: &str) -> Result<usize, String> {
+    get_halting_values(input)?
+        .last()
+        .copied()
+        .ok_or_else(|| "No halting value".to_owned())
+}
 
-        rule ip() -> usize
-            = "#ip" _ i:integer()
-                { i }
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Steps register `1` through a cycle of the values 0 to 15, halting
+    /// if register `0` is ever one more than it.
+    const INPUT: &str = r#"#ip 5
+seti 7 0 1
+muli 1 5 1
+addi 1 3 1
+bani 1 15 1
+addi 1 1 3
+eqrr 3 0 2
+addr 2 5 5
+seti 0 0 5"#;
+
+    #[test]
+    fn test_part_one() {
+        assert_eq!(Ok(7), get_part_one(INPUT));
+    }
 
-        pub rule cpu() -> Cpu
-            = ip:ip()
-              _
-              instructions:instructions() ++ _
-                {
-                    Cpu {
-                        registers: [0; 6],
-                        instructions,
-                        instruction_pointer: ip,
-                    }
-                }
+    #[test]
+    fn test_part_two() {
+        assert_eq!(Ok(16), get_halting_values(INPUT).map(|values| values.len()));
+        assert_eq!(Ok(8), get_part_two(INPUT));
     }
 }
@@ -1,7 +1,9 @@
-use std::cmp::{Ordering, Reverse};
-use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
 use std::str::FromStr;
 
+use aoc_search::{astar, State};
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 enum Type {
     Rocky,
@@ -15,23 +17,53 @@ enum Tool {
     ClimbingGear,
 }
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
 struct Move {
     region: Coordinate,
     tool: Option<Tool>,
-    minutes: usize,
-    distance_to_target: usize,
 }
 
-impl PartialOrd for Move {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(&other))
+impl State for Move {
+    type Context = Cave;
+
+    /// Either step into a neighbouring region that suits the current tool or
+    /// switch to the other tool suited to this region.
+    fn successors(&self, cave: &Cave) -> Vec<(Self, usize)> {
+        let mut next = cave
+            .get_neighbouring_regions(&self.region)
+            .into_iter()
+            .filter(|region| cave.get_is_tool_ok_for_region(&self.tool, region))
+            .map(|region| {
+                let next = Move {
+                    region,
+                    tool: self.tool,
+                };
+                (next, 1)
+            })
+            .collect::<Vec<_>>();
+
+        next.extend(
+            cave.get_tools_ok_for_region(&self.region)
+                .into_iter()
+                .filter(|tool| *tool != self.tool)
+                .map(|tool| {
+                    let next = Move {
+                        region: self.region,
+                        tool,
+                    };
+                    (next, 7)
+                }),
+        );
+
+        next
+    }
+
+    fn is_goal(&self, cave: &Cave) -> bool {
+        self.region == cave.target && self.tool == Some(Tool::Torch)
     }
-}
 
-impl Ord for Move {
-    fn cmp(&self, other: &Self) -> Ordering {
-        (self.minutes + self.distance_to_target).cmp(&(other.minutes + other.distance_to_target))
+    fn heuristic(&self, cave: &Cave) -> usize {
+        cave.get_manhattan_distance(&self.region, &cave.target)
     }
 }
 
@@ -39,7 +71,7 @@ type Coordinate = (usize, usize);
 
 pub struct Cave {
     depth: usize,
-    erosion: HashMap<Coordinate, usize>,
+    erosion: RefCell<HashMap<Coordinate, usize>>,
     target: Coordinate,
 }
 
@@ -59,7 +91,7 @@ impl FromStr for Cave {
 
         Ok(Self {
             depth,
-            erosion: HashMap::new(),
+            erosion: RefCell::new(HashMap::new()),
             target: (target[0], target[1]),
         })
     }
@@ -85,8 +117,8 @@ impl Cave {
         .collect()
     }
 
-    fn get_type(&mut self, coordinate: &Coordinate) -> Type {
-        match self.get_erosion_level(&coordinate) % 3 {
+    fn get_type(&self, coordinate: &Coordinate) -> Type {
+        match self.get_erosion_level(coordinate) % 3 {
             0 => Type::Rocky,
             1 => Type::Wet,
             2 => Type::Narrow,
@@ -94,20 +126,20 @@ impl Cave {
         }
     }
 
-    fn get_erosion_level(&mut self, coordinate: &Coordinate) -> usize {
-        if self.erosion.contains_key(&coordinate) {
-            return *self.erosion.get(&coordinate).unwrap();
+    fn get_erosion_level(&self, coordinate: &Coordinate) -> usize {
+        if let Some(erosion) = self.erosion.borrow().get(coordinate) {
+            return *erosion;
         }
 
-        let geologic_index = self.get_geologic_index(&coordinate);
+        let geologic_index = self.get_geologic_index(coordinate);
         let erosion = (geologic_index + self.depth) % 20_183;
 
-        self.erosion.insert(*coordinate, erosion);
+        self.erosion.borrow_mut().insert(*coordinate, erosion);
 
         erosion
     }
 
-    fn get_geologic_index(&mut self, coordinate: &Coordinate) -> usize {
+    fn get_geologic_index(&self, coordinate: &Coordinate) -> usize {
         match coordinate {
             (0, 0) => 0,
             target if *target == self.target => 0,
@@ -117,7 +149,7 @@ impl Cave {
         }
     }
 
-    pub fn get_risk_level(&mut self) -> usize {
+    pub fn get_risk_level(&self) -> usize {
         (0..=self.target.1)
             .map(|y| {
                 (0..=self.target.0)
@@ -131,7 +163,7 @@ impl Cave {
             .sum()
     }
 
-    fn get_is_tool_ok_for_region(&mut self, tool: &Option<Tool>, region: &Coordinate) -> bool {
+    fn get_is_tool_ok_for_region(&self, tool: &Option<Tool>, region: &Coordinate) -> bool {
         match self.get_type(region) {
             Type::Rocky => {
                 matches!(tool, Some(Tool::ClimbingGear)) || matches!(tool, Some(Tool::Torch))
@@ -141,7 +173,7 @@ impl Cave {
         }
     }
 
-    fn get_tools_ok_for_region(&mut self, region: &Coordinate) -> Vec<Option<Tool>> {
+    fn get_tools_ok_for_region(&self, region: &Coordinate) -> Vec<Option<Tool>> {
         match self.get_type(region) {
             Type::Rocky => vec![Some(Tool::ClimbingGear), Some(Tool::Torch)],
             Type::Wet => vec![Some(Tool::ClimbingGear), None],
@@ -149,89 +181,35 @@ impl Cave {
         }
     }
 
-    fn get_right_tool(
-        &mut self,
-        current: &Coordinate,
-        next: &Coordinate,
-    ) -> Result<Option<Tool>, String> {
-        let next_tools = self.get_tools_ok_for_region(next);
-
-        self.get_tools_ok_for_region(current)
-            .into_iter()
-            .find(|tool| next_tools.contains(tool))
-            .ok_or_else(|| "Unable to find the right tool".to_owned())
-    }
-
-    pub fn get_quickest_time_to_target(&mut self) -> Result<usize, String> {
-        let mut queue = BinaryHeap::new();
-        let mut discovered = HashSet::new();
-
-        queue.push(Reverse(Move {
+    pub fn get_quickest_time_to_target(&self) -> Result<usize, String> {
+        let start = Move {
             region: (0, 0),
             tool: Some(Tool::Torch),
-            minutes: 0,
-            distance_to_target: self.get_manhattan_distance(&(0, 0), &self.target),
-        }));
-
-        while !queue.is_empty() {
-            let current = queue.pop().unwrap().0;
-            discovered.insert((current.region, current.tool));
-
-            if current.region == self.target {
-                return match current.tool {
-                    Some(Tool::Torch) => Ok(current.minutes),
-                    _ => Ok(current.minutes + 7),
-                };
-            }
-
-            let next_moves = self
-                .get_neighbouring_regions(&current.region)
-                .iter()
-                .map(|neighbouring_region| {
-                    let current_region_type = self.get_type(&current.region);
-                    let neighbouring_region_type = self.get_type(neighbouring_region);
-                    if neighbouring_region_type == current_region_type
-                        || self.get_is_tool_ok_for_region(&current.tool, &neighbouring_region)
-                    {
-                        Move {
-                            region: *neighbouring_region,
-                            tool: current.tool,
-                            minutes: current.minutes + 1,
-                            distance_to_target: self
-                                .get_manhattan_distance(&neighbouring_region, &self.target),
-                        }
-                    } else {
-                        Move {
-                            region: *neighbouring_region,
-                            tool: self
-                                .get_right_tool(&current.region, neighbouring_region)
-                                .unwrap(),
-                            minutes: current.minutes + 8,
-                            distance_to_target: self
-                                .get_manhattan_distance(&neighbouring_region, &self.target),
-                        }
-                    }
-                })
-                .filter(|next| !discovered.contains(&(next.region, next.tool)))
-                .collect::<Vec<_>>();
-            for next_move in next_moves {
-                queue.push(Reverse(next_move));
-            }
-        }
+        };
 
-        Err("No path to target found.".to_owned())
+        astar(start, self)
+            .cost()
+            .ok_or_else(|| "No path to target found.".to_owned())
     }
 }
 
+pub fn get_part_one(input: &str) -> Result<usize, String> {
+    Cave::from_str(input).map(|cave| cave.get_risk_level())
+}
+
+pub fn get_part_two(input: &str) -> Result<usize, String> {
+    Cave::from_str(input)?.get_quickest_time_to_target()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let mut cave = Cave {
+        let cave = Cave {
             depth: 510,
-            erosion: HashMap::new(),
+            erosion: RefCell::new(HashMap::new()),
             target: (10, 10),
         };
 
@@ -255,9 +233,9 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let mut cave = Cave {
+        let cave = Cave {
             depth: 510,
-            erosion: HashMap::new(),
+            erosion: RefCell::new(HashMap::new()),
             target: (10, 10),
         };
 
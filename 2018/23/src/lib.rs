@@ -102,6 +102,21 @@ pub fn get_teleportation_position_distance(nanobots: &[Nanobot]) -> usize {
         .unwrap()
 }
 
+pub fn get_part_one(input: &str) -> Result<usize, String> {
+    let nanobots = wrist_device::nanobots(input.trim()).map_err(|error| error.to_string())?;
+
+    Ok(get_nanobots_in_range(
+        get_strongest_nanobot(&nanobots),
+        &nanobots,
+    ))
+}
+
+pub fn get_part_two(input: &str) -> Result<usize, String> {
+    let nanobots = wrist_device::nanobots(input.trim()).map_err(|error| error.to_string())?;
+
+    Ok(get_teleportation_position_distance(&nanobots))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
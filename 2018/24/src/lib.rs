@@ -262,6 +262,16 @@ peg::parser! {
     }
 }
 
+pub fn get_part_one(input: &str) -> Result<usize, String> {
+    reindeer::reindeer(input)
+        .map_err(|error| error.to_string())?
+        .fight()
+}
+
+pub fn get_part_two(input: &str) -> usize {
+    get_immune_system_boost(input)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,19 +1,17 @@
 use std::fs;
 
-use ::day24::*;
+use ::aoc_2018_24::*;
 
 fn main() {
     let input = fs::read_to_string("input.txt").expect("Error reading input.txt");
 
-    let mut reindeer = reindeer::reindeer(&input).unwrap();
-
     println!(
         "…how many units would the winning army have? {}",
-        reindeer.fight().unwrap(),
+        get_part_one(&input).unwrap(),
     );
 
     println!(
         "How many units does the immune system have left…? {}",
-        get_immune_system_boost(&input),
+        get_part_two(&input),
     );
 }
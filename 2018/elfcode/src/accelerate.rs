@@ -0,0 +1,320 @@
+use crate::{Instruction, OpCode, Registers};
+
+/// Recognises a loop that can be computed directly rather than stepped
+/// through an instruction at a time.
+pub trait Accelerator {
+    /// Whether the instructions from `start` are this loop, given the
+    /// register bound to the instruction pointer.
+    fn recognise(&self, instructions: &[Instruction], ip: Option<usize>, start: usize) -> bool;
+
+    /// Applies the effect of the loop at `start` to `registers`, giving
+    /// the instruction to carry on from; `None` leaves the loop to run as
+    /// written, such as when the registers would make it behave unusually.
+    fn apply(
+        &self,
+        instructions: &[Instruction],
+        start: usize,
+        registers: &mut Registers,
+    ) -> Option<usize>;
+}
+
+/// Whether an instruction with commutative inputs `a` and `b` reads the
+/// registers `x` and `y`, in either order.
+fn reads(instruction: &Instruction, x: usize, y: usize) -> bool {
+    (instruction.a, instruction.b) == (x, y) || (instruction.a, instruction.b) == (y, x)
+}
+
+/// The registers of a `sum` of every divisor of a `target`, found by
+/// testing every product of an `outer` and `inner` counter:
+///
+/// ```text
+/// seti 1 _ outer
+/// seti 1 _ inner
+/// mulr outer inner t
+/// eqrr t target t
+/// addr t ip ip
+/// addi ip 1 ip
+/// addr outer sum sum
+/// addi inner 1 inner
+/// gtrr inner target t
+/// addr ip t ip
+/// seti <start + 1> _ ip
+/// addi outer 1 outer
+/// gtrr outer target t
+/// addr t ip ip
+/// seti <start> _ ip
+/// ```
+struct Divisors {
+    ip: usize,
+    sum: usize,
+    outer: usize,
+    inner: usize,
+    target: usize,
+    t: usize,
+}
+
+impl Divisors {
+    fn find(instructions: &[Instruction], start: usize) -> Option<Self> {
+        use OpCode::*;
+
+        let window = instructions.get(start..start + 15)?;
+        let (outer, inner) = (window[0].c, window[1].c);
+        let (t, target, ip) = (window[2].c, window[3].b, window[4].c);
+        let sum = window[6].c;
+
+        let expected = [
+            (Seti, window[0].a == 1),
+            (Seti, window[1].a == 1),
+            (Mulr, reads(&window[2], outer, inner)),
+            (Eqrr, window[3].a == t && window[3].c == t),
+            (Addr, reads(&window[4], t, ip)),
+            (Addi, (window[5].a, window[5].b, window[5].c) == (ip, 1, ip)),
+            (Addr, reads(&window[6], outer, sum)),
+            (
+                Addi,
+                (window[7].a, window[7].b, window[7].c) == (inner, 1, inner),
+            ),
+            (
+                Gtrr,
+                (window[8].a, window[8].b, window[8].c) == (inner, target, t),
+            ),
+            (Addr, reads(&window[9], ip, t) && window[9].c == ip),
+            (Seti, window[10].a == start + 1 && window[10].c == ip),
+            (
+                Addi,
+                (window[11].a, window[11].b, window[11].c) == (outer, 1, outer),
+            ),
+            (
+                Gtrr,
+                (window[12].a, window[12].b, window[12].c) == (outer, target, t),
+            ),
+            (Addr, reads(&window[13], t, ip) && window[13].c == ip),
+            (Seti, window[14].a == start && window[14].c == ip),
+        ];
+        let mut registers = [sum, outer, inner, target, t, ip];
+        registers.sort_unstable();
+
+        match expected
+            .iter()
+            .zip(window)
+            .all(|((opcode, ok), instruction)| instruction.opcode == *opcode && *ok)
+            && registers.windows(2).all(|pair| pair[0] != pair[1])
+            && registers[5] < 6
+        {
+            true => Some(Divisors {
+                ip,
+                sum,
+                outer,
+                inner,
+                target,
+                t,
+            }),
+            false => None,
+        }
+    }
+}
+
+/// Adds the sum of the divisors of one register to another, as 2018/19
+/// does, slowly.
+pub struct DivisorSum;
+
+impl Accelerator for DivisorSum {
+    fn recognise(&self, instructions: &[Instruction], ip: Option<usize>, start: usize) -> bool {
+        Divisors::find(instructions, start).is_some_and(|divisors| Some(divisors.ip) == ip)
+    }
+
+    fn apply(
+        &self,
+        instructions: &[Instruction],
+        start: usize,
+        registers: &mut Registers,
+    ) -> Option<usize> {
+        let divisors = Divisors::find(instructions, start)?;
+        let target = registers[divisors.target];
+        if target == 0 {
+            return None;
+        }
+
+        let sum = (1..)
+            .take_while(|divisor| divisor * divisor <= target)
+            .filter(|divisor| target.is_multiple_of(*divisor))
+            .map(|divisor| match target / divisor {
+                pair if pair == divisor => divisor,
+                pair => divisor + pair,
+            })
+            .sum::<usize>();
+
+        registers[divisors.sum] += sum;
+        registers[divisors.outer] = target + 1;
+        registers[divisors.inner] = target + 1;
+        registers[divisors.t] = 1;
+
+        Some(start + 15)
+    }
+}
+
+/// The registers of a `quotient` found by counting up until the next
+/// multiple of a `divisor` exceeds a `dividend`:
+///
+/// ```text
+/// seti 0 _ quotient
+/// addi quotient 1 t
+/// muli t <divisor> t
+/// gtrr t dividend t
+/// addr t ip ip
+/// addi ip 1 ip
+/// seti <start + 8> _ ip
+/// addi quotient 1 quotient
+/// seti <start> _ ip
+/// ```
+struct Quotient {
+    ip: usize,
+    quotient: usize,
+    t: usize,
+    dividend: usize,
+    divisor: usize,
+}
+
+impl Quotient {
+    fn find(instructions: &[Instruction], start: usize) -> Option<Self> {
+        use OpCode::*;
+
+        let window = instructions.get(start..start + 9)?;
+        let (quotient, t, divisor) = (window[0].c, window[1].c, window[2].b);
+        let (dividend, ip) = (window[3].b, window[4].c);
+
+        let expected = [
+            (Seti, window[0].a == 0),
+            (Addi, (window[1].a, window[1].b) == (quotient, 1)),
+            (Muli, (window[2].a, window[2].c) == (t, t) && divisor > 0),
+            (Gtrr, (window[3].a, window[3].c) == (t, t)),
+            (Addr, reads(&window[4], t, ip)),
+            (Addi, (window[5].a, window[5].b, window[5].c) == (ip, 1, ip)),
+            (Seti, window[6].a == start + 8 && window[6].c == ip),
+            (
+                Addi,
+                (window[7].a, window[7].b, window[7].c) == (quotient, 1, quotient),
+            ),
+            (Seti, window[8].a == start && window[8].c == ip),
+        ];
+        let mut registers = [quotient, t, dividend, ip];
+        registers.sort_unstable();
+
+        match expected
+            .iter()
+            .zip(window)
+            .all(|((opcode, ok), instruction)| instruction.opcode == *opcode && *ok)
+            && registers.windows(2).all(|pair| pair[0] != pair[1])
+            && registers[3] < 6
+        {
+            true => Some(Quotient {
+                ip,
+                quotient,
+                t,
+                dividend,
+                divisor,
+            }),
+            false => None,
+        }
+    }
+}
+
+/// Divides one register by a constant, as 2018/21 does, slowly.
+pub struct Division;
+
+impl Accelerator for Division {
+    fn recognise(&self, instructions: &[Instruction], ip: Option<usize>, start: usize) -> bool {
+        Quotient::find(instructions, start).is_some_and(|quotient| Some(quotient.ip) == ip)
+    }
+
+    fn apply(
+        &self,
+        instructions: &[Instruction],
+        start: usize,
+        registers: &mut Registers,
+    ) -> Option<usize> {
+        let quotient = Quotient::find(instructions, start)?;
+
+        registers[quotient.quotient] = registers[quotient.dividend] / quotient.divisor;
+        registers[quotient.t] = 1;
+
+        Some(start + 9)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use aoc_error::Result;
+
+    use crate::{Cpu, State};
+
+    const DIVISORS: &str = r#"#ip 4
+addi 4 16 4
+seti 1 1 1
+seti 1 7 3
+mulr 1 3 5
+eqrr 5 2 5
+addr 5 4 4
+addi 4 1 4
+addr 1 0 0
+addi 3 1 3
+gtrr 3 2 5
+addr 4 5 4
+seti 2 3 4
+addi 1 1 1
+gtrr 1 2 5
+addr 5 4 4
+seti 1 7 4
+mulr 4 4 4
+seti 60 0 2
+seti 0 0 4"#;
+
+    const DIVISION: &str = r#"#ip 1
+seti 100000 0 4
+seti 0 6 3
+addi 3 1 2
+muli 2 256 2
+gtrr 2 4 2
+addr 2 1 1
+addi 1 1 1
+seti 9 3 1
+addi 3 1 3
+seti 1 3 1
+setr 3 6 4"#;
+
+    fn run_both(input: &str, accelerator: impl Accelerator + 'static) -> Result<(Cpu, Cpu)> {
+        let mut accelerated = Cpu::from_str(input)?;
+        let mut slow = Cpu::from_str(input)?;
+        accelerated.add_accelerator(accelerator);
+
+        assert_eq!(State::Halted, accelerated.run());
+        assert_eq!(State::Halted, slow.run());
+
+        Ok((accelerated, slow))
+    }
+
+    #[test]
+    fn test_divisor_sum() -> Result<()> {
+        let (accelerated, slow) = run_both(DIVISORS, DivisorSum)?;
+
+        assert_eq!(vec![1], accelerated.recognised().collect::<Vec<_>>());
+        assert_eq!(168, accelerated.registers()[0]);
+        assert_eq!(slow.registers(), accelerated.registers());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_division() -> Result<()> {
+        let (accelerated, slow) = run_both(DIVISION, Division)?;
+
+        assert_eq!(vec![1], accelerated.recognised().collect::<Vec<_>>());
+        assert_eq!(390, accelerated.registers()[4]);
+        assert_eq!(slow.registers(), accelerated.registers());
+
+        Ok(())
+    }
+}
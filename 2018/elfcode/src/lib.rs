@@ -0,0 +1,434 @@
+#![deny(clippy::expect_used, clippy::unwrap_used)]
+
+use std::collections::BTreeSet;
+use std::fmt;
+use std::str::FromStr;
+
+use aoc_error::{Error, Result};
+
+pub mod accelerate;
+
+pub use accelerate::Accelerator;
+
+pub type Registers = [usize; 6];
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum OpCode {
+    Addr,
+    Addi,
+    Mulr,
+    Muli,
+    Banr,
+    Bani,
+    Borr,
+    Bori,
+    Setr,
+    Seti,
+    Gtir,
+    Gtri,
+    Gtrr,
+    Eqir,
+    Eqri,
+    Eqrr,
+}
+
+impl OpCode {
+    pub const ALL: [OpCode; 16] = [
+        OpCode::Addr,
+        OpCode::Addi,
+        OpCode::Mulr,
+        OpCode::Muli,
+        OpCode::Banr,
+        OpCode::Bani,
+        OpCode::Borr,
+        OpCode::Bori,
+        OpCode::Setr,
+        OpCode::Seti,
+        OpCode::Gtir,
+        OpCode::Gtri,
+        OpCode::Gtrr,
+        OpCode::Eqir,
+        OpCode::Eqri,
+        OpCode::Eqrr,
+    ];
+
+    pub fn mnemonic(&self) -> &'static str {
+        match self {
+            OpCode::Addr => "addr",
+            OpCode::Addi => "addi",
+            OpCode::Mulr => "mulr",
+            OpCode::Muli => "muli",
+            OpCode::Banr => "banr",
+            OpCode::Bani => "bani",
+            OpCode::Borr => "borr",
+            OpCode::Bori => "bori",
+            OpCode::Setr => "setr",
+            OpCode::Seti => "seti",
+            OpCode::Gtir => "gtir",
+            OpCode::Gtri => "gtri",
+            OpCode::Gtrr => "gtrr",
+            OpCode::Eqir => "eqir",
+            OpCode::Eqri => "eqri",
+            OpCode::Eqrr => "eqrr",
+        }
+    }
+
+    /// The value this opcode would write, given inputs `a` and `b`, each
+    /// read as a register or taken as is depending on the opcode.
+    ///
+    /// Returns `None` if it reads a register that doesn't exist.
+    pub fn apply(&self, a: usize, b: usize, registers: &[usize]) -> Option<usize> {
+        let register = |r: usize| registers.get(r).copied();
+
+        let value = match self {
+            OpCode::Addr => register(a)? + register(b)?,
+            OpCode::Addi => register(a)? + b,
+            OpCode::Mulr => register(a)? * register(b)?,
+            OpCode::Muli => register(a)? * b,
+            OpCode::Banr => register(a)? & register(b)?,
+            OpCode::Bani => register(a)? & b,
+            OpCode::Borr => register(a)? | register(b)?,
+            OpCode::Bori => register(a)? | b,
+            OpCode::Setr => register(a)?,
+            OpCode::Seti => a,
+            OpCode::Gtir => usize::from(a > register(b)?),
+            OpCode::Gtri => usize::from(register(a)? > b),
+            OpCode::Gtrr => usize::from(register(a)? > register(b)?),
+            OpCode::Eqir => usize::from(a == register(b)?),
+            OpCode::Eqri => usize::from(register(a)? == b),
+            OpCode::Eqrr => usize::from(register(a)? == register(b)?),
+        };
+
+        Some(value)
+    }
+}
+
+impl FromStr for OpCode {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        OpCode::ALL
+            .into_iter()
+            .find(|opcode| opcode.mnemonic() == s)
+            .ok_or_else(|| Error::invalid_input(format!("unknown opcode {:?}", s)))
+    }
+}
+
+impl fmt::Display for OpCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.mnemonic())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Instruction {
+    pub opcode: OpCode,
+    pub a: usize,
+    pub b: usize,
+    pub c: usize,
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {} {}", self.opcode, self.a, self.b, self.c)
+    }
+}
+
+/// A program, along with the register its instruction pointer is bound
+/// to by any `#ip` directive.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Program {
+    pub ip: Option<usize>,
+    pub instructions: Vec<Instruction>,
+}
+
+peg::parser! {
+    grammar elfcode() for str {
+        rule _() = [' ' | '\t']+
+
+        rule integer() -> usize
+            = n:$(['0'..='9']+) {? n.parse().or(Err("integer")) }
+
+        rule opcode() -> OpCode
+            = o:$(['a'..='z']+) {? o.parse().or(Err("opcode")) }
+
+        rule instruction() -> Instruction
+            = opcode:opcode() _ a:integer() _ b:integer() _ c:integer()
+                { Instruction { opcode, a, b, c } }
+
+        rule ip() -> usize
+            = "#ip" _ ip:integer() "\n"+ { ip }
+
+        pub rule program() -> Program
+            = ip:ip()? instructions:instruction() ++ ("\n"+)
+                { Program { ip, instructions } }
+    }
+}
+
+impl FromStr for Program {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(elfcode::program(s.trim())?)
+    }
+}
+
+impl fmt::Display for Program {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(ip) = self.ip {
+            writeln!(f, "#ip {}", ip)?;
+        }
+        for instruction in &self.instructions {
+            writeln!(f, "{}", instruction)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Why the CPU stopped running.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum State {
+    Halted,
+    /// A hook asked to stop before the instruction at the pointer.
+    Hooked,
+    /// A watched register changed.
+    Watch {
+        register: usize,
+        old: usize,
+        new: usize,
+    },
+}
+
+pub struct Cpu {
+    registers: Registers,
+    program: Program,
+    pointer: usize,
+    watches: BTreeSet<usize>,
+    accelerators: Vec<Box<dyn Accelerator>>,
+    /// For each instruction, the accelerator recognising a loop there.
+    recognised: Vec<Option<usize>>,
+}
+
+impl FromStr for Cpu {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(Cpu::new(Program::from_str(s)?))
+    }
+}
+
+impl Cpu {
+    pub fn new(program: Program) -> Self {
+        Cpu {
+            registers: [0; 6],
+            recognised: vec![None; program.instructions.len()],
+            program,
+            pointer: 0,
+            watches: BTreeSet::new(),
+            accelerators: Vec::new(),
+        }
+    }
+
+    /// Runs loops that `accelerator` recognises in a single step.
+    pub fn add_accelerator(&mut self, accelerator: impl Accelerator + 'static) {
+        let index = self.accelerators.len();
+        for (start, recognised) in self.recognised.iter_mut().enumerate() {
+            if recognised.is_none()
+                && accelerator.recognise(&self.program.instructions, self.program.ip, start)
+            {
+                *recognised = Some(index);
+            }
+        }
+        self.accelerators.push(Box::new(accelerator));
+    }
+
+    /// The loops found by the accelerators, by where they start.
+    pub fn recognised(&self) -> impl Iterator<Item = usize> + '_ {
+        self.recognised
+            .iter()
+            .enumerate()
+            .filter_map(|(start, recognised)| recognised.map(|_| start))
+    }
+
+    pub fn program(&self) -> &Program {
+        &self.program
+    }
+
+    pub fn registers(&self) -> &Registers {
+        &self.registers
+    }
+
+    pub fn registers_mut(&mut self) -> &mut Registers {
+        &mut self.registers
+    }
+
+    pub fn pointer(&self) -> usize {
+        self.pointer
+    }
+
+    /// The instruction about to run, if the program hasn't halted.
+    pub fn instruction(&self) -> Option<&Instruction> {
+        self.program.instructions.get(self.pointer)
+    }
+
+    /// Starts or stops stopping whenever `register` changes; returns
+    /// whether it's now watched.
+    pub fn toggle_watch(&mut self, register: usize) -> bool {
+        if self.watches.remove(&register) {
+            false
+        } else {
+            self.watches.insert(register)
+        }
+    }
+
+    /// Executes a single instruction, or a whole recognised loop.
+    ///
+    /// Returns `None` if the CPU can carry on; otherwise the `State` that
+    /// stopped it.
+    pub fn step(&mut self) -> Option<State> {
+        let instruction = *self.instruction()?;
+        let before = self.registers;
+
+        if let Some(ip) = self.program.ip {
+            self.registers[ip] = self.pointer;
+        }
+
+        let accelerated = self.recognised[self.pointer].and_then(|index| {
+            self.accelerators[index].apply(
+                &self.program.instructions,
+                self.pointer,
+                &mut self.registers,
+            )
+        });
+        match accelerated {
+            Some(pointer) => self.pointer = pointer,
+            None => {
+                let Some(value) =
+                    instruction
+                        .opcode
+                        .apply(instruction.a, instruction.b, &self.registers)
+                else {
+                    self.pointer = self.program.instructions.len();
+                    return Some(State::Halted);
+                };
+                match self.registers.get_mut(instruction.c) {
+                    Some(register) => *register = value,
+                    None => {
+                        self.pointer = self.program.instructions.len();
+                        return Some(State::Halted);
+                    }
+                }
+                self.pointer = match self.program.ip {
+                    Some(ip) => self.registers[ip] + 1,
+                    None => self.pointer + 1,
+                };
+            }
+        }
+        if let Some(ip) = self.program.ip {
+            self.registers[ip] = self.pointer;
+        }
+
+        self.watches
+            .iter()
+            .find(|register| before[**register] != self.registers[**register])
+            .map(|&register| State::Watch {
+                register,
+                old: before[register],
+                new: self.registers[register],
+            })
+    }
+
+    /// Runs until the program halts, a watched register changes or `hook`
+    /// returns `true` for the instruction about to run.
+    ///
+    /// A hook stops before its instruction runs, so `step` past it before
+    /// running on.
+    pub fn run_until(&mut self, mut hook: impl FnMut(&Cpu) -> bool) -> State {
+        loop {
+            if self.instruction().is_none() {
+                return State::Halted;
+            }
+            if hook(self) {
+                return State::Hooked;
+            }
+            if let Some(state) = self.step() {
+                return state;
+            }
+        }
+    }
+
+    pub fn run(&mut self) -> State {
+        self.run_until(|_| false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROGRAM: &str = r#"#ip 0
+seti 5 0 1
+seti 6 0 2
+addi 0 1 0
+addr 1 2 3
+setr 1 0 0
+seti 8 0 4
+seti 9 0 5"#;
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        let program = Program::from_str(PROGRAM)?;
+
+        assert_eq!(Some(0), program.ip);
+        assert_eq!(7, program.instructions.len());
+        assert_eq!(format!("{}\n", PROGRAM), program.to_string());
+        assert!(Program::from_str("seti 5 0 1\nnope 1 2 3").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_opcodes() {
+        let registers = [3, 2, 1, 1];
+
+        assert_eq!(Some(2), OpCode::Mulr.apply(2, 1, &registers));
+        assert_eq!(Some(2), OpCode::Addi.apply(2, 1, &registers));
+        assert_eq!(Some(2), OpCode::Seti.apply(2, 1, &registers));
+        assert_eq!(Some(3), OpCode::Addr.apply(2, 1, &registers));
+        assert_eq!(None, OpCode::Addr.apply(2, 4, &registers));
+    }
+
+    #[test]
+    fn test_run() -> Result<()> {
+        let mut cpu = Cpu::from_str(PROGRAM)?;
+
+        assert_eq!(State::Halted, cpu.run());
+        assert_eq!([7, 5, 6, 0, 0, 9], *cpu.registers());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_hooks() -> Result<()> {
+        let mut cpu = Cpu::from_str(PROGRAM)?;
+
+        let state = cpu.run_until(|cpu| cpu.instruction().map(|i| i.opcode) == Some(OpCode::Setr));
+        assert_eq!(State::Hooked, state);
+        assert_eq!(4, cpu.pointer());
+
+        assert!(cpu.toggle_watch(5));
+        assert_eq!(None, cpu.step());
+        assert_eq!(
+            State::Watch {
+                register: 5,
+                old: 0,
+                new: 9
+            },
+            cpu.run()
+        );
+        assert_eq!(State::Halted, cpu.run());
+
+        Ok(())
+    }
+}
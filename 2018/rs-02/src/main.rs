@@ -1,6 +1,6 @@
 use std::fs;
 
-use ::day02::*;
+use ::aoc_2018_rs_02::*;
 
 fn main() {
     let input = fs::read_to_string("input.txt").expect("Error reading input.txt");
@@ -1,6 +1,6 @@
 use std::fs;
 
-use ::day08::*;
+use ::aoc_2018_rs_08::*;
 
 fn main() {
     let input = fs::read_to_string("input.txt").expect("Error reading input.txt");
@@ -1,6 +1,6 @@
 use std::fs;
 
-use day10::*;
+use aoc_2018_rs_10::*;
 
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
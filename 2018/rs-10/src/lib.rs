@@ -131,7 +131,7 @@ impl Sky {
     }
 }
 
-pub fn get_part_one(input: &str) -> Result<String, String> {
+pub fn get_message(input: &str) -> Result<String, String> {
     let mut sky = Sky::from_str(input.trim())?;
 
     let mut last_lit_area = None;
@@ -149,6 +149,10 @@ pub fn get_part_one(input: &str) -> Result<String, String> {
     Ok(sky.to_string())
 }
 
+pub fn get_part_one(input: &str) -> Result<String, String> {
+    aoc_ocr::recognise(&get_message(input)?).map_err(|error| error.to_string())
+}
+
 pub fn get_part_two(input: &str) -> Result<usize, String> {
     let mut sky = Sky::from_str(input.trim())?;
     let mut seconds = 0;
@@ -207,7 +211,7 @@ position=<-3,  6> velocity=< 2, -1>
 
     #[test]
     fn test_part_one() {
-        let result = get_part_one(INPUT);
+        let result = get_message(INPUT);
 
         let expected = r##"#...#..###
 #...#...#.
@@ -1,12 +1,12 @@
 use std::fs;
 
-use ::day10::*;
+use ::aoc_2018_rs_10::*;
 
 fn main() -> Result<(), String> {
     let input = fs::read_to_string("input.txt").expect("Error reading input.txt");
 
     println!(
-        "What message will eventually appear in the sky?\n\n{}\n",
+        "What message will eventually appear in the sky? {}",
         get_part_one(&input)?,
     );
 
@@ -1,7 +1,7 @@
 use std::fs;
 use std::hint::black_box;
 
-use day11::*;
+use aoc_2018_rs_11::*;
 
 use criterion::{criterion_group, criterion_main, Criterion};
 
@@ -1,6 +1,6 @@
 use std::fs;
 
-use ::day11::*;
+use ::aoc_2018_rs_11::*;
 
 fn main() -> Result<(), String> {
     let input = fs::read_to_string("input.txt").expect("Error reading input.txt");
@@ -0,0 +1,75 @@
+fn get_fuel_for_module(mass: isize) -> isize {
+    (mass / 3) - 2
+}
+
+fn get_total_fuel_requirement(input: &str) -> isize {
+    input
+        .lines()
+        .map(|line| line.parse::<isize>().unwrap())
+        .map(get_fuel_for_module)
+        .sum()
+}
+
+fn get_fuel_for_module_including_fuel(mass: isize) -> isize {
+    let fuel_for_mass = (mass / 3) - 2;
+    match fuel_for_mass {
+        fuel if fuel <= 0 => 0,
+        _ => fuel_for_mass + get_fuel_for_module_including_fuel(fuel_for_mass),
+    }
+}
+
+fn get_total_fuel_requirement_including_fuel(input: String) -> isize {
+    input
+        .lines()
+        .map(|line| line.parse::<isize>().unwrap())
+        .map(get_fuel_for_module_including_fuel)
+        .sum()
+}
+
+pub fn get_part_one(input: &str) -> isize {
+    get_total_fuel_requirement(input)
+}
+
+pub fn get_part_two(input: &str) -> isize {
+    get_total_fuel_requirement_including_fuel(input.to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_12() {
+        assert_eq!(get_fuel_for_module(12), 2);
+    }
+
+    #[test]
+    fn test_14() {
+        assert_eq!(get_fuel_for_module(14), 2);
+    }
+
+    #[test]
+    fn test_1969() {
+        assert_eq!(get_fuel_for_module(1969), 654);
+    }
+
+    #[test]
+    fn test_100756() {
+        assert_eq!(get_fuel_for_module(100756), 33583);
+    }
+
+    #[test]
+    fn test_14_inc_fuel() {
+        assert_eq!(get_fuel_for_module_including_fuel(14), 2);
+    }
+
+    #[test]
+    fn test_1969_inc_fuel() {
+        assert_eq!(get_fuel_for_module_including_fuel(1969), 966);
+    }
+
+    #[test]
+    fn test_100756_inc_fuel() {
+        assert_eq!(get_fuel_for_module_including_fuel(100756), 50346);
+    }
+}
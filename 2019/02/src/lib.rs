@@ -0,0 +1,77 @@
+use std::str::FromStr;
+
+use intcode::Intcode;
+
+fn get_final_state(mut intcode: Intcode) -> Vec<i64> {
+    intcode.run().unwrap();
+
+    intcode.memory().to_vec()
+}
+
+fn find_noun_verb(input: String) -> Vec<i64> {
+    let initial_state = Intcode::from_str(&input).unwrap();
+
+    for noun in 0..99 {
+        for verb in 0..99 {
+            let mut intcode = initial_state.clone();
+            intcode.write(1, noun);
+            intcode.write(2, verb);
+            let final_state = get_final_state(intcode.clone());
+            if final_state[0] == 19_690_720 {
+                return intcode.memory().to_vec();
+            }
+        }
+    }
+    panic!("Could not find noun/verb.")
+}
+
+pub fn get_part_one(input: &str) -> i64 {
+    let mut intcode = Intcode::from_str(input).unwrap();
+    intcode.write(1, 12);
+    intcode.write(2, 2);
+
+    get_final_state(intcode)[0]
+}
+
+pub fn get_part_two(input: &str) -> i64 {
+    let final_state = find_noun_verb(input.to_owned());
+
+    100 * final_state[1] + final_state[2]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_100099() {
+        assert_eq!(
+            get_final_state(Intcode::from_str(&String::from("1,0,0,0,99")).unwrap()),
+            vec![2, 0, 0, 0, 99]
+        );
+    }
+
+    #[test]
+    fn test_230399() {
+        assert_eq!(
+            get_final_state(Intcode::from_str(&String::from("2,3,0,3,99")).unwrap()),
+            vec![2, 3, 0, 6, 99]
+        );
+    }
+
+    #[test]
+    fn test_2445990() {
+        assert_eq!(
+            get_final_state(Intcode::from_str(&String::from("2,4,4,5,99,0")).unwrap()),
+            vec![2, 4, 4, 5, 99, 9801]
+        );
+    }
+
+    #[test]
+    fn test_11149956099() {
+        assert_eq!(
+            get_final_state(Intcode::from_str(&String::from("1,1,1,4,99,5,6,0,99")).unwrap()),
+            vec![30, 1, 1, 4, 2, 5, 6, 0, 99]
+        );
+    }
+}
@@ -0,0 +1,103 @@
+use std::str::FromStr;
+
+use intcode::Intcode;
+
+pub fn get_final_state(mut intcode: Intcode, input: i64) -> Vec<i64> {
+    intcode.push_input(input);
+    for output in intcode.collect_outputs().unwrap() {
+        println!("{}", output);
+    }
+
+    intcode.memory().to_vec()
+}
+
+fn get_diagnostic_code(input: &str, id: i64) -> Result<i64, String> {
+    let mut intcode = Intcode::from_str(input).unwrap();
+    intcode.push_input(id);
+
+    intcode
+        .collect_outputs()
+        .unwrap()
+        .pop()
+        .ok_or_else(|| String::from("No diagnostic code produced"))
+}
+
+pub fn get_part_one(input: &str) -> Result<i64, String> {
+    get_diagnostic_code(input, 1)
+}
+
+pub fn get_part_two(input: &str) -> Result<i64, String> {
+    get_diagnostic_code(input, 5)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_100243433() {
+        assert_eq!(
+            get_final_state(
+                Intcode::from_str(&String::from("1002,4,3,4,33")).unwrap(),
+                1
+            ),
+            vec![1002, 4, 3, 4, 99],
+        );
+    }
+
+    #[test]
+    fn test_1101100140() {
+        assert_eq!(
+            get_final_state(
+                Intcode::from_str(&String::from("1101,100,-1,4,0")).unwrap(),
+                1
+            ),
+            vec![1101, 100, -1, 4, 99],
+        );
+    }
+
+    #[test]
+    fn test_jump_test_one() {
+        let final_state = get_final_state(
+            Intcode::from_str(&String::from("3,12,6,12,15,1,13,14,13,4,13,99,-1,0,1,9")).unwrap(),
+            1,
+        );
+        assert_eq!(final_state[13], 1);
+
+        let final_state = get_final_state(
+            Intcode::from_str(&String::from("3,12,6,12,15,1,13,14,13,4,13,99,-1,0,1,9")).unwrap(),
+            0,
+        );
+        assert_eq!(final_state[13], 0);
+    }
+
+    #[test]
+    fn test_jump_test_two() {
+        let final_state = get_final_state(
+            Intcode::from_str(&String::from("3,3,1105,-1,9,1101,0,0,12,4,12,99,1")).unwrap(),
+            1,
+        );
+        assert_eq!(final_state[12], 1);
+
+        let final_state = get_final_state(
+            Intcode::from_str(&String::from("3,3,1105,-1,9,1101,0,0,12,4,12,99,1")).unwrap(),
+            0,
+        );
+        assert_eq!(final_state[12], 0);
+    }
+
+    #[test]
+    fn test_larger_example() {
+        let final_state = get_final_state(
+            Intcode::from_str(&String::from("3,21,1008,21,8,20,1005,20,22,107,8,21,20,1006,20,31,1106,0,36,98,0,0,1002,21,125,20,4,20,1105,1,46,104,999,1105,1,46,1101,1000,1,20,4,20,1105,1,46,98,99")).unwrap(),
+            0,
+        );
+        assert_eq!(final_state[32], 999);
+
+        let final_state = get_final_state(
+            Intcode::from_str(&String::from("3,21,1008,21,8,20,1005,20,22,107,8,21,20,1006,20,31,1106,0,36,98,0,0,1002,21,125,20,4,20,1105,1,46,104,999,1105,1,46,1101,1000,1,20,4,20,1105,1,46,98,99")).unwrap(),
+            8,
+        );
+        assert_eq!(final_state[20], 1000);
+    }
+}
@@ -0,0 +1,111 @@
+use std::str::FromStr;
+
+use intcode::{Intcode, State};
+use itertools::Itertools;
+
+fn get_output(intcode: &Intcode, phase_setting: i64, signal: i64) -> i64 {
+    let mut amplifier = intcode.clone();
+    amplifier.push_input(phase_setting);
+    amplifier.push_input(signal);
+    match amplifier.run().unwrap() {
+        State::Output(output) => output,
+        state => panic!("Amplifier stopped without output: {:?}", state),
+    }
+}
+
+fn run_amplifier_sequence(intcode: &Intcode) -> i64 {
+    (0..=4)
+        .permutations(5)
+        .map(|combination| {
+            combination.iter().fold(0, |signal, phase_setting| {
+                get_output(intcode, *phase_setting, signal)
+            })
+        })
+        .max()
+        .unwrap()
+}
+
+fn run_feedback_loop(intcode: &Intcode) -> i64 {
+    let mut outputs = Vec::new();
+
+    for combination in (5..=9).permutations(5) {
+        let mut signal = 0;
+
+        let mut amplifiers = combination
+            .iter()
+            .map(|phase_setting| {
+                let mut amplifier = intcode.clone();
+                amplifier.push_input(*phase_setting);
+                amplifier
+            })
+            .collect::<Vec<_>>();
+
+        for i in (0..amplifiers.len()).cycle() {
+            amplifiers[i].push_input(signal);
+            signal = match amplifiers[i].run().unwrap() {
+                State::Output(output) => output,
+                _ => break,
+            }
+        }
+        outputs.push(signal);
+    }
+    *outputs.iter().max().unwrap()
+}
+
+pub fn get_part_one(input: &str) -> i64 {
+    run_amplifier_sequence(&Intcode::from_str(input).unwrap())
+}
+
+pub fn get_part_two(input: &str) -> i64 {
+    run_feedback_loop(&Intcode::from_str(input).unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_43210() {
+        let intcode = String::from("3,15,3,16,1002,16,10,16,1,16,15,15,4,15,99,0,0");
+        let intcode = Intcode::from_str(&intcode).unwrap();
+        assert_eq!(run_amplifier_sequence(&intcode), 43210);
+    }
+
+    #[test]
+    fn test_54321() {
+        let intcode = String::from(
+            "3,23,3,24,1002,24,10,24,1002,23,-1,23,101,5,23,23,1,24,23,23,4,23,99,0,0",
+        );
+        let intcode = Intcode::from_str(&intcode).unwrap();
+        assert_eq!(run_amplifier_sequence(&intcode), 54321);
+    }
+
+    #[test]
+    fn test_65210() {
+        let intcode = String::from("3,31,3,32,1002,32,10,32,1001,31,-2,31,1007,31,0,33,1002,33,7,33,1,33,31,31,1,32,31,31,4,31,99,0,0,0");
+        let intcode = Intcode::from_str(&intcode).unwrap();
+        assert_eq!(run_amplifier_sequence(&intcode), 65210);
+    }
+
+    #[test]
+    fn test_139629729() {
+        let intcode = String::from(
+            "3,26,1001,26,-4,26,3,27,1002,27,2,27,1,27,26,27,4,27,1001,28,-1,28,1005,28,6,99,0,0,5",
+        );
+        assert_eq!(
+            run_feedback_loop(&Intcode::from_str(&intcode).unwrap()),
+            139629729
+        );
+    }
+
+    #[test]
+    fn test_18216() {
+        let intcode = String::from(
+            "3,52,1001,52,-5,52,3,53,1,52,56,54,1007,54,5,55,1005,55,26,1001,54,-5,54,1105,1,12,1,53,54,53,1008,54,0,55,1001,55,1,55,2,53,55,53,4,53,1001,56,-1,56,1005,56,6,99,0,0,0,0,10",
+        );
+        assert_eq!(
+            run_feedback_loop(&Intcode::from_str(&intcode).unwrap()),
+            18216
+        );
+    }
+}
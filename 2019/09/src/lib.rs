@@ -0,0 +1,52 @@
+use std::str::FromStr;
+
+use intcode::{Intcode, State};
+
+fn run(input: &str, signal: i64) -> Option<i64> {
+    let mut intcode = Intcode::from_str(input).unwrap();
+    intcode.push_input(signal);
+    match intcode.run().unwrap() {
+        State::Output(output) => Some(output),
+        _ => None,
+    }
+}
+
+pub fn get_part_one(input: &str) -> Result<i64, String> {
+    run(input, 1).ok_or_else(|| String::from("No BOOST keycode produced"))
+}
+
+pub fn get_part_two(input: &str) -> Result<i64, String> {
+    run(input, 2).ok_or_else(|| String::from("No coordinates produced"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_10912041100110011001008100161011006101099() {
+        let intcode = String::from("109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99");
+        let mut amplifier = Intcode::from_str(&intcode).unwrap();
+        let outputs = amplifier.collect_outputs().unwrap();
+        let output = outputs
+            .iter()
+            .map(|o| o.to_string())
+            .collect::<Vec<String>>()
+            .join(",");
+        assert_eq!(output, intcode);
+    }
+
+    #[test]
+    fn test_11023491519234915192747990() {
+        let intcode = String::from("1102,34915192,34915192,7,4,7,99,0");
+        let output = run(&intcode, 0);
+        assert_eq!(output.unwrap().to_string().len(), 16);
+    }
+
+    #[test]
+    fn test_104112589990684262499() {
+        let intcode = String::from("104,1125899906842624,99");
+        let output = run(&intcode, 0);
+        assert_eq!(output.unwrap(), 1125899906842624);
+    }
+}
@@ -0,0 +1,246 @@
+use crate::station::{Heatmap, Station};
+
+pub mod station;
+
+pub type Point = (isize, isize);
+
+pub fn get_asteroids(input: &str) -> Vec<Point> {
+    input
+        .trim()
+        .lines()
+        .enumerate()
+        .flat_map(|(y, line)| {
+            line.chars()
+                .enumerate()
+                .filter(|(_, c)| *c == '#')
+                .map(move |(x, _)| (x as isize, y as isize))
+        })
+        .collect::<Vec<Point>>()
+}
+
+pub fn get_part_one(input: &str) -> Result<usize, String> {
+    let heatmap = Heatmap::new(&get_asteroids(input));
+
+    heatmap
+        .best()
+        .map(|best| heatmap.counts[&best])
+        .ok_or_else(|| String::from("No asteroids to build a station on"))
+}
+
+pub fn get_part_two(input: &str) -> Result<isize, String> {
+    let asteroids = get_asteroids(input);
+    let best = Heatmap::new(&asteroids)
+        .best()
+        .ok_or_else(|| String::from("No asteroids to build a station on"))?;
+
+    Station::new(best, &asteroids)
+        .vaporise()
+        .nth(199)
+        .map(|(x, y)| x * 100 + y)
+        .ok_or_else(|| String::from("Fewer than 200 asteroids to vaporise"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::station::Heading;
+
+    #[test]
+    fn test_3_4() {
+        let input = String::from(
+            r#".#..#
+.....
+#####
+....#
+...##
+"#,
+        );
+        let asteroids = get_asteroids(&input);
+        assert_eq!(Heatmap::new(&asteroids).best().unwrap(), (3, 4));
+    }
+
+    #[test]
+    fn test_5_8() {
+        let input = String::from(
+            r#"......#.#.
+#..#.#....
+..#######.
+.#.#.###..
+.#..#.....
+..#....#.#
+#..#....#.
+.##.#..###
+##...#..#.
+.#....####"#,
+        );
+        let asteroids = get_asteroids(&input);
+        assert_eq!(Heatmap::new(&asteroids).best().unwrap(), (5, 8));
+    }
+
+    #[test]
+    fn test_6_3() {
+        let input = String::from(
+            r#".#..#..###
+####.###.#
+....###.#.
+..###.##.#
+##.##.#.#.
+....###..#
+..#.#..#.#
+#..#.#.###
+.##...##.#
+.....#.#.."#,
+        );
+        let asteroids = get_asteroids(&input);
+        assert_eq!(Heatmap::new(&asteroids).best().unwrap(), (6, 3));
+    }
+
+    #[test]
+    fn test_11_13() {
+        let input = String::from(
+            r#".#..##.###...#######
+##.############..##.
+.#.######.########.#
+.###.#######.####.#.
+#####.##.#.##.###.##
+..#####..#.#########
+####################
+#.####....###.#.#.##
+##.#################
+#####.##.###..####..
+..######..##.#######
+####.##.####...##..#
+.#####..#.######.###
+##...#.##########...
+#.##########.#######
+.####.#.###.###.#.##
+....##.##.###..#####
+.#.#.###########.###
+#.#.#.#####.####.###
+###.##.####.##.#..##"#,
+        );
+        let asteroids = get_asteroids(&input);
+        assert_eq!(Heatmap::new(&asteroids).best().unwrap(), (11, 13));
+    }
+
+    #[test]
+    fn test_8_3() {
+        let input = String::from(
+            r#".#....#####...#..
+##...##.#####..##
+##...#...#.#####.
+..#.....#...###..
+..#.#.....#....##"#,
+        );
+        let asteroids = get_asteroids(&input);
+        let best = Heatmap::new(&asteroids).best().unwrap();
+        assert_eq!(best, (8, 3));
+
+        let station = Station::new(best, &asteroids);
+        assert_eq!(
+            vec![
+                (8, 1),
+                (9, 0),
+                (9, 1),
+                (10, 0),
+                (9, 2),
+                (11, 1),
+                (12, 1),
+                (11, 2),
+                (15, 1),
+            ],
+            station.vaporise().take(9).collect::<Vec<_>>(),
+        );
+        assert_eq!(asteroids.len() - 1, station.vaporise().count());
+    }
+
+    #[test]
+    fn test_vaporise() {
+        let input = String::from(
+            r#".#..##.###...#######
+##.############..##.
+.#.######.########.#
+.###.#######.####.#.
+#####.##.#.##.###.##
+..#####..#.#########
+####################
+#.####....###.#.#.##
+##.#################
+#####.##.###..####..
+..######..##.#######
+####.##.####...##..#
+.#####..#.######.###
+##...#.##########...
+#.##########.#######
+.####.#.###.###.#.##
+....##.##.###..#####
+.#.#.###########.###
+#.#.#.#####.####.###
+###.##.####.##.#..##"#,
+        );
+        let asteroids = get_asteroids(&input);
+        let heatmap = Heatmap::new(&asteroids);
+        assert_eq!(Some(&210), heatmap.counts.get(&(11, 13)));
+
+        let order = Station::new((11, 13), &asteroids)
+            .vaporise()
+            .collect::<Vec<_>>();
+        assert_eq!(299, order.len());
+        for (n, asteroid) in [
+            (1, (11, 12)),
+            (2, (12, 1)),
+            (3, (12, 2)),
+            (10, (12, 8)),
+            (20, (16, 0)),
+            (50, (16, 9)),
+            (100, (10, 16)),
+            (199, (9, 6)),
+            (200, (8, 2)),
+            (201, (10, 9)),
+            (299, (11, 1)),
+        ] {
+            assert_eq!(asteroid, order[n - 1], "asteroid {}", n);
+        }
+    }
+
+    #[test]
+    fn test_heading() {
+        let headings = [
+            (0, -2),
+            (1, -3),
+            (2, 0),
+            (1, 1),
+            (0, 2),
+            (-2, 1),
+            (-1, 0),
+            (-1, -5),
+        ]
+        .iter()
+        .map(|&to| Heading::between((0, 0), to).unwrap())
+        .collect::<Vec<_>>();
+        let mut sorted = headings.clone();
+        sorted.reverse();
+        sorted.sort();
+
+        assert_eq!(headings, sorted);
+        assert_eq!((Heading { dx: 0, dy: -1 }, 2), headings[0]);
+        assert_eq!(None, Heading::between((3, 3), (3, 3)));
+    }
+
+    #[test]
+    fn test_heatmap() {
+        let input = String::from(
+            r#".#..#
+.....
+#####
+....#
+...##
+"#,
+        );
+        let heatmap = Heatmap::new(&get_asteroids(&input));
+
+        assert_eq!(Some((3, 4)), heatmap.best());
+        assert_eq!(".7..7\n.....\n67775\n....7\n...97", heatmap.to_string());
+        assert!(heatmap.to_csv().starts_with("x,y,visible\n0,2,6\n"));
+    }
+}
@@ -1,215 +1,40 @@
-use std::collections::HashMap;
-use std::fs::File;
-use std::io::prelude::*;
+use std::env;
+use std::fs;
 
-use num::integer::gcd;
+use ::aoc_2019_10::station::{Heatmap, Station};
+use ::aoc_2019_10::*;
 
-type Point = (isize, isize);
-
-fn read_input() -> String {
-    let filename = "input.txt";
-    match File::open(filename) {
-        Ok(mut file) => {
-            let mut content = String::new();
-            file.read_to_string(&mut content).unwrap();
-            content
-        }
-        Err(error) => {
-            panic!("Error opening file {}: {}", filename, error);
-        }
-    }
-}
-
-fn get_asteroids(input: &str) -> Vec<Point> {
-    input
-        .trim()
-        .lines()
-        .enumerate()
-        .flat_map(|(y, line)| {
-            line.chars()
-                .enumerate()
-                .filter(|(_, c)| *c == '#')
-                .map(move |(x, _)| (x as isize, y as isize))
-        })
-        .collect::<Vec<Point>>()
-}
-
-/// As the `target` is already relative to the origin, we need only
-/// calculate the arctangent.
-fn get_angle(target: &Point) -> f64 {
-    let angle = f64::atan2(target.1 as f64, target.0 as f64).to_degrees() + 90_f64;
-    ((angle % 360.0) + 360.0) % 360.0
-}
-
-fn get_best_asteroid(asteroids: Vec<Point>) -> (Point, HashMap<Point, Vec<(isize, Point)>>) {
-    let mut best_asteroid: (Point, HashMap<Point, Vec<(isize, Point)>>) = ((0, 0), HashMap::new());
-
-    for source in asteroids.iter() {
-        let mut targets: HashMap<Point, Vec<(isize, Point)>> = HashMap::new();
-        for target in asteroids.iter() {
-            if source == target {
-                continue;
-            }
-            let mut dx = (target.0 - source.0) as isize;
-            let mut dy = (target.1 - source.1) as isize;
-            let dist = gcd(dx.abs(), dy.abs());
-            dx /= dist;
-            dy /= dist;
-            let distances = targets.entry((dx, dy)).or_insert_with(Vec::new);
-            distances.push((dist, *target));
-        }
-        if targets.keys().len() > best_asteroid.1.keys().len() {
-            best_asteroid = (*source, targets);
-        }
-    }
-    best_asteroid
-}
-
-fn destroy(
-    station: (Point, HashMap<Point, Vec<(isize, Point)>>),
-) -> Vec<(f64, Point, isize, Point)> {
-    let mut targets = station
-        .1
-        .iter()
-        .flat_map(|(k, distances)| {
-            let angle = get_angle(&k);
-            distances
-                .iter()
-                .map(|(distance, coord)| (angle, *k, *distance, *coord))
-                .collect::<Vec<_>>()
-        })
-        .collect::<Vec<_>>();
-
-    targets.sort_by(|a, b| {
-        a.0.partial_cmp(&b.0).unwrap().then(a.2.cmp(&b.2))
-        //            a.2.cmp(&b.2).then(a.0.partial_cmp(&b.0).unwrap())
-    });
-
-    targets
+/// The value following `flag` on the command line, if it's there.
+fn get_option(flag: &str) -> Option<String> {
+    env::args().skip_while(|arg| arg != flag).nth(1)
 }
 
 fn main() {
-    let input = read_input();
+    let input = fs::read_to_string("input.txt").expect("Error reading input.txt");
     let asteroids = get_asteroids(&input);
-    let best_asteroid = get_best_asteroid(asteroids);
+    let heatmap = Heatmap::new(&asteroids);
+    let best = heatmap.best().unwrap();
     println!(
         "How many other asteroids can be detected from that location? {}",
-        best_asteroid.1.keys().len()
+        heatmap.counts[&best],
     );
+    if let Some(path) = get_option("--heatmap") {
+        fs::write(path, heatmap.to_csv()).unwrap();
+        eprintln!("{}", heatmap);
+    }
 
-    let destruction_order = destroy(best_asteroid);
-    let two_hundredth = destruction_order.get(199).unwrap();
+    let position = get_option("--station")
+        .map(|station| {
+            let (x, y) = station.split_once(',').expect("Expected --station X,Y");
+            (x.parse().unwrap(), y.parse().unwrap())
+        })
+        .unwrap_or(best);
+    let (x, y) = Station::new(position, &asteroids)
+        .vaporise()
+        .nth(199)
+        .unwrap();
     println!(
         "…what do you get if you multiply its X coordinate by 100 and then add its Y coordinate? {}",
-        ((two_hundredth.3).0 * 100) + (two_hundredth.3).1,
+        x * 100 + y,
     )
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_3_4() {
-        let input = String::from(
-            r#".#..#
-.....
-#####
-....#
-...##
-"#,
-        );
-        let asteroids = get_asteroids(&input);
-        assert_eq!(get_best_asteroid(asteroids).0, (3, 4));
-    }
-
-    #[test]
-    fn test_5_8() {
-        let input = String::from(
-            r#"......#.#.
-#..#.#....
-..#######.
-.#.#.###..
-.#..#.....
-..#....#.#
-#..#....#.
-.##.#..###
-##...#..#.
-.#....####"#,
-        );
-        let asteroids = get_asteroids(&input);
-        assert_eq!(get_best_asteroid(asteroids).0, (5, 8));
-    }
-
-    #[test]
-    fn test_6_3() {
-        let input = String::from(
-            r#".#..#..###
-####.###.#
-....###.#.
-..###.##.#
-##.##.#.#.
-....###..#
-..#.#..#.#
-#..#.#.###
-.##...##.#
-.....#.#.."#,
-        );
-        let asteroids = get_asteroids(&input);
-        assert_eq!(get_best_asteroid(asteroids).0, (6, 3));
-    }
-
-    #[test]
-    fn test_11_13() {
-        let input = String::from(
-            r#".#..##.###...#######
-##.############..##.
-.#.######.########.#
-.###.#######.####.#.
-#####.##.#.##.###.##
-..#####..#.#########
-####################
-#.####....###.#.#.##
-##.#################
-#####.##.###..####..
-..######..##.#######
-####.##.####...##..#
-.#####..#.######.###
-##...#.##########...
-#.##########.#######
-.####.#.###.###.#.##
-....##.##.###..#####
-.#.#.###########.###
-#.#.#.#####.####.###
-###.##.####.##.#..##"#,
-        );
-        let asteroids = get_asteroids(&input);
-        assert_eq!(get_best_asteroid(asteroids).0, (11, 13));
-    }
-
-    #[test]
-    fn test_8_3() {
-        let input = String::from(
-            r#".#....#####...#..
-##...##.#####..##
-##...#...#.#####.
-..#.....#...###..
-..#.#.....#....##"#,
-        );
-        let asteroids = get_asteroids(&input);
-        let best_asteroid = get_best_asteroid(asteroids);
-        assert_eq!(best_asteroid.0, (8, 3));
-
-        let destruction = destroy(best_asteroid);
-        println!("{:?}", destruction);
-        assert_eq!(destruction.get(0).unwrap().3, (8, 1));
-    }
-
-    #[test]
-    fn test_get_angle() {
-        assert_eq!(get_angle(&(0, -2)), 0_f64);
-        assert_eq!(get_angle(&(2, 0)), 90_f64);
-        assert_eq!(get_angle(&(0, 2)), 180_f64);
-        assert_eq!(get_angle(&(-2, 0)), 270_f64);
-    }
-}
@@ -0,0 +1,182 @@
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt;
+
+use aoc_math::gcd;
+
+use crate::Point;
+
+/// A direction from the station, as the smallest whole step along it, so
+/// that every asteroid in line shares one.
+///
+/// Ordered clockwise from straight up, the way the laser turns, using only
+/// integer arithmetic.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Heading {
+    pub dx: isize,
+    pub dy: isize,
+}
+
+impl Heading {
+    /// The heading from `from` to `to`, and how many of its steps away
+    /// `to` is; `None` if they're the same point.
+    pub fn between(from: Point, to: Point) -> Option<(Self, isize)> {
+        let (dx, dy) = (to.0 - from.0, to.1 - from.1);
+        let steps = gcd(dx, dy)?;
+        if steps == 0 {
+            return None;
+        }
+
+        Some((
+            Heading {
+                dx: dx / steps,
+                dy: dy / steps,
+            },
+            steps,
+        ))
+    }
+
+    /// Straight up and everything clockwise of it short of straight down.
+    fn is_right(&self) -> bool {
+        self.dx > 0 || (self.dx == 0 && self.dy < 0)
+    }
+}
+
+impl Ord for Heading {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Within a half, `other` is clockwise of `self` when their cross
+        // product is positive (`y` increasing downwards).
+        other.is_right().cmp(&self.is_right()).then_with(|| {
+            let cross = self.dx * other.dy - self.dy * other.dx;
+            0.cmp(&cross)
+        })
+    }
+}
+
+impl PartialOrd for Heading {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// An asteroid with a monitoring station on it, and every other asteroid
+/// by its heading, nearest first.
+#[derive(Clone, Debug)]
+pub struct Station {
+    targets: BTreeMap<Heading, VecDeque<Point>>,
+}
+
+impl Station {
+    pub fn new(position: Point, asteroids: &[Point]) -> Self {
+        let mut targets: BTreeMap<Heading, Vec<(isize, Point)>> = BTreeMap::new();
+        for asteroid in asteroids {
+            if let Some((heading, steps)) = Heading::between(position, *asteroid) {
+                targets.entry(heading).or_default().push((steps, *asteroid));
+            }
+        }
+
+        let targets = targets
+            .into_iter()
+            .map(|(heading, mut asteroids)| {
+                asteroids.sort_unstable();
+                let asteroids = asteroids.into_iter().map(|(_, asteroid)| asteroid);
+                (heading, asteroids.collect())
+            })
+            .collect();
+
+        Station { targets }
+    }
+
+    /// How many asteroids can be seen: the nearest on each heading.
+    pub fn visible(&self) -> usize {
+        self.targets.len()
+    }
+
+    /// Every other asteroid, in the order the laser reaches them.
+    pub fn vaporise(&self) -> Vaporisation {
+        Vaporisation {
+            targets: self.targets.values().cloned().collect(),
+            next: 0,
+        }
+    }
+}
+
+/// The laser sweeping round, taking the nearest asteroid on each heading
+/// per turn.
+pub struct Vaporisation {
+    targets: Vec<VecDeque<Point>>,
+    next: usize,
+}
+
+impl Iterator for Vaporisation {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Point> {
+        for _ in 0..self.targets.len() {
+            let heading = self.next;
+            self.next = (self.next + 1) % self.targets.len();
+            if let Some(asteroid) = self.targets[heading].pop_front() {
+                return Some(asteroid);
+            }
+        }
+
+        None
+    }
+}
+
+/// The number of asteroids visible from each asteroid.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Heatmap {
+    pub counts: BTreeMap<Point, usize>,
+}
+
+impl Heatmap {
+    pub fn new(asteroids: &[Point]) -> Self {
+        let counts = asteroids
+            .iter()
+            .map(|asteroid| (*asteroid, Station::new(*asteroid, asteroids).visible()))
+            .collect();
+
+        Heatmap { counts }
+    }
+
+    /// Where the station should go, being where the most can be seen.
+    pub fn best(&self) -> Option<Point> {
+        self.counts
+            .iter()
+            .max_by_key(|(&(x, y), &count)| (count, -y, -x))
+            .map(|(asteroid, _)| *asteroid)
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("x,y,visible\n");
+        for ((x, y), count) in self.counts.iter() {
+            csv.push_str(&format!("{},{},{}\n", x, y, count));
+        }
+
+        csv
+    }
+}
+
+/// Draws each asteroid as a digit, scaled so the best is `9`.
+impl fmt::Display for Heatmap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let max = self.counts.values().copied().max().unwrap_or(0).max(1);
+        let width = self.counts.keys().map(|(x, _)| *x).max().unwrap_or(-1) + 1;
+        let height = self.counts.keys().map(|(_, y)| *y).max().unwrap_or(-1) + 1;
+
+        for y in 0..height {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for x in 0..width {
+                match self.counts.get(&(x, y)) {
+                    Some(count) => write!(f, "{}", count * 9 / max)?,
+                    None => write!(f, ".")?,
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
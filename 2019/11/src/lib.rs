@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use intcode::{Intcode, State};
+
+#[derive(Clone, Debug)]
+enum Orientation {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+#[derive(Clone, Debug)]
+struct Robot {
+    location: (isize, isize),
+    orientation: Orientation,
+    squares: HashMap<(isize, isize), i64>,
+    intcode: Intcode,
+}
+
+impl Robot {
+    fn new(input: &str) -> Robot {
+        Robot {
+            location: (0, 0),
+            orientation: Orientation::Up,
+            squares: HashMap::new(),
+            intcode: Intcode::from_str(input).unwrap(),
+        }
+    }
+
+    fn advance(&mut self) {
+        self.location = match self.orientation {
+            Orientation::Up => (self.location.0, self.location.1 - 1),
+            Orientation::Right => (self.location.0 + 1, self.location.1),
+            Orientation::Down => (self.location.0, self.location.1 + 1),
+            Orientation::Left => (self.location.0 - 1, self.location.1),
+        };
+    }
+
+    fn rotate(&mut self, rotation: i64) {
+        self.orientation = match self.orientation {
+            Orientation::Up => {
+                if rotation == 0 {
+                    Orientation::Left
+                } else {
+                    Orientation::Right
+                }
+            }
+            Orientation::Right => {
+                if rotation == 0 {
+                    Orientation::Up
+                } else {
+                    Orientation::Down
+                }
+            }
+            Orientation::Down => {
+                if rotation == 0 {
+                    Orientation::Right
+                } else {
+                    Orientation::Left
+                }
+            }
+            Orientation::Left => {
+                if rotation == 0 {
+                    Orientation::Down
+                } else {
+                    Orientation::Up
+                }
+            }
+        };
+    }
+
+    fn get_registration(&self) -> aoc_error::Result<String> {
+        aoc_ocr::recognise_points(
+            self.squares
+                .iter()
+                .filter(|(_, colour)| **colour == 1)
+                .map(|(location, _)| *location),
+        )
+    }
+}
+
+#[allow(clippy::while_let_loop)]
+fn paint(input: &str, start: i64) -> Robot {
+    let mut robot = Robot::new(input);
+    robot.intcode.push_input(start);
+    loop {
+        let colour = match robot.intcode.run().unwrap() {
+            State::Output(n) => n,
+            _ => break,
+        };
+        robot.squares.insert(robot.location, colour);
+        let rotation = match robot.intcode.run().unwrap() {
+            State::Output(n) => n,
+            _ => break,
+        };
+        robot.rotate(rotation);
+        robot.advance();
+        let input = match robot.squares.get(&robot.location) {
+            Some(c) => *c,
+            None => 0,
+        };
+        robot.intcode.push_input(input);
+    }
+
+    robot
+}
+
+pub fn get_part_one(input: &str) -> usize {
+    paint(input, 0).squares.keys().len()
+}
+
+pub fn get_part_two(input: &str) -> aoc_error::Result<String> {
+    paint(input, 1).get_registration()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {}
+}
@@ -0,0 +1,65 @@
+use std::str::FromStr;
+
+use crate::nbody::System;
+
+pub mod nbody;
+
+/// How many steps each axis gets to return to where it started, unless
+/// `--limit` says otherwise.
+pub const PERIOD_LIMIT: usize = 10_000_000;
+
+pub fn get_part_one(input: &str) -> Result<i64, String> {
+    let mut moons = System::<3>::from_str(input)?;
+    moons.run(1000);
+
+    Ok(moons.total_energy())
+}
+
+pub fn get_part_two(input: &str) -> Result<usize, String> {
+    System::<3>::from_str(input)?
+        .period(PERIOD_LIMIT)
+        .map_err(|error| error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_10() {
+        let input = String::from(
+            r#"<x=-1, y=0, z=2>
+<x=2, y=-10, z=-7>
+<x=4, y=-8, z=8>
+<x=3, y=5, z=-1>"#,
+        );
+        let mut system = System::<3>::from_str(&input).unwrap();
+        system.run(10);
+        let moons = &system.bodies;
+        assert_eq!(moons[0].position, [2, 1, -3]);
+        assert_eq!(moons[1].position, [1, -8, 0]);
+        assert_eq!(moons[2].position, [3, -6, 1]);
+        assert_eq!(moons[3].position, [2, 0, 4]);
+        assert_eq!(moons[0].velocity, [-3, -2, 1]);
+        assert_eq!(moons[1].velocity, [-1, 1, 3]);
+        assert_eq!(moons[2].velocity, [3, 2, -3]);
+        assert_eq!(moons[3].velocity, [1, -1, -1]);
+        assert_eq!(system.total_energy(), 179);
+        assert_eq!([0, 0, 0], system.momentum());
+    }
+
+    #[test]
+    fn test_2772() {
+        let input = String::from(
+            r#"<x=-1, y=0, z=2>
+<x=2, y=-10, z=-7>
+<x=4, y=-8, z=8>
+<x=3, y=5, z=-1>"#,
+        );
+        let mut system = System::<3>::from_str(&input).unwrap();
+        let initial = system.clone();
+        system.run(2772);
+        assert_eq!(system, initial);
+        assert_eq!(Ok(2772), initial.period(10_000));
+    }
+}
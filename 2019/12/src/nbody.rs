@@ -0,0 +1,326 @@
+use std::convert::TryInto;
+use std::fmt;
+use std::str::FromStr;
+
+use aoc_math::lcm;
+
+/// A body in `N` dimensions.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Body<const N: usize> {
+    pub position: [i64; N],
+    pub velocity: [i64; N],
+}
+
+impl<const N: usize> Body<N> {
+    pub fn at(position: [i64; N]) -> Self {
+        Body {
+            position,
+            velocity: [0; N],
+        }
+    }
+
+    pub fn potential_energy(&self) -> i64 {
+        self.position.iter().map(|p| p.abs()).sum()
+    }
+
+    pub fn kinetic_energy(&self) -> i64 {
+        self.velocity.iter().map(|v| v.abs()).sum()
+    }
+
+    pub fn total_energy(&self) -> i64 {
+        self.potential_energy() * self.kinetic_energy()
+    }
+}
+
+/// Reads the value after each `=` on the line as a coordinate, in order,
+/// whatever the axes are called; there must be exactly `N`.
+impl<const N: usize> FromStr for Body<N> {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let coordinates = s
+            .split('=')
+            .skip(1)
+            .map(|value| value.split([',', '>']).next().map_or("", str::trim))
+            .map(str::parse::<i64>)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|error| error.to_string())?;
+        let count = coordinates.len();
+
+        coordinates
+            .try_into()
+            .map(Body::at)
+            .map_err(|_| format!("expected {} coordinates, found {} in {:?}", N, count, s))
+    }
+}
+
+/// Why a system's period couldn't be found.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PeriodError {
+    /// The axes that hadn't come back to where they started within the
+    /// step limit.
+    Unreturned(Vec<usize>),
+    /// Every axis returned, but the period doesn't fit in a `usize`.
+    Overflow(Vec<usize>),
+}
+
+impl fmt::Display for PeriodError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PeriodError::Unreturned(axes) => write!(f, "axes {:?} never returned", axes),
+            PeriodError::Overflow(periods) => {
+                write!(f, "the lcm of the axis periods {:?} overflowed", periods)
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct System<const N: usize> {
+    pub bodies: Vec<Body<N>>,
+}
+
+impl<const N: usize> FromStr for System<N> {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bodies = s
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(Body::from_str)
+            .collect::<Result<_, _>>()?;
+
+        Ok(System { bodies })
+    }
+}
+
+impl<const N: usize> System<N> {
+    /// Applies gravity between every pair of bodies, then moves each by
+    /// its velocity.
+    pub fn step(&mut self) {
+        for axis in 0..N {
+            System::step_axis(&mut self.bodies, axis);
+        }
+    }
+
+    /// As [`System::step`], along only one axis; nothing on one axis
+    /// affects any other.
+    fn step_axis(bodies: &mut [Body<N>], axis: usize) {
+        for i in 0..bodies.len() {
+            for j in i + 1..bodies.len() {
+                let pull = (bodies[j].position[axis] - bodies[i].position[axis]).signum();
+                bodies[i].velocity[axis] += pull;
+                bodies[j].velocity[axis] -= pull;
+            }
+        }
+        for body in bodies.iter_mut() {
+            body.position[axis] += body.velocity[axis];
+        }
+    }
+
+    pub fn run(&mut self, steps: usize) {
+        for _ in 0..steps {
+            self.step();
+        }
+    }
+
+    pub fn total_energy(&self) -> i64 {
+        self.bodies.iter().map(Body::total_energy).sum()
+    }
+
+    /// The sum of the velocities; as gravity always pulls in pairs, this
+    /// never changes.
+    pub fn momentum(&self) -> [i64; N] {
+        let mut momentum = [0; N];
+        for body in &self.bodies {
+            for (total, velocity) in momentum.iter_mut().zip(body.velocity.iter()) {
+                *total += velocity;
+            }
+        }
+
+        momentum
+    }
+
+    /// How many steps each axis, simulated on its own, takes to get back
+    /// to exactly where it started, if it does within `limit`.
+    pub fn axis_periods(&self, limit: usize) -> [Option<usize>; N] {
+        let mut periods = [None; N];
+        for (axis, period) in periods.iter_mut().enumerate() {
+            let mut bodies = self.bodies.clone();
+            *period = (1..=limit).find(|_| {
+                System::step_axis(&mut bodies, axis);
+                bodies
+                    .iter()
+                    .zip(self.bodies.iter())
+                    .all(|(body, initial)| {
+                        body.position[axis] == initial.position[axis]
+                            && body.velocity[axis] == initial.velocity[axis]
+                    })
+            });
+        }
+
+        periods
+    }
+
+    /// How many steps the whole system takes to get back to exactly where
+    /// it started: the lcm of the axis periods, as the axes move
+    /// independently.
+    pub fn period(&self, limit: usize) -> Result<usize, PeriodError> {
+        let periods = self.axis_periods(limit);
+        let unreturned = (0..N)
+            .filter(|axis| periods[*axis].is_none())
+            .collect::<Vec<_>>();
+        if !unreturned.is_empty() {
+            return Err(PeriodError::Unreturned(unreturned));
+        }
+
+        let periods = periods.iter().flatten().copied().collect::<Vec<_>>();
+        periods
+            .iter()
+            .try_fold(1, |acc, period| lcm(acc, *period))
+            .ok_or(PeriodError::Overflow(periods))
+    }
+
+    /// Runs for `steps`, keeping the state after every one, including the
+    /// start.
+    pub fn trajectory(&mut self, steps: usize) -> Trajectory<N> {
+        let mut frames = vec![self.bodies.clone()];
+        for _ in 0..steps {
+            self.step();
+            frames.push(self.bodies.clone());
+        }
+
+        Trajectory { frames }
+    }
+}
+
+/// The bodies at each step of a simulation.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Trajectory<const N: usize> {
+    pub frames: Vec<Vec<Body<N>>>,
+}
+
+impl<const N: usize> Trajectory<N> {
+    /// The names of the axes: `x`, `y` and `z` where there are no more than
+    /// three, numbered otherwise.
+    fn axes() -> Vec<String> {
+        match N {
+            0..=3 => ["x", "y", "z"][..N]
+                .iter()
+                .map(|axis| axis.to_string())
+                .collect(),
+            _ => (0..N).map(|axis| format!("x{}", axis)).collect(),
+        }
+    }
+
+    /// One row per body per step.
+    pub fn to_csv(&self) -> String {
+        let axes = Trajectory::<N>::axes();
+        let mut csv = format!(
+            "step,body,{},{}\n",
+            axes.join(","),
+            axes.iter()
+                .map(|axis| format!("v{}", axis))
+                .collect::<Vec<_>>()
+                .join(","),
+        );
+        for (step, bodies) in self.frames.iter().enumerate() {
+            for (i, body) in bodies.iter().enumerate() {
+                let values = body
+                    .position
+                    .iter()
+                    .chain(body.velocity.iter())
+                    .map(|value| value.to_string())
+                    .collect::<Vec<_>>();
+                csv.push_str(&format!("{},{},{}\n", step, i, values.join(",")));
+            }
+        }
+
+        csv
+    }
+
+    /// An array of steps, each an array of bodies.
+    pub fn to_json(&self) -> String {
+        let array = |values: &[i64; N]| {
+            format!(
+                "[{}]",
+                values
+                    .iter()
+                    .map(|value| value.to_string())
+                    .collect::<Vec<_>>()
+                    .join(",")
+            )
+        };
+        let frames = self
+            .frames
+            .iter()
+            .enumerate()
+            .map(|(step, bodies)| {
+                let bodies = bodies
+                    .iter()
+                    .map(|body| {
+                        format!(
+                            r#"{{"position":{},"velocity":{}}}"#,
+                            array(&body.position),
+                            array(&body.velocity)
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                format!(r#"{{"step":{},"bodies":[{}]}}"#, step, bodies.join(","))
+            })
+            .collect::<Vec<_>>();
+
+        format!("[{}]", frames.join(","))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_planar() {
+        let mut system = System::<2>::from_str("<x=0, y=0>\n<x=2, y=-1>").unwrap();
+        let start = system.clone();
+        system.run(3);
+
+        assert_eq!([0, 0], system.momentum());
+        assert_eq!([Some(6), Some(4)], start.axis_periods(100));
+        assert_eq!(Ok(12), start.period(100));
+        assert!(System::<2>::from_str("<x=0, y=0, z=1>").is_err());
+    }
+
+    #[test]
+    fn test_numbered_axes() {
+        assert_eq!(
+            Ok(Body::at([1, -2, 3, 40])),
+            Body::<4>::from_str("<x0=1, x1=-2, x2=3, x3=40>"),
+        );
+    }
+
+    #[test]
+    fn test_unreturned() {
+        let system = System::<3>::from_str("<x=0, y=0, z=0>\n<x=50, y=0, z=0>").unwrap();
+
+        assert_eq!(Err(PeriodError::Unreturned(vec![0])), system.period(10));
+        assert_eq!([None, Some(1), Some(1)], system.axis_periods(10));
+    }
+
+    #[test]
+    fn test_export() {
+        let mut system = System::<1>::from_str("<x=0>\n<x=2>").unwrap();
+        let trajectory = system.trajectory(1);
+
+        assert_eq!(
+            "step,body,x,vx\n0,0,0,0\n0,1,2,0\n1,0,1,1\n1,1,1,-1\n",
+            trajectory.to_csv(),
+        );
+        assert_eq!(
+            concat!(
+                r#"[{"step":0,"bodies":[{"position":[0],"velocity":[0]},{"position":[2],"velocity":[0]}]},"#,
+                r#"{"step":1,"bodies":[{"position":[1],"velocity":[1]},{"position":[1],"velocity":[-1]}]}]"#,
+            ),
+            trajectory.to_json(),
+        );
+    }
+}
@@ -0,0 +1,151 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::io;
+use std::str::FromStr;
+
+use intcode::{Intcode, State};
+
+use crate::render::{Frame, Null, Renderer};
+
+pub mod render;
+
+pub type Screen = HashMap<(i64, i64), i64>;
+
+/// Decides which way to tilt the joystick each time the game asks.
+pub trait Policy {
+    fn tilt(&mut self, screen: &Screen) -> i64;
+}
+
+/// Keeps the paddle under the ball.
+pub struct Autopilot;
+
+impl Policy for Autopilot {
+    fn tilt(&mut self, screen: &Screen) -> i64 {
+        let ball = screen.iter().find(|(_, tile)| *tile == &4);
+        let paddle = screen.iter().find(|(_, tile)| *tile == &3);
+        match (ball, paddle) {
+            (Some(ball), Some(paddle)) => match (ball.0).0.cmp(&(paddle.0).0) {
+                Ordering::Less => -1,
+                Ordering::Greater => 1,
+                Ordering::Equal => 0,
+            },
+            _ => 0,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Cabinet {
+    pub intcode: Intcode,
+    pub screen: Screen,
+    pub score: i64,
+}
+
+impl Cabinet {
+    pub fn new(input: &str) -> Self {
+        Cabinet {
+            intcode: Intcode::from_str(input).unwrap(),
+            screen: HashMap::new(),
+            score: 0,
+        }
+    }
+
+    fn frame(&self) -> Frame {
+        let width = self.screen.keys().map(|(x, _)| *x).max().unwrap_or(-1);
+        let height = self.screen.keys().map(|(_, y)| *y).max().unwrap_or(-1);
+        let rows = (0..=height)
+            .map(|y| {
+                (0..=width)
+                    .map(|x| match self.screen.get(&(x, y)) {
+                        Some(1) => '+',
+                        Some(2) => '#',
+                        Some(3) => '=',
+                        Some(4) => '@',
+                        _ => ' ',
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Frame {
+            rows,
+            score: self.score,
+        }
+    }
+
+    fn read_output(&mut self) -> i64 {
+        match self.intcode.run().unwrap() {
+            State::Output(n) => n,
+            state => panic!("Expected a tile, got {:?}", state),
+        }
+    }
+
+    /// Runs the game to the end, rendering a frame whenever the joystick
+    /// is needed and once more when the game's over.
+    pub fn play(
+        &mut self,
+        policy: &mut impl Policy,
+        renderer: &mut impl Renderer,
+    ) -> io::Result<()> {
+        loop {
+            match self.intcode.run().unwrap() {
+                State::Output(x) => {
+                    let y = self.read_output();
+                    let tile = self.read_output();
+                    if x == -1 && y == 0 {
+                        self.score = tile;
+                    } else {
+                        self.screen.insert((x, y), tile);
+                    }
+                }
+                State::NeedsInput => {
+                    renderer.render(&self.frame())?;
+                    let tilt = policy.tilt(&self.screen);
+                    self.intcode.push_input(tilt);
+                }
+                State::Halted => return renderer.render(&self.frame()),
+            }
+        }
+    }
+}
+
+pub fn get_part_one(input: &str) -> io::Result<usize> {
+    let mut cabinet = Cabinet::new(input);
+    cabinet.play(&mut Autopilot, &mut Null)?;
+
+    Ok(cabinet.screen.values().filter(|tile| *tile == &2).count())
+}
+
+pub fn get_part_two(input: &str) -> io::Result<i64> {
+    let mut cabinet = Cabinet::new(input);
+    cabinet.intcode.write(0, 2);
+    cabinet.play(&mut Autopilot, &mut Null)?;
+
+    Ok(cabinet.score)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::render::Recording;
+
+    /// Draws a wall, the ball and the paddle, then scores ten more than
+    /// the joystick's tilt.
+    const GAME: &str = "104,0,104,0,104,1,104,2,104,0,104,4,104,1,104,1,104,3,\
+                        3,100,1001,100,10,101,104,-1,104,0,4,101,99";
+
+    #[test]
+    fn test_autopilot() -> io::Result<()> {
+        let mut cabinet = Cabinet::new(GAME);
+        let mut recording = Recording::default();
+        cabinet.play(&mut Autopilot, &mut recording)?;
+
+        assert_eq!(
+            "+ @\n = \nScore: 0\n\n+ @\n = \nScore: 11\n\n",
+            recording.to_string(),
+        );
+        assert_eq!(Some(11), recording.score());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,204 @@
+use std::fmt;
+use std::io::{self, Write};
+use std::str::FromStr;
+use std::thread;
+use std::time::Duration;
+
+use aoc_error::{Context, Error, Result};
+use termion::{clear, cursor};
+
+/// The screen as drawn, along with the score at the time.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Frame {
+    pub rows: Vec<String>,
+    pub score: i64,
+}
+
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in &self.rows {
+            writeln!(f, "{}", row)?;
+        }
+
+        writeln!(f, "Score: {}", self.score)
+    }
+}
+
+impl FromStr for Frame {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut rows = s.lines().map(str::to_string).collect::<Vec<_>>();
+        let score = rows
+            .pop()
+            .as_deref()
+            .and_then(|line| line.strip_prefix("Score: "))
+            .context("frame doesn't end with a score")?
+            .parse()?;
+
+        Ok(Frame { rows, score })
+    }
+}
+
+pub trait Renderer {
+    fn render(&mut self, frame: &Frame) -> io::Result<()>;
+}
+
+impl<R: Renderer + ?Sized> Renderer for Box<R> {
+    fn render(&mut self, frame: &Frame) -> io::Result<()> {
+        (**self).render(frame)
+    }
+}
+
+/// Renders to both, in order.
+impl<A: Renderer, B: Renderer> Renderer for (A, B) {
+    fn render(&mut self, frame: &Frame) -> io::Result<()> {
+        self.0.render(frame)?;
+        self.1.render(frame)
+    }
+}
+
+/// Redraws the terminal in place for each frame, pausing so it can be
+/// watched.
+pub struct Terminal {
+    pub delay: Duration,
+}
+
+impl Default for Terminal {
+    fn default() -> Self {
+        Terminal {
+            delay: Duration::from_millis(10),
+        }
+    }
+}
+
+impl Renderer for Terminal {
+    fn render(&mut self, frame: &Frame) -> io::Result<()> {
+        let mut stdout = io::stdout();
+        write!(stdout, "{}{}{}", clear::All, cursor::Goto(1, 1), frame)?;
+        stdout.flush()?;
+        thread::sleep(self.delay);
+
+        Ok(())
+    }
+}
+
+/// Writes each frame out as plain text, followed by a blank line.
+pub struct Dump<W: Write>(pub W);
+
+impl<W: Write> Renderer for Dump<W> {
+    fn render(&mut self, frame: &Frame) -> io::Result<()> {
+        writeln!(self.0, "{}", frame)
+    }
+}
+
+pub struct Null;
+
+impl Renderer for Null {
+    fn render(&mut self, _: &Frame) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Every frame of a game, to be saved and replayed later; written out
+/// the same way `Dump` does.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Recording {
+    pub frames: Vec<Frame>,
+}
+
+impl Recording {
+    pub fn score(&self) -> Option<i64> {
+        self.frames.last().map(|frame| frame.score)
+    }
+
+    pub fn replay(&self, renderer: &mut impl Renderer) -> io::Result<()> {
+        self.frames
+            .iter()
+            .try_for_each(|frame| renderer.render(frame))
+    }
+}
+
+impl Renderer for Recording {
+    fn render(&mut self, frame: &Frame) -> io::Result<()> {
+        self.frames.push(frame.clone());
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for Recording {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for frame in &self.frames {
+            writeln!(f, "{}", frame)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Recording {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let frames = s
+            .split_terminator("\n\n")
+            .enumerate()
+            .map(|(i, frame)| {
+                frame
+                    .parse()
+                    .map_err(|error: Error| error.with_context(format!("frame {}", i + 1)))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Recording { frames })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_recording() -> Recording {
+        Recording {
+            frames: vec![
+                Frame {
+                    rows: vec![String::from("+ @"), String::from(" = ")],
+                    score: 0,
+                },
+                Frame {
+                    rows: vec![String::from("+  "), String::from(" =@")],
+                    score: 11,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_round_trip() -> Result<()> {
+        let recording = get_recording();
+        let text = recording.to_string();
+
+        assert_eq!("+ @\n = \nScore: 0\n\n+  \n =@\nScore: 11\n\n", text);
+        assert_eq!(recording, Recording::from_str(&text)?);
+        assert!(Recording::from_str("+ @\n = \n").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_replay() -> io::Result<()> {
+        let recording = get_recording();
+        let mut dump = Dump(Vec::new());
+        let mut copy = Recording::default();
+
+        recording.replay(&mut dump)?;
+        recording.replay(&mut copy)?;
+
+        assert_eq!(recording.to_string().into_bytes(), dump.0);
+        assert_eq!(recording, copy);
+        assert_eq!(Some(11), copy.score());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,189 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+
+use aoc_error::Error;
+
+use crate::Reaction;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum FactoryError {
+    /// Chemicals that, by way of their reactions, each need the next and
+    /// the last the first.
+    Cycle(Vec<String>),
+    /// A chemical that's neither the base resource nor made by any
+    /// reaction.
+    Unreachable(String),
+    /// A chemical that takes none of the base resource to make, so
+    /// there's no most of it that can be made.
+    Free(String),
+}
+
+impl fmt::Display for FactoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FactoryError::Cycle(chemicals) => {
+                write!(f, "reactions form a cycle: {}", chemicals.join(" => "))
+            }
+            FactoryError::Unreachable(chemical) => {
+                write!(f, "nothing produces {}", chemical)
+            }
+            FactoryError::Free(chemical) => {
+                write!(f, "{} takes none of the base resource", chemical)
+            }
+        }
+    }
+}
+
+impl From<FactoryError> for Error {
+    fn from(error: FactoryError) -> Self {
+        Error::invalid_input(error.to_string())
+    }
+}
+
+/// What producing something took.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Production {
+    /// How much of the base resource was used.
+    pub base: usize,
+    /// Whatever was made beyond what was needed, by chemical.
+    pub surplus: BTreeMap<String, usize>,
+}
+
+/// The reactions as a graph from each product to its inputs, ending at
+/// the base resource.
+#[derive(Debug)]
+pub struct Factory {
+    reactions: HashMap<String, Reaction>,
+    base: String,
+    /// Every chemical, each before any it's made from, so that by the
+    /// time one is reached everything needing it has been counted.
+    order: Vec<String>,
+}
+
+impl Factory {
+    pub fn new(reactions: HashMap<String, Reaction>, base: &str) -> Result<Self, FactoryError> {
+        let mut factory = Factory {
+            reactions,
+            base: base.to_string(),
+            order: Vec::new(),
+        };
+
+        let mut done = HashSet::new();
+        let mut products = factory.reactions.keys().cloned().collect::<Vec<_>>();
+        products.sort();
+        for product in products {
+            let mut path = Vec::new();
+            factory.visit(&product, &mut path, &mut done)?;
+        }
+        factory.order.reverse();
+
+        Ok(factory)
+    }
+
+    /// Depth-first, appending `chemical` once all its inputs have been;
+    /// `path` holds the chemicals currently being visited, so meeting one
+    /// again is a cycle.
+    fn visit(
+        &mut self,
+        chemical: &str,
+        path: &mut Vec<String>,
+        done: &mut HashSet<String>,
+    ) -> Result<(), FactoryError> {
+        if done.contains(chemical) {
+            return Ok(());
+        }
+        if let Some(start) = path.iter().position(|visiting| visiting == chemical) {
+            return Err(FactoryError::Cycle(path[start..].to_vec()));
+        }
+
+        if chemical != self.base {
+            let inputs = self
+                .reactions
+                .get(chemical)
+                .ok_or_else(|| FactoryError::Unreachable(chemical.to_string()))?
+                .inputs
+                .iter()
+                .map(|input| input.name.clone())
+                .collect::<Vec<_>>();
+            path.push(chemical.to_string());
+            for input in inputs {
+                self.visit(&input, path, done)?;
+            }
+            path.pop();
+        }
+
+        done.insert(chemical.to_string());
+        self.order.push(chemical.to_string());
+
+        Ok(())
+    }
+
+    pub fn produce(&self, target: &str, quantity: usize) -> Result<Production, FactoryError> {
+        if !self.order.iter().any(|chemical| chemical == target) {
+            return Err(FactoryError::Unreachable(target.to_string()));
+        }
+
+        let mut needed = HashMap::new();
+        needed.insert(target, quantity);
+        let mut surplus = BTreeMap::new();
+
+        for chemical in self.order.iter().filter(|&chemical| *chemical != self.base) {
+            let amount = match needed.get(chemical.as_str()) {
+                Some(&amount) if amount > 0 => amount,
+                _ => continue,
+            };
+            let reaction = &self.reactions[chemical];
+            let runs = amount.div_ceil(reaction.product.quantity);
+            let extra = runs * reaction.product.quantity - amount;
+            if extra > 0 {
+                surplus.insert(chemical.clone(), extra);
+            }
+            for input in reaction.inputs.iter() {
+                *needed.entry(input.name.as_str()).or_default() += runs * input.quantity;
+            }
+        }
+
+        Ok(Production {
+            base: needed.get(self.base.as_str()).copied().unwrap_or(0),
+            surplus,
+        })
+    }
+
+    /// The most of `target` that can be made from `budget` of the base
+    /// resource.
+    ///
+    /// Making `n` never takes more than `n` times what one does, so that
+    /// many is always affordable; from there, double until it isn't and
+    /// then binary search between the two.
+    pub fn max_for_budget(&self, target: &str, budget: usize) -> Result<usize, FactoryError> {
+        if target == self.base {
+            return Ok(budget);
+        }
+        let affordable = |quantity| {
+            self.produce(target, quantity)
+                .map(|production| production.base <= budget)
+        };
+
+        let cost = self.produce(target, 1)?.base;
+        if cost == 0 {
+            return Err(FactoryError::Free(target.to_string()));
+        }
+
+        let mut low = budget / cost;
+        let mut high = low.max(1) * 2;
+        while affordable(high)? {
+            low = high;
+            high *= 2;
+        }
+        while high - low > 1 {
+            let middle = low + (high - low) / 2;
+            if affordable(middle)? {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+
+        Ok(low)
+    }
+}
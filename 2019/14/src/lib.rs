@@ -0,0 +1,265 @@
+use std::collections::HashMap;
+
+use aoc_error::{parse_lines, Context, Result};
+
+use crate::factory::Factory;
+
+pub mod factory;
+
+pub const TRILLION: usize = 1_000_000_000_000;
+
+#[derive(Debug, Eq, Hash, PartialEq)]
+pub struct Chemical {
+    name: String,
+    quantity: usize,
+}
+
+#[derive(Debug, Eq, Hash, PartialEq)]
+pub struct Reaction {
+    inputs: Vec<Chemical>,
+    product: Chemical,
+}
+
+impl Reaction {
+    fn from_string(input: &str) -> Result<Self> {
+        let (inputs, product) = input.split_once("=>").context("expected \"=>\"")?;
+        let product = Chemical::from_input_string(product)?;
+        let inputs = inputs
+            .split(',')
+            .map(Chemical::from_input_string)
+            .collect::<Result<Vec<Chemical>>>()?;
+
+        Ok(Reaction { inputs, product })
+    }
+}
+
+impl Chemical {
+    fn from_input_string(input: &str) -> Result<Self> {
+        let (quantity, name) = input
+            .trim()
+            .split_once(' ')
+            .with_context(|| format!("expected a quantity and chemical in {:?}", input.trim()))?;
+
+        Ok(Chemical {
+            name: String::from(name.trim()),
+            quantity: quantity.parse::<usize>()?,
+        })
+    }
+}
+
+pub fn get_reactions(input: &str) -> Result<HashMap<String, Reaction>> {
+    let reactions = parse_lines(input.trim_end(), Reaction::from_string)?;
+
+    Ok(reactions
+        .into_iter()
+        .map(|reaction| (reaction.product.name.clone(), reaction))
+        .collect())
+}
+
+pub fn get_part_one(input: &str) -> Result<usize> {
+    Ok(Factory::new(get_reactions(input)?, "ORE")?
+        .produce("FUEL", 1)
+        .map(|production| production.base)?)
+}
+
+pub fn get_part_two(input: &str) -> Result<usize> {
+    Ok(Factory::new(get_reactions(input)?, "ORE")?.max_for_budget("FUEL", TRILLION)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_error::Location;
+
+    use super::*;
+    use crate::factory::FactoryError;
+
+    fn get_fuel(input: &str) -> usize {
+        Factory::new(get_reactions(input).unwrap(), "ORE")
+            .unwrap()
+            .produce("FUEL", 1)
+            .unwrap()
+            .base
+    }
+
+    fn get_fuel_per_trillion(input: &str) -> usize {
+        Factory::new(get_reactions(input).unwrap(), "ORE")
+            .unwrap()
+            .max_for_budget("FUEL", TRILLION)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_31() {
+        let input = String::from(
+            r#"10 ORE => 10 A
+1 ORE => 1 B
+7 A, 1 B => 1 C
+7 A, 1 C => 1 D
+7 A, 1 D => 1 E
+7 A, 1 E => 1 FUEL"#,
+        );
+        assert_eq!(get_fuel(&input), 31);
+    }
+
+    #[test]
+    fn test_165() {
+        let input = String::from(
+            r#"9 ORE => 2 A
+8 ORE => 3 B
+7 ORE => 5 C
+3 A, 4 B => 1 AB
+5 B, 7 C => 1 BC
+4 C, 1 A => 1 CA
+2 AB, 3 BC, 4 CA => 1 FUEL"#,
+        );
+        assert_eq!(get_fuel(&input), 165);
+    }
+
+    #[test]
+    fn test_13312() {
+        let input = String::from(
+            r#"157 ORE => 5 NZVS
+165 ORE => 6 DCFZ
+44 XJWVT, 5 KHKGT, 1 QDVJ, 29 NZVS, 9 GPVTF, 48 HKGWZ => 1 FUEL
+12 HKGWZ, 1 GPVTF, 8 PSHF => 9 QDVJ
+179 ORE => 7 PSHF
+177 ORE => 5 HKGWZ
+7 DCFZ, 7 PSHF => 2 XJWVT
+165 ORE => 2 GPVTF
+3 DCFZ, 7 NZVS, 5 HKGWZ, 10 PSHF => 8 KHKGT"#,
+        );
+        assert_eq!(get_fuel(&input), 13312);
+        assert_eq!(get_fuel_per_trillion(&input), 82892753);
+    }
+
+    #[test]
+    fn test_180697() {
+        let input = String::from(
+            r#"2 VPVL, 7 FWMGM, 2 CXFTF, 11 MNCFX => 1 STKFG
+17 NVRVD, 3 JNWZP => 8 VPVL
+53 STKFG, 6 MNCFX, 46 VJHF, 81 HVMC, 68 CXFTF, 25 GNMV => 1 FUEL
+22 VJHF, 37 MNCFX => 5 FWMGM
+139 ORE => 4 NVRVD
+144 ORE => 7 JNWZP
+5 MNCFX, 7 RFSQX, 2 FWMGM, 2 VPVL, 19 CXFTF => 3 HVMC
+5 VJHF, 7 MNCFX, 9 VPVL, 37 CXFTF => 6 GNMV
+145 ORE => 6 MNCFX
+1 NVRVD => 8 CXFTF
+1 VJHF, 6 MNCFX => 4 RFSQX
+176 ORE => 6 VJHF"#,
+        );
+        assert_eq!(get_fuel(&input), 180697);
+        assert_eq!(get_fuel_per_trillion(&input), 5586022);
+    }
+
+    #[test]
+    fn test_2210736() {
+        let input = String::from(
+            r#"171 ORE => 8 CNZTR
+7 ZLQW, 3 BMBT, 9 XCVML, 26 XMNCP, 1 WPTQ, 2 MZWV, 1 RJRHP => 4 PLWSL
+114 ORE => 4 BHXH
+14 VRPVC => 6 BMBT
+6 BHXH, 18 KTJDG, 12 WPTQ, 7 PLWSL, 31 FHTLT, 37 ZDVW => 1 FUEL
+6 WPTQ, 2 BMBT, 8 ZLQW, 18 KTJDG, 1 XMNCP, 6 MZWV, 1 RJRHP => 6 FHTLT
+15 XDBXC, 2 LTCX, 1 VRPVC => 6 ZLQW
+13 WPTQ, 10 LTCX, 3 RJRHP, 14 XMNCP, 2 MZWV, 1 ZLQW => 1 ZDVW
+5 BMBT => 4 WPTQ
+189 ORE => 9 KTJDG
+1 MZWV, 17 XDBXC, 3 XCVML => 2 XMNCP
+12 VRPVC, 27 CNZTR => 2 XDBXC
+15 KTJDG, 12 BHXH => 5 XCVML
+3 BHXH, 2 VRPVC => 7 MZWV
+121 ORE => 7 VRPVC
+7 XCVML => 6 RJRHP
+5 BHXH, 4 VRPVC => 5 LTCX"#,
+        );
+        assert_eq!(get_fuel(&input), 2210736);
+        assert_eq!(get_fuel_per_trillion(&input), 460664);
+    }
+
+    #[test]
+    fn test_surplus() {
+        let input = String::from(
+            r#"10 ORE => 10 A
+1 ORE => 1 B
+7 A, 1 B => 1 C
+7 A, 1 C => 1 D
+7 A, 1 D => 1 E
+7 A, 1 E => 1 FUEL"#,
+        );
+        let factory = Factory::new(get_reactions(&input).unwrap(), "ORE").unwrap();
+        let production = factory.produce("FUEL", 1).unwrap();
+
+        assert_eq!(31, production.base);
+        assert_eq!(
+            vec![(String::from("A"), 2)],
+            production.surplus.into_iter().collect::<Vec<_>>(),
+        );
+        assert_eq!(11, factory.produce("C", 1).unwrap().base);
+        assert_eq!(10, factory.max_for_budget("A", 14).unwrap());
+    }
+
+    #[test]
+    fn test_base() {
+        let input = String::from(
+            r#"3 SAND => 2 GLASS
+2 GLASS, 1 SAND => 1 LENS"#,
+        );
+        let factory = Factory::new(get_reactions(&input).unwrap(), "SAND").unwrap();
+
+        assert_eq!(4, factory.produce("LENS", 1).unwrap().base);
+        assert_eq!(7, factory.max_for_budget("LENS", 30).unwrap());
+        assert_eq!(30, factory.max_for_budget("SAND", 30).unwrap());
+        assert_eq!(
+            FactoryError::Unreachable(String::from("SAND")),
+            Factory::new(get_reactions(&input).unwrap(), "ORE").unwrap_err(),
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let cycle = String::from(
+            r#"1 ORE => 1 A
+1 A, 1 C => 1 B
+1 B => 1 C
+1 C => 1 FUEL"#,
+        );
+        assert_eq!(
+            FactoryError::Cycle(vec![String::from("B"), String::from("C")]),
+            Factory::new(get_reactions(&cycle).unwrap(), "ORE").unwrap_err(),
+        );
+
+        let unreachable = String::from(
+            r#"1 ORE => 1 A
+1 A, 1 X => 1 FUEL"#,
+        );
+        assert_eq!(
+            FactoryError::Unreachable(String::from("X")),
+            Factory::new(get_reactions(&unreachable).unwrap(), "ORE").unwrap_err(),
+        );
+
+        let factory = Factory::new(get_reactions("1 ORE => 1 FUEL").unwrap(), "ORE").unwrap();
+        assert_eq!(
+            Err(FactoryError::Unreachable(String::from("GOLD"))),
+            factory.produce("GOLD", 1),
+        );
+
+        let factory = Factory::new(get_reactions("0 ORE => 1 FUEL").unwrap(), "ORE").unwrap();
+        assert_eq!(
+            Err(FactoryError::Free(String::from("FUEL"))),
+            factory.max_for_budget("FUEL", TRILLION),
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Err(Some(Location::line(2))),
+            get_reactions("1 ORE => 1 A\n1 A 1 FUEL").map_err(|error| error.location()),
+        );
+        assert_eq!(
+            Err(Some(Location::line(1))),
+            get_reactions("x ORE => 1 FUEL").map_err(|error| error.location()),
+        );
+    }
+}
@@ -0,0 +1,122 @@
+use aoc_error::{parse_fields, Error, Result};
+use intcode::{Intcode, State};
+
+use crate::ship::{Movement, Ship, Tile};
+
+pub mod ship;
+
+pub struct Droid {
+    intcode: Intcode,
+}
+
+impl Droid {
+    pub fn new(input: &str) -> Result<Self> {
+        let memory = parse_fields(input.trim_end(), ',', |value| value.trim().parse())?;
+
+        Ok(Droid {
+            intcode: Intcode::new(memory),
+        })
+    }
+
+    /// Issues a movement command, returning what the droid found there.
+    fn issue(&mut self, movement: Movement) -> Tile {
+        self.intcode.push_input(movement.command());
+        match self.intcode.run().unwrap() {
+            State::Output(status) => Tile::from_status(status)
+                .unwrap_or_else(|| panic!("Invalid status code: {}", status)),
+            state => panic!("Droid stopped without a status: {:?}", state),
+        }
+    }
+
+    /// Explores depth-first, backtracking once every tile around has been
+    /// tried, until the droid is back where it started.
+    pub fn map_section(&mut self) -> Ship {
+        let mut backtrace = Vec::new();
+        let mut ship = Ship::default();
+
+        let mut current = (0, 0);
+        while let Some(movement) = Movement::ALL
+            .iter()
+            .copied()
+            .find(|movement| *ship.tiles.get(movement.step(current)) == Tile::Unknown)
+            .or_else(|| backtrace.pop())
+        {
+            let next = movement.step(current);
+            let unvisited = *ship.tiles.get(next) == Tile::Unknown;
+            let tile = self.issue(movement);
+            ship.set(next, tile);
+            if tile != Tile::Wall {
+                if unvisited {
+                    backtrace.push(movement.reverse());
+                }
+                current = next;
+            }
+        }
+
+        ship
+    }
+
+    /// Replays a route from the droid's start, returning the tile it ends
+    /// up on; fails if it walks into a wall on the way.
+    pub fn follow(&mut self, route: &[Movement]) -> Result<Tile, String> {
+        route
+            .iter()
+            .try_fold(Tile::Open, |_, movement| match self.issue(*movement) {
+                Tile::Wall => Err(format!("Hit a wall moving {}", movement)),
+                tile => Ok(tile),
+            })
+    }
+}
+
+pub fn get_part_one(input: &str) -> Result<usize> {
+    let ship = Droid::new(input)?.map_section();
+    let oxygen = ship
+        .oxygen
+        .ok_or_else(|| Error::no_solution("No oxygen system found"))?;
+
+    ship.route((0, 0), oxygen)
+        .map(|route| route.len())
+        .ok_or_else(|| Error::no_solution("No route to the oxygen system"))
+}
+
+pub fn get_part_two(input: &str) -> Result<usize> {
+    Droid::new(input)?
+        .map_section()
+        .oxygen_timeline()
+        .map(|timeline| timeline.len() - 1)
+        .ok_or_else(|| Error::no_solution("No oxygen system found"))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_error::Location;
+
+    use super::*;
+
+    /// Answers open, open, then oxygen to whatever it's told.
+    const PROGRAM: &str = "3,100,104,1,3,100,104,1,3,100,104,2,99";
+
+    #[test]
+    fn test_follow() {
+        let route = [Movement::North, Movement::East, Movement::East];
+
+        assert_eq!(
+            Ok(Tile::Oxygen),
+            Droid::new(PROGRAM).unwrap().follow(&route)
+        );
+        assert_eq!(
+            Ok(Tile::Open),
+            Droid::new(PROGRAM).unwrap().follow(&route[..2])
+        );
+    }
+
+    #[test]
+    fn test_invalid_program() {
+        assert_eq!(
+            Err(Some(Location::line_column(1, 5))),
+            Droid::new("3,1,x,99")
+                .map(|_| ())
+                .map_err(|error| error.location()),
+        );
+    }
+}
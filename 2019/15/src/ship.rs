@@ -0,0 +1,306 @@
+use std::collections::{BTreeMap, HashMap};
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+
+use aoc_error::{Error, Result};
+use aoc_grid::{Direction, InfiniteGrid};
+use aoc_search::{bidirectional_bfs, distances, State};
+
+/// The droid's movement commands, numbered as the program expects them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Movement {
+    North = 1,
+    South = 2,
+    West = 3,
+    East = 4,
+}
+
+impl Movement {
+    pub const ALL: [Movement; 4] = [
+        Movement::North,
+        Movement::South,
+        Movement::West,
+        Movement::East,
+    ];
+
+    pub fn command(&self) -> i64 {
+        *self as i64
+    }
+
+    pub fn step(&self, (x, y): Direction) -> Direction {
+        match self {
+            Movement::North => (x, y - 1),
+            Movement::South => (x, y + 1),
+            Movement::West => (x - 1, y),
+            Movement::East => (x + 1, y),
+        }
+    }
+
+    pub fn reverse(&self) -> Self {
+        match self {
+            Movement::North => Movement::South,
+            Movement::South => Movement::North,
+            Movement::West => Movement::East,
+            Movement::East => Movement::West,
+        }
+    }
+
+    /// The movement taking the droid from one tile to the one next to it.
+    fn between(from: Direction, to: Direction) -> Option<Self> {
+        Movement::ALL
+            .iter()
+            .copied()
+            .find(|movement| movement.step(from) == to)
+    }
+}
+
+impl fmt::Display for Movement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Movement::North => "north",
+            Movement::South => "south",
+            Movement::West => "west",
+            Movement::East => "east",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Tile {
+    Unknown,
+    Wall,
+    Open,
+    Oxygen,
+}
+
+impl Tile {
+    /// What the droid's status code says about where it tried to move.
+    pub fn from_status(status: i64) -> Option<Self> {
+        match status {
+            0 => Some(Tile::Wall),
+            1 => Some(Tile::Open),
+            2 => Some(Tile::Oxygen),
+            _ => None,
+        }
+    }
+
+    fn is_open(&self) -> bool {
+        matches!(self, Tile::Open | Tile::Oxygen)
+    }
+}
+
+impl fmt::Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            Tile::Unknown => ' ',
+            Tile::Wall => '#',
+            Tile::Open => '.',
+            Tile::Oxygen => 'O',
+        };
+
+        write!(f, "{}", c)
+    }
+}
+
+impl TryFrom<char> for Tile {
+    type Error = Error;
+
+    fn try_from(c: char) -> Result<Self> {
+        match c {
+            ' ' => Ok(Tile::Unknown),
+            '#' => Ok(Tile::Wall),
+            '.' => Ok(Tile::Open),
+            'O' => Ok(Tile::Oxygen),
+            _ => Err(Error::invalid_input(format!("unknown tile {:?}", c))),
+        }
+    }
+}
+
+/// Everything the droid has found, relative to where it started at
+/// `(0, 0)`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Ship {
+    pub tiles: InfiniteGrid<Tile>,
+    pub oxygen: Option<Direction>,
+}
+
+/// Nothing known yet but the droid's own tile.
+impl Default for Ship {
+    fn default() -> Self {
+        let mut tiles = InfiniteGrid::new(Tile::Unknown);
+        tiles.set((0, 0), Tile::Open);
+
+        Ship {
+            tiles,
+            oxygen: None,
+        }
+    }
+}
+
+impl Ship {
+    pub fn set(&mut self, position: Direction, tile: Tile) {
+        if tile == Tile::Oxygen {
+            self.oxygen = Some(position);
+        }
+        self.tiles.set(position, tile);
+    }
+
+    /// The fewest moves to every open tile from `origin`.
+    pub fn distances(&self, origin: Direction) -> HashMap<Direction, usize> {
+        distances(Position(origin), self)
+            .into_iter()
+            .map(|(Position(position), distance)| (position, distance))
+            .collect()
+    }
+
+    /// The shortest way between two open tiles, as commands for the droid.
+    pub fn route(&self, from: Direction, to: Direction) -> Option<Vec<Movement>> {
+        let path = bidirectional_bfs(Position(from), Position(to), self).path?;
+
+        path.states
+            .windows(2)
+            .map(|pair| Movement::between(pair[0].0, pair[1].0))
+            .collect()
+    }
+
+    /// Which tiles fill with oxygen at each minute, starting with the
+    /// oxygen system itself at minute 0; the last minute is when the
+    /// ship is full.
+    pub fn oxygen_timeline(&self) -> Option<Vec<Vec<Direction>>> {
+        let mut minutes: BTreeMap<usize, Vec<Direction>> = BTreeMap::new();
+        for (position, minute) in self.distances(self.oxygen?) {
+            minutes.entry(minute).or_default().push(position);
+        }
+
+        Some(
+            minutes
+                .into_values()
+                .map(|mut tiles| {
+                    tiles.sort_by_key(|&(x, y)| (y, x));
+                    tiles
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Drawn with the droid's starting point as `D`.
+impl fmt::Display for Ship {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ((min_x, min_y), (max_x, max_y)) = match self.tiles.bounds() {
+            Some(bounds) => bounds,
+            None => return Ok(()),
+        };
+
+        for y in min_y..=max_y {
+            if y > min_y {
+                writeln!(f)?;
+            }
+            for x in min_x..=max_x {
+                match (x, y) {
+                    (0, 0) => write!(f, "D")?,
+                    position => write!(f, "{}", self.tiles.get(position))?,
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Ship {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (ox, oy) = s
+            .lines()
+            .enumerate()
+            .find_map(|(y, line)| line.find('D').map(|x| (x as isize, y as isize)))
+            .ok_or_else(|| Error::invalid_input("no D marking the droid's start"))?;
+
+        let mut ship = Ship::default();
+        for (y, line) in s.lines().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                let position = (x as isize - ox, y as isize - oy);
+                if c != 'D' {
+                    ship.set(position, Tile::try_from(c)?);
+                }
+            }
+        }
+
+        Ok(ship)
+    }
+}
+
+/// An open tile, for searching the ship.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct Position(Direction);
+
+impl State for Position {
+    type Context = Ship;
+
+    fn successors(&self, ship: &Ship) -> Vec<(Self, usize)> {
+        Movement::ALL
+            .iter()
+            .map(|movement| movement.step(self.0))
+            .filter(|&position| ship.tiles.get(position).is_open())
+            .map(|position| (Position(position), 1))
+            .collect()
+    }
+
+    fn is_goal(&self, ship: &Ship) -> bool {
+        ship.oxygen == Some(self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SHIP: &str = " ##   \n#..## \n#.#D.#\n#.O.# \n ###  ";
+
+    #[test]
+    fn test_round_trip() -> Result<()> {
+        let ship = Ship::from_str(SHIP)?;
+
+        assert_eq!(Some((-1, 1)), ship.oxygen);
+        assert_eq!(SHIP, ship.to_string());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_timeline() -> Result<()> {
+        let timeline = Ship::from_str(SHIP)?.oxygen_timeline();
+
+        assert_eq!(
+            Some(vec![
+                vec![(-1, 1)],
+                vec![(-2, 1), (0, 1)],
+                vec![(-2, 0), (0, 0)],
+                vec![(-2, -1), (1, 0)],
+                vec![(-1, -1)],
+            ]),
+            timeline,
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_route() -> Result<()> {
+        let ship = Ship::from_str(SHIP)?;
+
+        assert_eq!(
+            Some(vec![Movement::South, Movement::West]),
+            ship.route((0, 0), (-1, 1)),
+        );
+        assert_eq!(None, ship.route((0, 0), (5, 5)));
+        assert_eq!(Some(2), ship.distances((0, 0)).get(&(-1, 1)).copied());
+
+        Ok(())
+    }
+}
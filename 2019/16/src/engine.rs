@@ -0,0 +1,110 @@
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// How each phase's output digits are worked out.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Backend {
+    Serial,
+    #[cfg(feature = "parallel")]
+    Parallel,
+}
+
+impl Default for Backend {
+    #[cfg(feature = "parallel")]
+    fn default() -> Self {
+        Backend::Parallel
+    }
+
+    #[cfg(not(feature = "parallel"))]
+    fn default() -> Self {
+        Backend::Serial
+    }
+}
+
+/// A signal of `repeat` copies of some digits, of which only the part
+/// from `offset` on is kept: the pattern for any output digit is zero
+/// before its own position, so nothing earlier ever feeds into it.
+///
+/// Each output digit sums alternating runs of its block size, so with
+/// prefix sums it costs one lookup per run; that's `n / (i + 1)` for the
+/// `i`th digit and `O(n log n)` for a phase.
+#[derive(Clone, Debug)]
+pub struct Transmission {
+    offset: usize,
+    signal: Vec<isize>,
+    backend: Backend,
+}
+
+impl Transmission {
+    pub fn new(digits: &[isize], repeat: usize, offset: usize) -> Self {
+        let signal = digits
+            .iter()
+            .copied()
+            .cycle()
+            .take(digits.len() * repeat)
+            .skip(offset)
+            .collect();
+
+        Transmission {
+            offset,
+            signal,
+            backend: Backend::default(),
+        }
+    }
+
+    pub fn with_backend(mut self, backend: Backend) -> Self {
+        self.backend = backend;
+        self
+    }
+
+    pub fn phase(&mut self) {
+        let mut prefix = Vec::with_capacity(self.signal.len() + 1);
+        prefix.push(0);
+        for digit in &self.signal {
+            prefix.push(prefix[prefix.len() - 1] + digit);
+        }
+
+        let digit = |i| Transmission::digit(&prefix, self.offset, i);
+        self.signal = match self.backend {
+            Backend::Serial => (0..self.signal.len()).map(digit).collect(),
+            #[cfg(feature = "parallel")]
+            Backend::Parallel => (0..self.signal.len()).into_par_iter().map(digit).collect(),
+        };
+    }
+
+    pub fn run(&mut self, phases: usize) {
+        (0..phases).for_each(|_| self.phase());
+    }
+
+    /// The output digit `i` places after the offset, given prefix sums of
+    /// the kept signal.
+    fn digit(prefix: &[isize], offset: usize, i: usize) -> isize {
+        let len = prefix.len() - 1;
+        let block = offset + i + 1;
+        let sum = |start: usize| {
+            let end = (start + block).min(len);
+            prefix[end] - prefix[start]
+        };
+
+        let mut total = 0;
+        let mut start = i;
+        while start < len {
+            total += sum(start);
+            if start + 2 * block < len {
+                total -= sum(start + 2 * block);
+            }
+            start += 4 * block;
+        }
+
+        total.abs() % 10
+    }
+
+    /// The first `count` digits from the offset.
+    pub fn digits(&self, count: usize) -> String {
+        self.signal
+            .iter()
+            .take(count)
+            .map(|digit| digit.to_string())
+            .collect()
+    }
+}
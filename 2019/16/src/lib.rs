@@ -0,0 +1,201 @@
+use aoc_error::{Error, Location, Result};
+
+use crate::engine::{Backend, Transmission};
+
+pub mod engine;
+
+pub fn parse_digits(input: &str) -> Result<Vec<isize>> {
+    let start = input.len() - input.trim_start().len();
+
+    input
+        .trim()
+        .char_indices()
+        .map(|(i, c)| {
+            c.to_digit(10).map(|digit| digit as isize).ok_or_else(|| {
+                Error::invalid_input(format!("invalid digit {:?}", c))
+                    .at(Location::from_offset(input, start + i))
+            })
+        })
+        .collect()
+}
+
+/// The message is at the offset given by the first seven digits, in the
+/// signal repeated 10,000 times; there must be all eight digits of it
+/// after the offset.
+pub fn get_message(digits: &[isize], phases: usize, backend: Backend) -> Result<String> {
+    let offset = digits
+        .iter()
+        .take(7)
+        .fold(0, |offset, digit| offset * 10 + *digit as usize);
+    let length = digits.len() * 10_000;
+    if offset + 8 > length {
+        return Err(Error::invalid_input(format!(
+            "offset {} leaves no message in a signal of length {}",
+            offset, length,
+        )));
+    }
+    let mut transmission = Transmission::new(digits, 10_000, offset).with_backend(backend);
+    transmission.run(phases);
+
+    Ok(transmission.digits(8))
+}
+
+pub fn get_part_one(input: &str) -> Result<String> {
+    let mut transmission = Transmission::new(&parse_digits(input)?, 1, 0);
+    transmission.run(100);
+
+    Ok(transmission.digits(8))
+}
+
+pub fn get_part_two(input: &str) -> Result<String> {
+    get_message(&parse_digits(input)?, 100, Backend::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_pattern(digits: &[isize], position: usize) -> Vec<isize> {
+        let base = [0, 1, 0, -1];
+
+        let mut pattern = base
+            .iter()
+            .flat_map(|digit| vec![*digit as isize; position])
+            .collect::<Vec<_>>();
+        let repeat = (digits.len() / pattern.len()) + 1;
+        pattern = pattern
+            .iter()
+            .copied()
+            .cycle()
+            .take(pattern.len() * repeat)
+            .collect::<Vec<_>>();
+        Vec::from(&pattern[1..=digits.len()])
+    }
+
+    fn fft(digits: &[isize], phase: usize) -> String {
+        let mut input = digits.to_owned();
+
+        for _ in 0..phase {
+            let mut elements: Vec<isize> = Vec::new();
+            for position in 1..=input.len() {
+                let pattern = get_pattern(&input, position);
+
+                let output: isize = input.iter().zip(pattern.iter()).map(|(a, b)| *a * *b).sum();
+                elements.push(output.abs() % 10);
+            }
+            input = elements.clone();
+        }
+        input
+            .iter()
+            .map(|digit| digit.to_string())
+            .collect::<String>()
+    }
+
+    /// Every backend, run against the naive version for a range of
+    /// offsets and repetitions.
+    fn check(input: &str, phases: usize) {
+        let digits = parse_digits(input).unwrap();
+        let backends = [
+            Backend::Serial,
+            #[cfg(feature = "parallel")]
+            Backend::Parallel,
+        ];
+
+        for repeat in 1..=3 {
+            let repeated = digits.repeat(repeat);
+            let expected = fft(&repeated, phases);
+            for offset in 0..repeated.len() {
+                for backend in backends.iter() {
+                    let mut transmission =
+                        Transmission::new(&digits, repeat, offset).with_backend(*backend);
+                    transmission.run(phases);
+                    assert_eq!(
+                        &expected[offset..],
+                        transmission.digits(usize::MAX),
+                        "repeat {}, offset {}",
+                        repeat,
+                        offset,
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_against_naive() {
+        check("12345678", 4);
+        check("80871224585914546619083218645595", 10);
+        check("03036732577212944063491565474664", 3);
+    }
+
+    #[test]
+    fn test_12345678() {
+        let input = String::from("12345678");
+        let digits = parse_digits(&input).unwrap();
+        assert_eq!("01029498", fft(&digits, 4));
+    }
+
+    #[test]
+    fn test_80871224585914546619083218645595() {
+        let input = String::from("80871224585914546619083218645595");
+        let digits = parse_digits(&input).unwrap();
+        let output = fft(&digits, 100);
+        assert_eq!("24176176", &output[..8]);
+    }
+
+    #[test]
+    fn test_19617804207202209144916044189917() {
+        let input = String::from("19617804207202209144916044189917");
+        let digits = parse_digits(&input).unwrap();
+        let output = fft(&digits, 100);
+        assert_eq!("73745418", &output[..8]);
+    }
+
+    #[test]
+    fn test_69317163492948606335995924319873() {
+        let input = String::from("69317163492948606335995924319873");
+        let digits = parse_digits(&input).unwrap();
+        let output = fft(&digits, 100);
+        assert_eq!("52432133", &output[..8]);
+    }
+
+    #[test]
+    fn test_03036732577212944063491565474664() {
+        let input = String::from("03036732577212944063491565474664");
+        let output = get_message(&parse_digits(&input).unwrap(), 100, Backend::default()).unwrap();
+        assert_eq!("84462026", output);
+    }
+
+    #[test]
+    fn test_02935109699940807407585447034323() {
+        let input = String::from("02935109699940807407585447034323");
+        let output = get_message(&parse_digits(&input).unwrap(), 100, Backend::default()).unwrap();
+        assert_eq!("78725270", output);
+    }
+
+    #[test]
+    fn test_03081770884921959731165446850517() {
+        let input = String::from("03081770884921959731165446850517");
+        let output = get_message(&parse_digits(&input).unwrap(), 100, Backend::default()).unwrap();
+        assert_eq!("53553731", output);
+    }
+
+    #[test]
+    fn test_invalid_digit() {
+        assert_eq!(
+            Err(Some(Location::line_column(1, 4))),
+            parse_digits(" 12x4\n").map_err(|error| error.location()),
+        );
+    }
+
+    #[test]
+    fn test_offset_out_of_range() {
+        let digits = parse_digits("9999999").unwrap();
+        assert!(get_message(&digits, 100, Backend::default()).is_err());
+
+        let digits = parse_digits("0069993").unwrap();
+        assert!(get_message(&digits, 1, Backend::default()).is_err());
+        let digits = parse_digits("0069992").unwrap();
+        assert!(get_message(&digits, 1, Backend::default()).is_ok());
+    }
+}
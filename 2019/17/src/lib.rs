@@ -0,0 +1,145 @@
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+use intcode::{Intcode, State};
+
+use crate::routine::{get_path, Routine};
+
+mod routine;
+
+struct Droid {
+    intcode: Intcode,
+}
+
+impl Droid {
+    fn new(input: &str) -> Self {
+        Droid {
+            intcode: Intcode::from_str(input).unwrap(),
+        }
+    }
+
+    fn wake_up(&mut self) {
+        self.intcode.write(0, 2);
+    }
+
+    /// Sends the robot along `routine`, returning the dust it reports
+    /// collecting. With `video`, every frame of the feed is shown as it
+    /// arrives.
+    fn run_routine(&mut self, routine: &Routine, video: bool) -> Option<i64> {
+        let feed = if video { "y" } else { "n" };
+        for input in routine.to_inputs().iter().map(String::as_str).chain([feed]) {
+            self.intcode.push_ascii(input);
+            self.intcode.push_ascii("\n");
+        }
+
+        let mut dust = None;
+        while let State::Output(output) = self.intcode.run().unwrap() {
+            match u8::try_from(output).ok().filter(u8::is_ascii) {
+                Some(c) if video => print!("{}", char::from(c)),
+                Some(_) => {}
+                None => dust = Some(output),
+            }
+        }
+
+        dust
+    }
+}
+
+fn map_shield(droid: &mut Droid) -> Vec<Vec<char>> {
+    let mut shield = Vec::new();
+    let mut row = Vec::new();
+
+    while let State::Output(c) = droid.intcode.run().unwrap() {
+        match char::from(c as u8) {
+            '\n' => {
+                if !row.is_empty() {
+                    shield.push(row);
+                    row = Vec::new();
+                }
+            }
+            x => row.push(x),
+        };
+    }
+    shield
+}
+
+#[allow(clippy::collapsible_if)]
+fn locate_intersections(shield: &[Vec<char>]) -> Vec<(usize, usize)> {
+    let mut intersections = Vec::new();
+
+    for (y, row) in shield.iter().enumerate() {
+        for (x, _) in row.iter().enumerate() {
+            if x > 0 && y > 0 && x < (row.len() - 1) && y < (shield.len() - 1) {
+                if shield[y][x] == '#'
+                    && shield[y - 1][x] == '#'
+                    && shield[y][x + 1] == '#'
+                    && shield[y + 1][x] == '#'
+                    && shield[y][x - 1] == '#'
+                {
+                    intersections.push((x, y));
+                }
+            }
+        }
+    }
+    intersections
+}
+
+#[allow(dead_code)]
+fn render_shield(shield: &[Vec<char>]) {
+    for row in shield.iter() {
+        for point in row.iter() {
+            print!("{}", point);
+        }
+        println!();
+    }
+}
+
+/// The dust collected once the robot has been along the whole scaffold;
+/// with `video`, every frame of the feed is shown as it arrives.
+pub fn get_dust(input: &str, video: bool) -> Result<i64, String> {
+    let shield = map_shield(&mut Droid::new(input.trim()));
+    let path = get_path(&shield).ok_or_else(|| String::from("Couldn't find the robot"))?;
+    let routine =
+        Routine::compress(&path).ok_or_else(|| String::from("Couldn't fit the path in memory"))?;
+
+    let mut droid = Droid::new(input.trim());
+    droid.wake_up();
+    droid
+        .run_routine(&routine, video)
+        .ok_or_else(|| String::from("The robot didn't report any dust"))
+}
+
+pub fn get_part_one(input: &str) -> usize {
+    let shield = map_shield(&mut Droid::new(input.trim()));
+
+    locate_intersections(&shield)
+        .iter()
+        .map(|(x, y)| *x * *y)
+        .sum()
+}
+
+pub fn get_part_two(input: &str) -> Result<i64, String> {
+    get_dust(input, false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let shield = r#"..#..........
+..#..........
+#######...###
+#.#...#...#.#
+#############
+..#...#...#..
+..#####...^.."#
+            .lines()
+            .map(|line| line.chars().collect::<Vec<char>>())
+            .collect::<Vec<Vec<char>>>();
+        let intersections = locate_intersections(&shield);
+        let sum_alignment_parameters: usize = intersections.iter().map(|(x, y)| *x * *y).sum();
+        assert_eq!(sum_alignment_parameters, 76);
+    }
+}
@@ -0,0 +1,226 @@
+use std::convert::TryFrom;
+use std::fmt;
+
+/// The most characters the robot's memory holds for the main routine or
+/// any one movement function, not counting the newline.
+const MEMORY: usize = 20;
+
+const FUNCTIONS: [char; 3] = ['A', 'B', 'C'];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Turn {
+    Left,
+    Right,
+}
+
+/// A turn followed by moving forward some number of steps.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Move {
+    turn: Turn,
+    steps: usize,
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let turn = match self.turn {
+            Turn::Left => 'L',
+            Turn::Right => 'R',
+        };
+
+        write!(f, "{},{}", turn, self.steps)
+    }
+}
+
+fn join<T: fmt::Display>(items: &[T]) -> String {
+    items
+        .iter()
+        .map(|item| item.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Follows the scaffold from the robot to its far end, carrying straight
+/// on through every intersection.
+pub fn get_path(scaffold: &[Vec<char>]) -> Option<Vec<Move>> {
+    let is_scaffold = |(x, y): (isize, isize)| {
+        usize::try_from(y)
+            .ok()
+            .and_then(|y| scaffold.get(y))
+            .and_then(|row| usize::try_from(x).ok().and_then(|x| row.get(x)))
+            .is_some_and(|c| *c == '#')
+    };
+
+    let (mut position, mut direction) = scaffold.iter().enumerate().find_map(|(y, row)| {
+        row.iter().enumerate().find_map(|(x, c)| {
+            let direction = match c {
+                '^' => (0, -1),
+                '>' => (1, 0),
+                'v' => (0, 1),
+                '<' => (-1, 0),
+                _ => return None,
+            };
+            Some(((x as isize, y as isize), direction))
+        })
+    })?;
+
+    let mut path = Vec::new();
+    loop {
+        let (dx, dy): (isize, isize) = direction;
+        let (left, right) = ((dy, -dx), (-dy, dx));
+        let ahead = |(x, y): (isize, isize), (dx, dy): (isize, isize)| (x + dx, y + dy);
+
+        let turn = if is_scaffold(ahead(position, left)) {
+            direction = left;
+            Turn::Left
+        } else if is_scaffold(ahead(position, right)) {
+            direction = right;
+            Turn::Right
+        } else {
+            return Some(path);
+        };
+
+        let mut steps = 0;
+        while is_scaffold(ahead(position, direction)) {
+            position = ahead(position, direction);
+            steps += 1;
+        }
+        path.push(Move { turn, steps });
+    }
+}
+
+/// A path split into a main routine calling up to three movement
+/// functions, each small enough for the robot's memory.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Routine {
+    main: Vec<usize>,
+    functions: Vec<Vec<Move>>,
+}
+
+impl Routine {
+    /// Finds functions covering the whole of `path`, trying the longest
+    /// prefix that fits first for each.
+    pub fn compress(path: &[Move]) -> Option<Self> {
+        let mut routine = Routine {
+            main: Vec::new(),
+            functions: Vec::new(),
+        };
+
+        routine.cover(path).then_some(routine)
+    }
+
+    fn cover(&mut self, path: &[Move]) -> bool {
+        if path.is_empty() {
+            return true;
+        }
+        if self.main.len() == MEMORY.div_ceil(2) {
+            return false;
+        }
+
+        for index in 0..self.functions.len() {
+            let function = &self.functions[index];
+            if path.starts_with(function) {
+                let length = function.len();
+                self.main.push(index);
+                if self.cover(&path[length..]) {
+                    return true;
+                }
+                self.main.pop();
+            }
+        }
+
+        if self.functions.len() < FUNCTIONS.len() {
+            for length in (1..=path.len()).rev() {
+                if join(&path[..length]).len() > MEMORY {
+                    continue;
+                }
+                self.functions.push(path[..length].to_vec());
+                self.main.push(self.functions.len() - 1);
+                if self.cover(&path[length..]) {
+                    return true;
+                }
+                self.main.pop();
+                self.functions.pop();
+            }
+        }
+
+        false
+    }
+
+    /// The main routine and then each of the three movement functions, as
+    /// the robot expects them, leaving any that aren't needed empty.
+    pub fn to_inputs(&self) -> [String; 4] {
+        let main = join(
+            &self
+                .main
+                .iter()
+                .map(|index| FUNCTIONS[*index])
+                .collect::<Vec<_>>(),
+        );
+        let function = |index: usize| {
+            self.functions
+                .get(index)
+                .map(|function| join(function))
+                .unwrap_or_default()
+        };
+
+        [main, function(0), function(1), function(2)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCAFFOLD: &str = r#"#######...#####
+#.....#...#...#
+#.....#...#...#
+......#...#...#
+......#...###.#
+......#.....#.#
+^########...#.#
+......#.#...#.#
+......#########
+........#...#..
+....#########..
+....#...#......
+....#...#......
+....#...#......
+....#####......"#;
+
+    fn expand(routine: &Routine) -> Vec<Move> {
+        routine
+            .main
+            .iter()
+            .flat_map(|index| routine.functions[*index].iter().copied())
+            .collect()
+    }
+
+    fn get_scaffold() -> Vec<Vec<char>> {
+        SCAFFOLD
+            .lines()
+            .map(|line| line.chars().collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_path() {
+        let path = get_path(&get_scaffold()).unwrap();
+
+        assert_eq!(
+            "R,8,R,8,R,4,R,4,R,8,L,6,L,2,R,4,R,4,R,8,R,8,R,8,L,6,L,2",
+            join(&path),
+        );
+    }
+
+    #[test]
+    fn test_compress() {
+        let path = get_path(&get_scaffold()).unwrap();
+        let routine = Routine::compress(&path).unwrap();
+
+        assert_eq!(path, expand(&routine));
+        assert!(routine
+            .to_inputs()
+            .iter()
+            .all(|input| input.len() <= MEMORY));
+    }
+}
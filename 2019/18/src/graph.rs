@@ -0,0 +1,152 @@
+use std::collections::{HashSet, VecDeque};
+
+use aoc_search::{dijkstra, State};
+
+use crate::{Step, Vault, U_R_D_L};
+
+/// The shortest way from one node to a key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Edge {
+    key: usize,
+    distance: usize,
+    /// The keys to have before setting off: those for the doors on the
+    /// way, and any lying on it, as they'd be picked up in passing and so
+    /// are better collected as a stop of their own.
+    requires: u32,
+}
+
+/// The vault boiled down to the distances between keys, and from each
+/// robot to the keys, along with the doors in the way.
+///
+/// Nodes are the keys, `a` to `z`, followed by the robots.
+#[derive(Debug)]
+pub struct KeyGraph {
+    edges: Vec<Vec<Edge>>,
+    robots: usize,
+    keys: u32,
+}
+
+/// The fewest steps to collect every key, and the order to do it in.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Collection {
+    pub steps: usize,
+    pub order: Vec<char>,
+}
+
+impl KeyGraph {
+    pub fn new(vault: &Vault) -> Self {
+        let mut edges = vec![Vec::new(); 26 + vault.positions.len()];
+        let mut keys = 0;
+        for (position, step) in vault.steps.iter() {
+            if let Step::Key(key) = step {
+                edges[*key as usize] = KeyGraph::find_edges(vault, *position);
+                keys |= 1 << key;
+            }
+        }
+        for (robot, position) in vault.positions.iter().enumerate() {
+            edges[26 + robot] = KeyGraph::find_edges(vault, *position);
+        }
+
+        KeyGraph {
+            edges,
+            robots: vault.positions.len(),
+            keys,
+        }
+    }
+
+    /// Walks out from `start` to every key it can reach.
+    fn find_edges(vault: &Vault, start: (isize, isize)) -> Vec<Edge> {
+        let mut edges = Vec::new();
+        let mut seen = HashSet::new();
+        let mut queue = VecDeque::new();
+        seen.insert(start);
+        queue.push_back((start, 0, 0));
+
+        while let Some((position, distance, requires)) = queue.pop_front() {
+            for (dx, dy) in U_R_D_L.iter() {
+                let next = (position.0 + dx, position.1 + dy);
+                let step = match vault.steps.get(&next) {
+                    Some(step) if seen.insert(next) => step,
+                    _ => continue,
+                };
+                let requires = match step {
+                    Step::Key(key) => {
+                        edges.push(Edge {
+                            key: *key as usize,
+                            distance: distance + 1,
+                            requires,
+                        });
+                        requires | 1 << key
+                    }
+                    Step::Door(door) => requires | 1 << door,
+                    Step::Open => requires,
+                };
+                queue.push_back((next, distance + 1, requires));
+            }
+        }
+
+        edges
+    }
+
+    /// Searches every way for the robots, between them, to collect the
+    /// keys; a door opens for any robot once any of them has its key.
+    pub fn collect(&self) -> Option<Collection> {
+        let start = Robots {
+            nodes: (26..26 + self.robots).collect(),
+            keys: 0,
+        };
+        let path = dijkstra(start, self).path?;
+
+        let order = path
+            .states
+            .windows(2)
+            .map(|pair| {
+                let key = (pair[1].keys ^ pair[0].keys).trailing_zeros();
+                char::from(b'a' + key as u8)
+            })
+            .collect();
+
+        Some(Collection {
+            steps: path.cost,
+            order,
+        })
+    }
+}
+
+/// Where each robot is, by node, and the keys collected so far.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct Robots {
+    nodes: Vec<usize>,
+    keys: u32,
+}
+
+impl State for Robots {
+    type Context = KeyGraph;
+
+    fn successors(&self, graph: &KeyGraph) -> Vec<(Self, usize)> {
+        self.nodes
+            .iter()
+            .enumerate()
+            .flat_map(|(robot, node)| {
+                graph.edges[*node]
+                    .iter()
+                    .filter(|edge| {
+                        self.keys & 1 << edge.key == 0 && edge.requires & !self.keys == 0
+                    })
+                    .map(move |edge| {
+                        let mut nodes = self.nodes.clone();
+                        nodes[robot] = edge.key;
+                        let robots = Robots {
+                            nodes,
+                            keys: self.keys | 1 << edge.key,
+                        };
+                        (robots, edge.distance)
+                    })
+            })
+            .collect()
+    }
+
+    fn is_goal(&self, graph: &KeyGraph) -> bool {
+        self.keys == graph.keys
+    }
+}
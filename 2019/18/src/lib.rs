@@ -0,0 +1,242 @@
+use std::collections::HashMap;
+
+use crate::graph::{Collection, KeyGraph};
+
+mod graph;
+
+#[derive(Debug, Clone)]
+enum Step {
+    Open,
+    Key(isize),
+    Door(isize),
+}
+
+#[derive(Debug)]
+struct Vault {
+    steps: HashMap<(isize, isize), Step>,
+    /// Where each robot starts.
+    positions: Vec<(isize, isize)>,
+}
+
+const U_R_D_L: [(isize, isize); 4] = [(0, 1), (1, 0), (0, -1), (-1, 0)];
+
+impl Vault {
+    fn from_string(input: &str) -> Self {
+        let mut steps: HashMap<(isize, isize), Step> = HashMap::new();
+        let mut positions = Vec::new();
+
+        for (y, line) in input.lines().enumerate() {
+            for (x, step) in line.chars().enumerate() {
+                let p = (x as isize, y as isize);
+                match step {
+                    'a'..='z' => {
+                        steps.insert(p, Step::Key(step as isize - 'a' as isize));
+                    }
+                    'A'..='Z' => {
+                        steps.insert(p, Step::Door(step as isize - 'A' as isize));
+                    }
+                    '.' => {
+                        steps.insert(p, Step::Open);
+                    }
+                    '@' => {
+                        steps.insert(p, Step::Open);
+                        positions.push(p);
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        Vault { steps, positions }
+    }
+
+    /// Walls off the space around the only robot, replacing it with four
+    /// robots on the diagonals.
+    fn split(&self) -> Vault {
+        let (ox, oy) = self.positions[0];
+        let mut steps = self.steps.clone();
+        steps.remove(&(ox, oy));
+        for (dx, dy) in &U_R_D_L {
+            steps.remove(&(ox + dx, oy + dy));
+        }
+
+        let positions = [(-1, -1), (1, 1), (1, -1), (-1, 1)]
+            .iter()
+            .map(|(dx, dy)| (ox + dx, oy + dy))
+            .collect();
+
+        Vault { steps, positions }
+    }
+}
+
+/// The quickest way to collect every key, with the vault `split` between
+/// four robots or left to just the one.
+pub fn get_collection(input: &str, split: bool) -> Result<Collection, String> {
+    let vault = Vault::from_string(input);
+    let vault = match split {
+        true => vault.split(),
+        false => vault,
+    };
+
+    KeyGraph::new(&vault)
+        .collect()
+        .ok_or_else(|| String::from("The keys can't all be collected"))
+}
+
+pub fn get_part_one(input: &str) -> Result<usize, String> {
+    get_collection(input, false).map(|collection| collection.steps)
+}
+
+pub fn get_part_two(input: &str) -> Result<usize, String> {
+    get_collection(input, true).map(|collection| collection.steps)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_step_count(vault: &Vault) -> usize {
+        KeyGraph::new(vault).collect().unwrap().steps
+    }
+
+    #[test]
+    fn test_8() {
+        let input = String::from(
+            r#"#########
+#b.A.@.a#
+#########"#,
+        );
+        let vault = Vault::from_string(&input);
+        print!("{:?}", vault);
+        assert_eq!(8, get_step_count(&vault));
+    }
+
+    #[test]
+    fn test_86() {
+        let input = String::from(
+            r#"########################
+#f.D.E.e.C.b.A.@.a.B.c.#
+######################.#
+#d.....................#
+########################"#,
+        );
+        let vault = Vault::from_string(&input);
+        print!("{:?}", vault);
+        assert_eq!(86, get_step_count(&vault));
+    }
+
+    #[test]
+    fn test_132() {
+        let input = String::from(
+            r#"########################
+#...............b.C.D.f#
+#.######################
+#.....@.a.B.c.d.A.e.F.g#
+########################"#,
+        );
+        let vault = Vault::from_string(&input);
+        print!("{:?}", vault);
+        assert_eq!(132, get_step_count(&vault));
+    }
+
+    #[test]
+    fn test_136() {
+        let input = String::from(
+            r#"#################
+#i.G..c...e..H.p#
+########.########
+#j.A..b...f..D.o#
+########@########
+#k.E..a...g..B.n#
+########.########
+#l.F..d...h..C.m#
+#################"#,
+        );
+        let vault = Vault::from_string(&input);
+        print!("{:?}", vault);
+        assert_eq!(136, get_step_count(&vault));
+    }
+
+    #[test]
+    fn test_81() {
+        let input = String::from(
+            r#"########################
+#@..............ac.GI.b#
+###d#e#f################
+###A#B#C################
+###g#h#i################
+########################
+"#,
+        );
+        let vault = Vault::from_string(&input);
+        print!("{:?}", vault);
+        assert_eq!(81, get_step_count(&vault));
+    }
+
+    #[test]
+    fn test_order() {
+        let input = String::from(
+            r#"########################
+#f.D.E.e.C.b.A.@.a.B.c.#
+######################.#
+#d.....................#
+########################"#,
+        );
+        let collection = KeyGraph::new(&Vault::from_string(&input))
+            .collect()
+            .unwrap();
+
+        assert_eq!(86, collection.steps);
+        assert_eq!("abcdef", collection.order.iter().collect::<String>());
+    }
+
+    #[test]
+    fn test_split_8() {
+        let input = String::from(
+            r#"#######
+#a.#Cd#
+##...##
+##.@.##
+##...##
+#cB#Ab#
+#######"#,
+        );
+        let vault = Vault::from_string(&input).split();
+        let collection = KeyGraph::new(&vault).collect().unwrap();
+
+        assert_eq!(8, collection.steps);
+        assert_eq!("abcd", collection.order.iter().collect::<String>());
+    }
+
+    #[test]
+    fn test_robots_24() {
+        let input = String::from(
+            r#"###############
+#d.ABC.#.....a#
+######@#@######
+###############
+######@#@######
+#b.....#.....c#
+###############"#,
+        );
+        let vault = Vault::from_string(&input);
+        assert_eq!(4, vault.positions.len());
+        assert_eq!(24, get_step_count(&vault));
+    }
+
+    #[test]
+    fn test_robots_72() {
+        let input = String::from(
+            r#"#############
+#g#f.D#..h#l#
+#F###e#E###.#
+#dCba@#@BcIJ#
+#############
+#nK.L@#@G...#
+#M###N#H###.#
+#o#m..#i#jk.#
+#############"#,
+        );
+        assert_eq!(72, get_step_count(&Vault::from_string(&input)));
+    }
+}
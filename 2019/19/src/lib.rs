@@ -0,0 +1,75 @@
+use std::str::FromStr;
+
+use intcode::{Intcode, State};
+
+struct Drone {
+    intcode: Intcode,
+}
+
+impl Drone {
+    fn new(input: &str) -> Self {
+        Drone {
+            intcode: Intcode::from_str(input).unwrap(),
+        }
+    }
+
+    fn scan(&mut self, size: usize) -> usize {
+        let mut total: usize = 0;
+        for y in 0..size {
+            for x in 0..size {
+                if let 1 = self.get_point_pulled(x, y) {
+                    total += 1
+                };
+            }
+        }
+
+        total
+    }
+
+    fn get_point_pulled(&self, x: usize, y: usize) -> i64 {
+        let mut intcode = self.intcode.clone();
+
+        intcode.push_input(x as i64);
+        intcode.push_input(y as i64);
+        match intcode.run().unwrap() {
+            State::Output(output) => output,
+            state => panic!("Drone stopped without output: {:?}", state),
+        }
+    }
+
+    fn get_nearest_corner_in_fit(&mut self, size: usize) -> (usize, usize) {
+        let size = size - 1;
+
+        for y in size.. {
+            for x in 0.. {
+                if let 1 = self.get_point_pulled(x, y) {
+                    if self.get_point_pulled(x + size, y - size) == 1 {
+                        return (x, y - size);
+                    } else {
+                        break;
+                    }
+                }
+            }
+        }
+
+        (0, 0)
+    }
+}
+
+pub fn get_part_one(input: &str) -> usize {
+    Drone::new(input.trim()).scan(50)
+}
+
+pub fn get_part_two(input: &str) -> usize {
+    let (x, y) = Drone::new(input.trim()).get_nearest_corner_in_fit(100);
+
+    (x * 10_000) + y
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_10x10() {}
+}
@@ -1,227 +1,17 @@
 use std::fs;
 
-#[derive(Clone, Debug)]
-struct Intcode {
-    opcode: Vec<isize>,
-    position: usize,
-    relative_base: isize,
-    inputs: Vec<isize>,
-}
-
-struct Drone {
-    intcode: Intcode,
-}
-
-impl Drone {
-    fn new(input: &str) -> Self {
-        Drone {
-            intcode: Intcode::new(input),
-        }
-    }
-
-    fn scan(&mut self, size: usize) -> usize {
-        let mut total: usize = 0;
-        for y in 0..size {
-            for x in 0..size {
-                if let 1 = self.get_point_pulled(x, y) {
-                    total += 1
-                };
-            }
-        }
-
-        total
-    }
-
-    fn get_point_pulled(&mut self, x: usize, y: usize) -> isize {
-        let intcode = self.intcode.clone();
-
-        self.intcode.inputs.push(y as isize);
-        self.intcode.inputs.push(x as isize);
-        let output = self.intcode.run().unwrap();
-        self.intcode = intcode;
-        output
-    }
-
-    fn get_nearest_corner_in_fit(&mut self, size: usize) -> (usize, usize) {
-        let size = size - 1;
-
-        for y in size.. {
-            for x in 0.. {
-                if let 1 = self.get_point_pulled(x, y) {
-                    if self.get_point_pulled(x + size, y - size) == 1 {
-                        return (x, y - size);
-                    } else {
-                        break;
-                    }
-                }
-            }
-        }
-
-        (0, 0)
-    }
-}
-
-impl Intcode {
-    fn new(input: &str) -> Intcode {
-        let mut opcode = parse_opcodes(input);
-        let mut extension = vec![0; 10000];
-        opcode.append(&mut extension);
-        Intcode {
-            opcode,
-            position: 0,
-            relative_base: 0,
-            inputs: Vec::new(),
-        }
-    }
-
-    fn get_param1(&mut self) -> isize {
-        match (self.opcode[self.position] / 100) % 10 {
-            0 => self.opcode[self.opcode[self.position + 1] as usize],
-            1 => self.opcode[self.position + 1],
-            2 => self.opcode[(self.relative_base + self.opcode[self.position + 1]) as usize],
-            _ => panic!("Invalid mode at position {}", self.opcode[self.position]),
-        }
-    }
-
-    fn get_param2(&mut self) -> isize {
-        match (self.opcode[self.position] / 1000) % 10 {
-            0 => self.opcode[self.opcode[self.position + 2] as usize],
-            1 => self.opcode[self.position + 2],
-            2 => self.opcode[(self.relative_base + self.opcode[self.position + 2]) as usize],
-            _ => panic!("Invalid mode at position {}", self.opcode[self.position]),
-        }
-    }
-
-    fn get_param3(&mut self) -> usize {
-        match (self.opcode[self.position] / 10000) % 10 {
-            0 => self.opcode[self.position + 3] as usize,
-            2 => (self.relative_base + self.opcode[self.position + 3]) as usize,
-            _ => panic!("Invalid mode at position {}", self.opcode[self.position]),
-        }
-    }
-
-    fn run(&mut self) -> Option<isize> {
-        loop {
-            let opcode = self.opcode[self.position] % 100;
-            match opcode {
-                1 => {
-                    let p1 = self.get_param1();
-                    let p2 = self.get_param2();
-                    let output = p1 + p2;
-                    let output_position = self.get_param3();
-                    self.opcode[output_position] = output;
-                    self.position += 4;
-                }
-                2 => {
-                    let p1 = self.get_param1();
-                    let p2 = self.get_param2();
-                    let output = p1 * p2;
-                    let output_position = self.get_param3();
-                    self.opcode[output_position] = output;
-                    self.position += 4;
-                }
-                3 => {
-                    let output_position = match (self.opcode[self.position] / 100) % 10 {
-                        0 => self.opcode[self.position + 1] as usize,
-                        2 => (self.relative_base + self.opcode[self.position + 1]) as usize,
-                        _ => panic!("Invalid mode at position {}", self.opcode[self.position]),
-                    };
-                    let i = match self.inputs.pop() {
-                        Some(i) => i as usize,
-                        None => panic!("Attempt to read empty inputs!"),
-                    };
-                    self.opcode[output_position as usize] = i as isize;
-                    self.position += 2;
-                }
-                4 => {
-                    let p1 = self.get_param1();
-                    self.position += 2;
-                    return Some(p1);
-                }
-                5 => {
-                    let p1 = self.get_param1();
-                    let p2 = self.get_param2();
-                    if p1 != 0 {
-                        self.position = p2 as usize;
-                    } else {
-                        self.position += 3;
-                    }
-                }
-                6 => {
-                    let p1 = self.get_param1();
-                    let p2 = self.get_param2();
-                    if p1 == 0 {
-                        self.position = p2 as usize;
-                    } else {
-                        self.position += 3;
-                    }
-                }
-                7 => {
-                    let p1 = self.get_param1();
-                    let p2 = self.get_param2();
-                    let output_position = self.get_param3();
-                    if p1 < p2 {
-                        self.opcode[output_position as usize] = 1;
-                    } else {
-                        self.opcode[output_position as usize] = 0;
-                    }
-                    self.position += 4;
-                }
-                8 => {
-                    let p1 = self.get_param1();
-                    let p2 = self.get_param2();
-                    let output_position = self.get_param3();
-                    if p1 == p2 {
-                        self.opcode[output_position] = 1;
-                    } else {
-                        self.opcode[output_position] = 0;
-                    }
-                    self.position += 4;
-                }
-                9 => {
-                    let p1 = self.get_param1();
-                    self.relative_base += p1;
-                    self.position += 2;
-                }
-                99 => break,
-                _ => panic!(
-                    "Invalid self.opcode at position {} for {:?}",
-                    self.position, self.opcode
-                ),
-            };
-        }
-        None
-    }
-}
-
-fn parse_opcodes(initial_state: &str) -> Vec<isize> {
-    initial_state
-        .trim()
-        .split(',')
-        .map(|s| s.parse::<isize>().unwrap())
-        .collect::<Vec<isize>>()
-}
+use ::aoc_2019_19::*;
 
 fn main() {
     let input = fs::read_to_string("input.txt").expect("Error reading input.txt");
-    let mut drone = Drone::new(&input.trim());
 
     println!(
         "How many points are affected by the tractor beam in the 50x50 area closest to the emitter? {}",
-        drone.scan(50),
+        get_part_one(&input),
     );
 
-    let (x, y) = drone.get_nearest_corner_in_fit(100);
     println!(
         "What value do you get if you take that point's X coordinate, multiply it by 10000, then add the point's Y coordinate? {}",
-        (x * 10_000) + y,
-    )
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_10x10() {}
+        get_part_two(&input),
+    );
 }
@@ -0,0 +1,254 @@
+use std::collections::{HashSet, VecDeque};
+use std::fmt;
+
+use aoc_search::{dijkstra, State};
+
+use crate::{Donut, Point, U_R_D_L};
+
+/// Which side of the donut a portal's tile is on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Edge {
+    Inner,
+    Outer,
+}
+
+impl fmt::Display for Edge {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Edge::Inner => write!(f, "inner"),
+            Edge::Outer => write!(f, "outer"),
+        }
+    }
+}
+
+/// The open tile next to a label; `AA` and `ZZ` have one each, every
+/// other label two.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Endpoint {
+    pub name: String,
+    pub position: Point,
+    pub edge: Edge,
+}
+
+/// What going through a portal does.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Recursion {
+    /// Portals simply link their two tiles.
+    Flat,
+    /// Inner portals lead a level down, outer ones a level up, and no
+    /// further down than the given depth; outer portals are walls at the
+    /// outermost level.
+    Levels(usize),
+}
+
+/// One leg of a route through the maze.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Hop {
+    Walk {
+        to: String,
+        edge: Edge,
+        steps: usize,
+    },
+    Warp {
+        portal: String,
+        from: usize,
+        to: usize,
+    },
+}
+
+impl fmt::Display for Hop {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Hop::Walk { to, edge, steps } => {
+                write!(f, "Walk {} steps to {} ({})", steps, to, edge)
+            }
+            Hop::Warp { portal, from, to } if to > from => {
+                write!(f, "Recurse into level {} through {}", to, portal)
+            }
+            Hop::Warp { portal, from, to } if to < from => {
+                write!(f, "Return to level {} through {}", to, portal)
+            }
+            Hop::Warp { portal, .. } => write!(f, "Teleport through {}", portal),
+        }
+    }
+}
+
+/// The fewest steps from `AA` to `ZZ`, and how they're taken.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Route {
+    pub steps: usize,
+    pub hops: Vec<Hop>,
+}
+
+impl fmt::Display for Route {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for hop in &self.hops {
+            writeln!(f, "{}", hop)?;
+        }
+
+        write!(f, "{} steps in total", self.steps)
+    }
+}
+
+/// The maze boiled down to the walking distances between portal
+/// endpoints, plus which endpoints each portal joins.
+#[derive(Debug)]
+pub struct PortalGraph {
+    endpoints: Vec<Endpoint>,
+    /// The endpoints reachable on foot from each, and how far.
+    walks: Vec<Vec<(usize, usize)>>,
+    /// The endpoint at the other end of each one's portal.
+    partners: Vec<Option<usize>>,
+    start: usize,
+    end: usize,
+    recursion: Recursion,
+}
+
+impl PortalGraph {
+    /// `None` if the maze lacks either `AA` or `ZZ`.
+    pub fn new(donut: &Donut, recursion: Recursion) -> Option<Self> {
+        let endpoints = donut.endpoints.clone();
+        let find = |name: &str| endpoints.iter().position(|endpoint| endpoint.name == name);
+        let start = find("AA")?;
+        let end = find("ZZ")?;
+
+        let partners = endpoints
+            .iter()
+            .enumerate()
+            .map(|(i, endpoint)| {
+                endpoints
+                    .iter()
+                    .enumerate()
+                    .position(|(j, other)| i != j && endpoint.name == other.name)
+            })
+            .collect();
+        let walks = endpoints
+            .iter()
+            .map(|endpoint| PortalGraph::find_walks(donut, endpoint.position))
+            .collect();
+
+        Some(PortalGraph {
+            endpoints,
+            walks,
+            partners,
+            start,
+            end,
+            recursion,
+        })
+    }
+
+    /// Walks out from `start` to every other endpoint it can reach.
+    fn find_walks(donut: &Donut, start: Point) -> Vec<(usize, usize)> {
+        let mut walks = Vec::new();
+        let mut seen = HashSet::new();
+        let mut queue = VecDeque::new();
+        seen.insert(start);
+        queue.push_back((start, 0));
+
+        while let Some((position, distance)) = queue.pop_front() {
+            for (dx, dy) in U_R_D_L.iter() {
+                let next = (position.0 + dx, position.1 + dy);
+                if !donut.open.contains(&next) || !seen.insert(next) {
+                    continue;
+                }
+                if let Some(endpoint) = donut
+                    .endpoints
+                    .iter()
+                    .position(|endpoint| endpoint.position == next)
+                {
+                    walks.push((endpoint, distance + 1));
+                }
+                queue.push_back((next, distance + 1));
+            }
+        }
+
+        walks
+    }
+
+    pub fn route(&self) -> Option<Route> {
+        let start = Position {
+            endpoint: self.start,
+            depth: 0,
+        };
+        let path = dijkstra(start, self).path?;
+
+        let hops = path
+            .states
+            .windows(2)
+            .map(|pair| {
+                let (from, to) = (&pair[0], &pair[1]);
+                if self.partners[from.endpoint] == Some(to.endpoint) {
+                    Hop::Warp {
+                        portal: self.endpoints[to.endpoint].name.clone(),
+                        from: from.depth,
+                        to: to.depth,
+                    }
+                } else {
+                    let steps = self.walks[from.endpoint]
+                        .iter()
+                        .find(|(endpoint, _)| *endpoint == to.endpoint)
+                        .map_or(0, |(_, steps)| *steps);
+                    Hop::Walk {
+                        to: self.endpoints[to.endpoint].name.clone(),
+                        edge: self.endpoints[to.endpoint].edge,
+                        steps,
+                    }
+                }
+            })
+            .collect();
+
+        Some(Route {
+            steps: path.cost,
+            hops,
+        })
+    }
+}
+
+/// An endpoint at some depth within the maze, the outermost being 0.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct Position {
+    endpoint: usize,
+    depth: usize,
+}
+
+impl State for Position {
+    type Context = PortalGraph;
+
+    fn successors(&self, graph: &PortalGraph) -> Vec<(Self, usize)> {
+        let mut successors = graph.walks[self.endpoint]
+            .iter()
+            .map(|&(endpoint, steps)| {
+                let position = Position {
+                    endpoint,
+                    depth: self.depth,
+                };
+                (position, steps)
+            })
+            .collect::<Vec<_>>();
+
+        if let Some(partner) = graph.partners[self.endpoint] {
+            let depth = match (graph.recursion, graph.endpoints[self.endpoint].edge) {
+                (Recursion::Flat, _) => Some(self.depth),
+                (Recursion::Levels(max), Edge::Inner) => {
+                    Some(self.depth + 1).filter(|&depth| depth <= max)
+                }
+                (Recursion::Levels(_), Edge::Outer) => self.depth.checked_sub(1),
+            };
+            if let Some(depth) = depth {
+                successors.push((
+                    Position {
+                        endpoint: partner,
+                        depth,
+                    },
+                    1,
+                ));
+            }
+        }
+
+        successors
+    }
+
+    fn is_goal(&self, graph: &PortalGraph) -> bool {
+        self.endpoint == graph.end && self.depth == 0
+    }
+}
@@ -0,0 +1,305 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::graph::{Edge, Endpoint, PortalGraph, Recursion, Route};
+
+mod graph;
+
+type Point = (isize, isize);
+
+const U_R_D_L: [Point; 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// How far down the recursive maze to search; without a limit, a maze
+/// with no way out would be searched forever.
+pub const MAX_DEPTH: usize = 100;
+
+/// The open tiles of the maze, along with those next to a label.
+#[derive(Debug)]
+struct Donut {
+    open: HashSet<Point>,
+    endpoints: Vec<Endpoint>,
+}
+
+impl Donut {
+    fn from_string(input: &str) -> Self {
+        let map = input
+            .lines()
+            .enumerate()
+            .flat_map(|(y, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(move |(x, c)| ((x as isize, y as isize), c))
+            })
+            .collect::<HashMap<Point, char>>();
+        let open = map
+            .iter()
+            .filter(|&(_, &c)| c == '.')
+            .map(|(&point, _)| point)
+            .collect::<HashSet<Point>>();
+
+        let walls = map.iter().filter(|&(_, &c)| c == '#' || c == '.');
+        let (min_x, max_x, min_y, max_y) = walls.fold(
+            (isize::MAX, isize::MIN, isize::MAX, isize::MIN),
+            |(min_x, max_x, min_y, max_y), (&(x, y), _)| {
+                (min_x.min(x), max_x.max(x), min_y.min(y), max_y.max(y))
+            },
+        );
+
+        let mut endpoints = Vec::new();
+        for &(x, y) in open.iter() {
+            for (dx, dy) in U_R_D_L.iter() {
+                let near = map
+                    .get(&(x + dx, y + dy))
+                    .filter(|c| c.is_ascii_uppercase());
+                let far = map
+                    .get(&(x + 2 * dx, y + 2 * dy))
+                    .filter(|c| c.is_ascii_uppercase());
+                if let (Some(&near), Some(&far)) = (near, far) {
+                    // Labels read left-to-right or top-to-bottom.
+                    let name = if dx + dy < 0 {
+                        [far, near].iter().collect()
+                    } else {
+                        [near, far].iter().collect()
+                    };
+                    let edge = if x == min_x || x == max_x || y == min_y || y == max_y {
+                        Edge::Outer
+                    } else {
+                        Edge::Inner
+                    };
+                    endpoints.push(Endpoint {
+                        name,
+                        position: (x, y),
+                        edge,
+                    });
+                }
+            }
+        }
+        endpoints.sort_by_key(|endpoint| (endpoint.position.1, endpoint.position.0));
+
+        Donut { open, endpoints }
+    }
+}
+
+fn get_steps(donut: &Donut, recursion: Recursion) -> Option<usize> {
+    PortalGraph::new(donut, recursion)?
+        .route()
+        .map(|route| route.steps)
+}
+
+/// The route through the recursive maze, searching no more than
+/// `max_depth` levels down.
+pub fn get_recursive_route(input: &str, max_depth: usize) -> Result<Route, String> {
+    PortalGraph::new(&Donut::from_string(input), Recursion::Levels(max_depth))
+        .and_then(|graph| graph.route())
+        .ok_or_else(|| format!("No route within {} levels", max_depth))
+}
+
+pub fn get_part_one(input: &str) -> Result<usize, String> {
+    get_steps(&Donut::from_string(input), Recursion::Flat)
+        .ok_or_else(|| String::from("No route from AA to ZZ"))
+}
+
+pub fn get_part_two(input: &str) -> Result<usize, String> {
+    get_recursive_route(input, MAX_DEPTH).map(|route| route.steps)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::Hop;
+
+    #[test]
+    fn test_23() {
+        let input = String::from(
+            r#"         A
+         A
+  #######.#########
+  #######.........#
+  #######.#######.#
+  #######.#######.#
+  #######.#######.#
+  #####  B    ###.#
+BC...##  C    ###.#
+  ##.##       ###.#
+  ##...DE  F  ###.#
+  #####    G  ###.#
+  #########.#####.#
+DE..#######...###.#
+  #.#########.###.#
+FG..#########.....#
+  ###########.#####
+             Z
+             Z       "#,
+        );
+        let donut = Donut::from_string(&input);
+        assert_eq!(Some(23), get_steps(&donut, Recursion::Flat));
+    }
+
+    #[test]
+    fn test_58() {
+        let input = String::from(
+            r#"                   A
+                   A
+  #################.#############
+  #.#...#...................#.#.#
+  #.#.#.###.###.###.#########.#.#
+  #.#.#.......#...#.....#.#.#...#
+  #.#########.###.#####.#.#.###.#
+  #.............#.#.....#.......#
+  ###.###########.###.#####.#.#.#
+  #.....#        A   C    #.#.#.#
+  #######        S   P    #####.#
+  #.#...#                 #......VT
+  #.#.#.#                 #.#####
+  #...#.#               YN....#.#
+  #.###.#                 #####.#
+DI....#.#                 #.....#
+  #####.#                 #.###.#
+ZZ......#               QG....#..AS
+  ###.###                 #######
+JO..#.#.#                 #.....#
+  #.#.#.#                 ###.#.#
+  #...#..DI             BU....#..LF
+  #####.#                 #.#####
+YN......#               VT..#....QG
+  #.###.#                 #.###.#
+  #.#...#                 #.....#
+  ###.###    J L     J    #.#.###
+  #.....#    O F     P    #.#...#
+  #.###.#####.#.#####.#####.###.#
+  #...#.#.#...#.....#.....#.#...#
+  #.#####.###.###.#.#.#########.#
+  #...#.#.....#...#.#.#.#.....#.#
+  #.###.#####.###.###.#.#.#######
+  #.#.........#...#.............#
+  #########.###.###.#############
+           B   J   C
+           U   P   P
+"#,
+        );
+        let donut = Donut::from_string(&input);
+        assert_eq!(Some(58), get_steps(&donut, Recursion::Flat));
+        assert_eq!(None, get_steps(&donut, Recursion::Levels(MAX_DEPTH)));
+    }
+
+    #[test]
+    fn test_route() {
+        let input = String::from(
+            r#"         A
+         A
+  #######.#########
+  #######.........#
+  #######.#######.#
+  #######.#######.#
+  #######.#######.#
+  #####  B    ###.#
+BC...##  C    ###.#
+  ##.##       ###.#
+  ##...DE  F  ###.#
+  #####    G  ###.#
+  #########.#####.#
+DE..#######...###.#
+  #.#########.###.#
+FG..#########.....#
+  ###########.#####
+             Z
+             Z       "#,
+        );
+        let donut = Donut::from_string(&input);
+        let route = |recursion| PortalGraph::new(&donut, recursion)?.route();
+
+        assert_eq!(
+            Some(String::from(
+                r#"Walk 4 steps to BC (inner)
+Teleport through BC
+Walk 6 steps to DE (inner)
+Teleport through DE
+Walk 4 steps to FG (outer)
+Teleport through FG
+Walk 6 steps to ZZ (outer)
+23 steps in total"#
+            )),
+            route(Recursion::Flat).map(|route| route.to_string()),
+        );
+        assert_eq!(
+            Some(vec![Hop::Walk {
+                to: String::from("ZZ"),
+                edge: Edge::Outer,
+                steps: 26,
+            }]),
+            route(Recursion::Levels(MAX_DEPTH)).map(|route| route.hops),
+        );
+    }
+
+    #[test]
+    fn test_26_recursive() {
+        let input = String::from(
+            r#"         A
+         A
+  #######.#########
+  #######.........#
+  #######.#######.#
+  #######.#######.#
+  #######.#######.#
+  #####  B    ###.#
+BC...##  C    ###.#
+  ##.##       ###.#
+  ##...DE  F  ###.#
+  #####    G  ###.#
+  #########.#####.#
+DE..#######...###.#
+  #.#########.###.#
+FG..#########.....#
+  ###########.#####
+             Z
+             Z       "#,
+        );
+        let donut = Donut::from_string(&input);
+        assert_eq!(Some(26), get_steps(&donut, Recursion::Levels(MAX_DEPTH)));
+    }
+
+    #[test]
+    fn test_396_recursive() {
+        let input = String::from(
+            r#"             Z L X W       C
+             Z P Q B       K
+  ###########.#.#.#.#######.###############
+  #...#.......#.#.......#.#.......#.#.#...#
+  ###.#.#.#.#.#.#.#.###.#.#.#######.#.#.###
+  #.#...#.#.#...#.#.#...#...#...#.#.......#
+  #.###.#######.###.###.#.###.###.#.#######
+  #...#.......#.#...#...#.............#...#
+  #.#########.#######.#.#######.#######.###
+  #...#.#    F       R I       Z    #.#.#.#
+  #.###.#    D       E C       H    #.#.#.#
+  #.#...#                           #...#.#
+  #.###.#                           #.###.#
+  #.#....OA                       WB..#.#..ZH
+  #.###.#                           #.#.#.#
+CJ......#                           #.....#
+  #######                           #######
+  #.#....CK                         #......IC
+  #.###.#                           #.###.#
+  #.....#                           #...#.#
+  ###.###                           #.#.#.#
+XF....#.#                         RF..#.#.#
+  #####.#                           #######
+  #......CJ                       NM..#...#
+  ###.#.#                           #.###.#
+RE....#.#                           #......RF
+  ###.###        X   X       L      #.#.#.#
+  #.....#        F   Q       P      #.#.#.#
+  ###.###########.###.#######.#########.###
+  #.....#...#.....#.......#...#.....#.#...#
+  #####.#.###.#######.#######.###.###.#.#.#
+  #.......#.......#.#.#.#.#...#...#...#.#.#
+  #####.###.#####.#.#.#.#.###.###.#.###.###
+  #.......#.....#.#...#...............#...#
+  #############.#.#.###.###################
+               A O F   N
+               A A D   M                     "#,
+        );
+        let donut = Donut::from_string(&input);
+        assert_eq!(Some(396), get_steps(&donut, Recursion::Levels(MAX_DEPTH)));
+        assert_eq!(None, get_steps(&donut, Recursion::Levels(5)));
+    }
+}
@@ -0,0 +1,149 @@
+use std::str::FromStr;
+
+use aoc_error::{Error, Result};
+use intcode::{Intcode, State};
+
+use crate::springscript::{Expr, Hull, Mode, Register, Springscript};
+
+mod springscript;
+
+/// Hulls the springdroid has fallen into space on before; each script is
+/// checked against these before being sent.
+const WALK_HULLS: [&str; 4] = [
+    "#####.###########",
+    "#####...#########",
+    "#####..#.########",
+    "#####.#..########",
+];
+
+/// Hulls only met when running, as walking never gets this far.
+const RUN_HULLS: [&str; 4] = [
+    "#####.#.##..#.###",
+    "#####.##.##.#.###",
+    "#####..###.#..###",
+    "#####...##.##.###",
+];
+
+struct Springdroid {
+    intcode: Intcode,
+}
+
+impl Springdroid {
+    fn new(input: &str) -> Self {
+        Springdroid {
+            intcode: Intcode::from_str(input).unwrap(),
+        }
+    }
+
+    /// Returns the hull damage reported or, if the springdroid fell, its
+    /// report of where.
+    fn run_springscript(&mut self, springscript: &Springscript) -> Result<i64, String> {
+        self.intcode.push_ascii(&springscript.to_string());
+
+        let mut report = String::new();
+        while let State::Output(output) = self.intcode.run().unwrap() {
+            if output > 0 && output <= 127 {
+                report.push(char::from(output as u8));
+            } else {
+                return Ok(output);
+            }
+        }
+        Err(report)
+    }
+}
+
+fn get_hulls(mode: Mode) -> Vec<Hull> {
+    let run: &[&str] = match mode {
+        Mode::Walk => &[],
+        Mode::Run => &RUN_HULLS,
+    };
+
+    WALK_HULLS
+        .iter()
+        .chain(run)
+        .map(|hull| Hull::from_str(hull).unwrap())
+        .collect()
+}
+
+/// Checks `springscript` against the hulls known to its mode and, if it
+/// survives them all, sends it to the springdroid.
+fn survey(input: &str, springscript: &Springscript) -> Result<i64> {
+    for hull in get_hulls(springscript.mode()) {
+        if let Some(tile) = hull.simulate(springscript) {
+            return Err(Error::no_solution(format!(
+                "The springdroid fell into space at tile {} of known hull {}",
+                tile, hull,
+            )));
+        }
+    }
+
+    let mut droid = Springdroid::new(input);
+    droid.run_springscript(springscript).map_err(|report| {
+        let message = match Hull::from_report(&report) {
+            Some(hull) => format!(
+                "The springdroid fell into space on a new hull {}:\n{}",
+                hull, report,
+            ),
+            None => format!("The springdroid fell into space:\n{}", report),
+        };
+
+        Error::no_solution(message)
+    })
+}
+
+/// Jumps whenever there's a hole in the next three tiles, so long as
+/// there's ground to land on.
+fn get_walk_springscript() -> Result<Springscript> {
+    let sensor = Expr::sensor;
+
+    (!(sensor(Register::A) & sensor(Register::C)) & sensor(Register::D)).compile(Mode::Walk)
+}
+
+/// As walking, but only if it can run or jump again on landing.
+fn get_run_springscript() -> Result<Springscript> {
+    let sensor = Expr::sensor;
+
+    ((!(sensor(Register::B) & sensor(Register::C)) & sensor(Register::D) & sensor(Register::H))
+        | !sensor(Register::A))
+    .compile(Mode::Run)
+}
+
+pub fn get_part_one(input: &str) -> Result<i64> {
+    survey(input.trim(), &get_walk_springscript()?)
+}
+
+pub fn get_part_two(input: &str) -> Result<i64> {
+    survey(input.trim(), &get_run_springscript()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hulls() {
+        let walk = Springscript::from_str("OR A T\nAND C T\nNOT T J\nAND D J\nWALK").unwrap();
+        let run = Springscript::from_str(
+            "OR B J\nAND C J\nNOT J J\nAND D J\nAND H J\nNOT A T\nOR T J\nRUN",
+        )
+        .unwrap();
+
+        for (springscript, mode) in [(walk, Mode::Walk), (run, Mode::Run)].iter() {
+            for hull in get_hulls(*mode) {
+                assert_eq!(None, hull.simulate(springscript));
+            }
+        }
+    }
+
+    #[test]
+    fn test_survey_known_hull() {
+        let springscript = Springscript::from_str("NOT A J\nWALK").unwrap();
+
+        assert_eq!(
+            Err(Error::no_solution(
+                "The springdroid fell into space at tile 8 of known hull #####..#.########"
+            )),
+            survey("", &springscript),
+        );
+    }
+}
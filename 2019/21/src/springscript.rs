@@ -0,0 +1,615 @@
+use std::fmt;
+use std::ops::{BitAnd, BitOr, Not};
+use std::str::FromStr;
+
+use aoc_error::{parse_lines, Context, Error, Result};
+
+/// The most instructions the springdroid's memory holds.
+pub const MEMORY: usize = 15;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Register {
+    A,
+    B,
+    C,
+    D,
+    E,
+    F,
+    G,
+    H,
+    I,
+    T,
+    J,
+}
+
+impl Register {
+    const ALL: [Register; 11] = [
+        Register::A,
+        Register::B,
+        Register::C,
+        Register::D,
+        Register::E,
+        Register::F,
+        Register::G,
+        Register::H,
+        Register::I,
+        Register::T,
+        Register::J,
+    ];
+
+    fn name(&self) -> char {
+        "ABCDEFGHITJ".as_bytes()[*self as usize] as char
+    }
+
+    fn is_writable(&self) -> bool {
+        matches!(self, Register::T | Register::J)
+    }
+
+    /// How many tiles ahead a sensor reads, counting from zero.
+    fn sensor(&self) -> Option<usize> {
+        match self.is_writable() {
+            true => None,
+            false => Some(*self as usize),
+        }
+    }
+}
+
+impl FromStr for Register {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Register::ALL
+            .iter()
+            .find(|register| s.len() == 1 && s.starts_with(register.name()))
+            .copied()
+            .with_context(|| format!("unknown register {:?}", s))
+    }
+}
+
+impl fmt::Display for Register {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Whether the springdroid walks, seeing four tiles ahead, or runs,
+/// seeing nine.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mode {
+    Walk,
+    Run,
+}
+
+impl Mode {
+    fn range(&self) -> usize {
+        match self {
+            Mode::Walk => 4,
+            Mode::Run => 9,
+        }
+    }
+}
+
+impl FromStr for Mode {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "WALK" => Ok(Mode::Walk),
+            "RUN" => Ok(Mode::Run),
+            _ => Err(Error::invalid_input(format!(
+                "expected WALK or RUN, found {:?}",
+                s
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for Mode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Mode::Walk => write!(f, "WALK"),
+            Mode::Run => write!(f, "RUN"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Opcode {
+    And,
+    Or,
+    Not,
+}
+
+impl fmt::Display for Opcode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Opcode::And => write!(f, "AND"),
+            Opcode::Or => write!(f, "OR"),
+            Opcode::Not => write!(f, "NOT"),
+        }
+    }
+}
+
+/// Reads `x` and `y`, writing the result to `y`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Instruction {
+    opcode: Opcode,
+    x: Register,
+    y: Register,
+}
+
+impl Instruction {
+    pub fn new(opcode: Opcode, x: Register, y: Register) -> Result<Self> {
+        if !y.is_writable() {
+            return Err(Error::invalid_input(format!(
+                "{} can't be written to; only T and J can",
+                y
+            )));
+        }
+
+        Ok(Instruction { opcode, x, y })
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let parts = s.split_whitespace().collect::<Vec<_>>();
+        let (opcode, x, y) = match parts.as_slice() {
+            [opcode, x, y] => (opcode, x.parse()?, y.parse()?),
+            _ => return Err(Error::invalid_input(format!("invalid instruction {:?}", s))),
+        };
+        let opcode = match *opcode {
+            "AND" => Opcode::And,
+            "OR" => Opcode::Or,
+            "NOT" => Opcode::Not,
+            _ => return Err(Error::invalid_input(format!("unknown opcode {:?}", opcode))),
+        };
+
+        Instruction::new(opcode, x, y)
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {}", self.opcode, self.x, self.y)
+    }
+}
+
+/// A program the springdroid will accept: short enough to fit in its
+/// memory and only reading the sensors it has in its `mode`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Springscript {
+    instructions: Vec<Instruction>,
+    mode: Mode,
+}
+
+impl Springscript {
+    pub fn new(instructions: Vec<Instruction>, mode: Mode) -> Result<Self> {
+        if instructions.len() > MEMORY {
+            return Err(Error::invalid_input(format!(
+                "{} instructions won't fit in memory; at most {} will",
+                instructions.len(),
+                MEMORY
+            )));
+        }
+        for (i, instruction) in instructions.iter().enumerate() {
+            check_range(instruction, mode).map_err(|error| error.on_line(i + 1))?;
+        }
+
+        Ok(Springscript { instructions, mode })
+    }
+
+    pub fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    /// Whether the springdroid jumps, given whether there's ground on each
+    /// tile ahead of it; tiles past the end of `ground` are taken to be
+    /// solid.
+    pub fn evaluate(&self, ground: &[bool]) -> bool {
+        let mut registers = [false; 2];
+        let index = |register: Register| register as usize - Register::T as usize;
+
+        for instruction in &self.instructions {
+            let x = match instruction.x.sensor() {
+                Some(sensor) => ground.get(sensor).copied().unwrap_or(true),
+                None => registers[index(instruction.x)],
+            };
+            let y = &mut registers[index(instruction.y)];
+            *y = match instruction.opcode {
+                Opcode::And => x && *y,
+                Opcode::Or => x || *y,
+                Opcode::Not => !x,
+            };
+        }
+
+        registers[index(Register::J)]
+    }
+}
+
+fn check_range(instruction: &Instruction, mode: Mode) -> Result<()> {
+    match instruction.x.sensor() {
+        Some(sensor) if sensor >= mode.range() => Err(Error::invalid_input(format!(
+            "sensor {} isn't available in {} mode",
+            instruction.x, mode
+        ))),
+        _ => Ok(()),
+    }
+}
+
+impl FromStr for Springscript {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let s = s.trim();
+        let (instructions, mode) = match s.rfind('\n') {
+            Some(newline) => (&s[..newline], &s[newline + 1..]),
+            None => ("", s),
+        };
+        let mode = Mode::from_str(mode.trim())
+            .map_err(|error| error.on_line(instructions.lines().count() + 1))?;
+        let instructions = parse_lines(instructions, |line| {
+            let instruction = Instruction::from_str(line)?;
+            check_range(&instruction, mode).map(|_| instruction)
+        })?;
+
+        Springscript::new(instructions, mode)
+    }
+}
+
+/// The script, newline-terminated, as the springdroid expects it.
+impl fmt::Display for Springscript {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for instruction in &self.instructions {
+            writeln!(f, "{}", instruction)?;
+        }
+
+        writeln!(f, "{}", self.mode)
+    }
+}
+
+/// A condition on the sensors for the springdroid to jump, built with
+/// `!`, `&` and `|`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Expr {
+    Sensor(Register),
+    Not(Box<Expr>),
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+}
+
+impl Expr {
+    pub fn sensor(register: Register) -> Self {
+        Expr::Sensor(register)
+    }
+
+    /// Whether compiling this needs a register besides the one it's
+    /// written to.
+    fn needs_scratch(&self) -> bool {
+        match self {
+            Expr::Sensor(_) => false,
+            Expr::Not(expr) => expr.needs_scratch(),
+            Expr::And(left, right) | Expr::Or(left, right) => match (&**left, &**right) {
+                (leaf, other) | (other, leaf) if leaf.is_leaf() => other.needs_scratch(),
+                _ => true,
+            },
+        }
+    }
+
+    /// A sensor, or a sensor negated.
+    fn is_leaf(&self) -> bool {
+        match self {
+            Expr::Sensor(_) => true,
+            Expr::Not(expr) => matches!(**expr, Expr::Sensor(_)),
+            _ => false,
+        }
+    }
+
+    /// Writes springscript leaving this condition in `J`, for the
+    /// springdroid to jump on.
+    pub fn compile(&self, mode: Mode) -> Result<Springscript> {
+        let mut compiler = Compiler {
+            instructions: Vec::new(),
+            clear: vec![Register::T, Register::J],
+        };
+        compiler.compile(self, Register::J, Some(Register::T))?;
+
+        Springscript::new(compiler.instructions, mode)
+    }
+}
+
+impl Not for Expr {
+    type Output = Expr;
+
+    fn not(self) -> Expr {
+        match self {
+            Expr::Not(expr) => *expr,
+            expr => Expr::Not(Box::new(expr)),
+        }
+    }
+}
+
+impl BitAnd for Expr {
+    type Output = Expr;
+
+    fn bitand(self, other: Expr) -> Expr {
+        Expr::And(Box::new(self), Box::new(other))
+    }
+}
+
+impl BitOr for Expr {
+    type Output = Expr;
+
+    fn bitor(self, other: Expr) -> Expr {
+        Expr::Or(Box::new(self), Box::new(other))
+    }
+}
+
+struct Compiler {
+    instructions: Vec<Instruction>,
+    /// The registers still known to be false.
+    clear: Vec<Register>,
+}
+
+impl Compiler {
+    fn emit(&mut self, opcode: Opcode, x: Register, y: Register) {
+        self.clear.retain(|register| *register != y);
+        self.instructions.push(Instruction { opcode, x, y });
+    }
+
+    fn compile(&mut self, expr: &Expr, target: Register, scratch: Option<Register>) -> Result<()> {
+        match expr {
+            Expr::Sensor(register) if register.is_writable() => Err(Error::invalid_input(format!(
+                "{} is a register, not a sensor",
+                register
+            ))),
+            Expr::Sensor(register) => {
+                if self.clear.contains(&target) {
+                    self.emit(Opcode::Or, *register, target);
+                } else {
+                    self.emit(Opcode::Not, *register, target);
+                    self.emit(Opcode::Not, target, target);
+                }
+                Ok(())
+            }
+            Expr::Not(inner) => match &**inner {
+                Expr::Sensor(register) if !register.is_writable() => {
+                    self.emit(Opcode::Not, *register, target);
+                    Ok(())
+                }
+                inner => {
+                    self.compile(inner, target, scratch)?;
+                    self.emit(Opcode::Not, target, target);
+                    Ok(())
+                }
+            },
+            Expr::And(left, right) => self.binary(Opcode::And, left, right, target, scratch),
+            Expr::Or(left, right) => self.binary(Opcode::Or, left, right, target, scratch),
+        }
+    }
+
+    fn binary(
+        &mut self,
+        opcode: Opcode,
+        left: &Expr,
+        right: &Expr,
+        target: Register,
+        scratch: Option<Register>,
+    ) -> Result<()> {
+        let (leaf, other) = match (left, right) {
+            (leaf, other) | (other, leaf) if leaf.is_leaf() => (leaf, other),
+            _ => {
+                let (first, second) = match (left.needs_scratch(), right.needs_scratch()) {
+                    (_, false) => (left, right),
+                    (false, true) => (right, left),
+                    (true, true) => {
+                        return Err(Error::invalid_input(
+                            "expression needs more than the two registers",
+                        ))
+                    }
+                };
+                let scratch = scratch.context("expression needs a third register")?;
+                self.compile(first, scratch, Some(target))?;
+                self.compile(second, target, None)?;
+                self.emit(opcode, scratch, target);
+                return Ok(());
+            }
+        };
+
+        self.compile(other, target, scratch)?;
+        match (leaf, scratch) {
+            (Expr::Sensor(register), _) => {
+                self.compile_sensor(register)?;
+                self.emit(opcode, *register, target);
+            }
+            (Expr::Not(sensor), Some(scratch)) => {
+                self.compile(&Expr::Not(sensor.clone()), scratch, None)?;
+                self.emit(opcode, scratch, target);
+            }
+            // De Morgan: x & !s is !(!x | s), and x | !s is !(!x & s).
+            (Expr::Not(sensor), None) => {
+                let Expr::Sensor(register) = **sensor else {
+                    unreachable!("leaves are sensors");
+                };
+                self.compile_sensor(&register)?;
+                let dual = match opcode {
+                    Opcode::And => Opcode::Or,
+                    _ => Opcode::And,
+                };
+                self.emit(Opcode::Not, target, target);
+                self.emit(dual, register, target);
+                self.emit(Opcode::Not, target, target);
+            }
+            _ => unreachable!("leaves are sensors"),
+        }
+
+        Ok(())
+    }
+
+    fn compile_sensor(&self, register: &Register) -> Result<()> {
+        match register.is_writable() {
+            true => Err(Error::invalid_input(format!(
+                "{} is a register, not a sensor",
+                register
+            ))),
+            false => Ok(()),
+        }
+    }
+}
+
+/// A stretch of hull, with the springdroid starting on its first tile.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Hull(Vec<bool>);
+
+impl Hull {
+    /// Finds the hull in what the springdroid reports after falling into
+    /// space: the first row of the animation that starts on ground.
+    pub fn from_report(report: &str) -> Option<Self> {
+        report
+            .lines()
+            .map(str::trim)
+            .find(|line| line.starts_with('#'))
+            .and_then(|line| line.parse().ok())
+    }
+
+    /// Walks the springdroid along the hull, giving the tile it falls at,
+    /// if it does.
+    pub fn simulate(&self, springscript: &Springscript) -> Option<usize> {
+        let ground = |tile: usize| self.0.get(tile).copied().unwrap_or(true);
+
+        let mut tile = 0;
+        while tile < self.0.len() {
+            if !ground(tile) {
+                return Some(tile);
+            }
+            let ahead = (tile + 1..=tile + springscript.mode.range())
+                .map(ground)
+                .collect::<Vec<_>>();
+            tile += match springscript.evaluate(&ahead) {
+                true => 4,
+                false => 1,
+            };
+        }
+
+        None
+    }
+}
+
+impl FromStr for Hull {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        s.chars()
+            .map(|c| match c {
+                '#' => Ok(true),
+                '.' => Ok(false),
+                _ => Err(Error::invalid_input(format!("unknown tile {:?}", c))),
+            })
+            .collect::<Result<_>>()
+            .map(Hull)
+    }
+}
+
+impl fmt::Display for Hull {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for ground in &self.0 {
+            write!(f, "{}", if *ground { '#' } else { '.' })?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use Register::*;
+
+    const WALK: &str = r#"OR A T
+AND C T
+NOT T J
+AND D J
+WALK"#;
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        let springscript = Springscript::from_str(WALK)?;
+
+        assert_eq!(format!("{}\n", WALK), springscript.to_string());
+        assert_eq!(
+            Some(aoc_error::Location::line(2)),
+            Springscript::from_str("NOT A J\nOR B C\nWALK")
+                .map_err(|error| error.location())
+                .err()
+                .flatten(),
+        );
+        assert!(Springscript::from_str("NOT E J\nWALK").is_err());
+        assert!(Springscript::from_str("NOT E J\nRUN").is_ok());
+        assert!(Springscript::from_str("NOT A J\nJUMP").is_err());
+        assert!(Springscript::from_str(&"NOT A J\n".repeat(16)).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_compile() -> Result<()> {
+        let expected = Springscript::from_str(WALK)?;
+        let (a, c, d) = (Expr::sensor(A), Expr::sensor(C), Expr::sensor(D));
+        let compiled = (!(a & c) & d).compile(Mode::Walk)?;
+
+        for bits in 0..1 << 4 {
+            let ground = (0..4).map(|i| bits & (1 << i) != 0).collect::<Vec<_>>();
+            assert_eq!(expected.evaluate(&ground), compiled.evaluate(&ground));
+        }
+        assert!(compiled.instructions.len() <= expected.instructions.len());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_compile_nested() -> Result<()> {
+        let sensor = Expr::sensor;
+        let expr = ((sensor(A) | !sensor(B)) & (sensor(C) | sensor(D))) | (!sensor(E) & sensor(H));
+        let compiled = expr.compile(Mode::Run)?;
+
+        for bits in 0..1 << 9 {
+            let ground = (0..9).map(|i| bits & (1 << i) != 0).collect::<Vec<_>>();
+            let expected = ((ground[0] || !ground[1]) && (ground[2] || ground[3]))
+                || (!ground[4] && ground[7]);
+            assert_eq!(expected, compiled.evaluate(&ground));
+        }
+        assert!(Expr::sensor(T).compile(Mode::Walk).is_err());
+        assert!((((sensor(A) & sensor(B)) | (sensor(C) & sensor(D)))
+            & ((sensor(A) | sensor(C)) & (sensor(B) | sensor(D))))
+        .compile(Mode::Walk)
+        .is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_simulate() -> Result<()> {
+        let springscript = Springscript::from_str(WALK)?;
+        let report = r#"
+Didn't make it across:
+
+.................
+.................
+@................
+#####.#..########
+"#;
+        let hull = Hull::from_report(report).unwrap();
+
+        assert_eq!("#####.#..########", hull.to_string());
+        assert_eq!(None, hull.simulate(&springscript));
+        assert_eq!(
+            Some(5),
+            Hull::from_str("#####.###")?.simulate(&Springscript::from_str("WALK")?)
+        );
+
+        Ok(())
+    }
+}
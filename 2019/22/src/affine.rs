@@ -0,0 +1,94 @@
+use aoc_math::{mod_inverse, mul_mod};
+
+/// The map `x ↦ a·x + b (mod m)`, as every shuffle technique is one and
+/// so, then, is any sequence of them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Affine {
+    a: i128,
+    b: i128,
+    modulus: i128,
+}
+
+impl Affine {
+    pub fn new(a: i128, b: i128, modulus: i128) -> Self {
+        Affine {
+            a: a.rem_euclid(modulus),
+            b: b.rem_euclid(modulus),
+            modulus,
+        }
+    }
+
+    pub fn identity(modulus: i128) -> Self {
+        Affine::new(1, 0, modulus)
+    }
+
+    pub fn apply(&self, x: i128) -> i128 {
+        (mul_mod(self.a, x, self.modulus) + self.b) % self.modulus
+    }
+
+    /// This map followed by `other`.
+    pub fn then(&self, other: &Affine) -> Self {
+        Affine::new(
+            mul_mod(self.a, other.a, self.modulus),
+            mul_mod(other.a, self.b, self.modulus) + other.b,
+            self.modulus,
+        )
+    }
+
+    /// This map applied `n` times over, by repeated squaring.
+    pub fn pow(&self, mut n: u128) -> Self {
+        let mut result = Affine::identity(self.modulus);
+        let mut square = *self;
+        while n > 0 {
+            if n & 1 == 1 {
+                result = result.then(&square);
+            }
+            square = square.then(&square);
+            n >>= 1;
+        }
+
+        result
+    }
+
+    /// The map undoing this one, if `a` has an inverse modulo `m`.
+    pub fn inverse(&self) -> Option<Self> {
+        let a = mod_inverse(self.a, self.modulus)?;
+
+        Some(Affine::new(
+            a,
+            -mul_mod(a, self.b, self.modulus),
+            self.modulus,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_then() {
+        let double = Affine::new(2, 0, 11);
+        let increment = Affine::new(1, 1, 11);
+
+        assert_eq!(7, double.then(&increment).apply(3));
+        assert_eq!(8, increment.then(&double).apply(3));
+    }
+
+    #[test]
+    fn test_pow() {
+        let f = Affine::new(3, 5, 10_007);
+        let slow = (0..1000).fold(2019, |x, _| f.apply(x));
+
+        assert_eq!(slow, f.pow(1000).apply(2019));
+        assert_eq!(Affine::identity(10_007), f.pow(0));
+    }
+
+    #[test]
+    fn test_inverse() {
+        let f = Affine::new(7, -3, 10);
+
+        assert_eq!(Some(Affine::identity(10)), f.inverse().map(|g| f.then(&g)));
+        assert_eq!(None, Affine::new(4, 1, 10).inverse());
+    }
+}
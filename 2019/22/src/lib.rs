@@ -0,0 +1,262 @@
+use regex::Regex;
+
+use crate::affine::Affine;
+
+mod affine;
+
+struct Deck {
+    count: i128,
+}
+
+#[derive(Copy, Clone, Debug)]
+enum Technique {
+    DealIntoNewStack,
+    CutNCards(i128),
+    DealWithIncrement(i128),
+}
+
+impl Technique {
+    /// Where the card at `x` ends up.
+    fn to_affine(self, count: i128) -> Affine {
+        match self {
+            Technique::DealIntoNewStack => Affine::new(-1, -1, count),
+            Technique::CutNCards(n) => Affine::new(1, -n, count),
+            Technique::DealWithIncrement(n) => Affine::new(n, 0, count),
+        }
+    }
+}
+
+impl Deck {
+    fn new(count: i128) -> Self {
+        Deck { count }
+    }
+
+    /// The whole sequence of `techniques` as a single map of positions.
+    fn compile(&self, techniques: &[Technique]) -> Affine {
+        techniques
+            .iter()
+            .fold(Affine::identity(self.count), |affine, technique| {
+                affine.then(&technique.to_affine(self.count))
+            })
+    }
+
+    /// Where `card` ends up after shuffling `repetitions` times.
+    fn position_of(&self, techniques: &[Technique], repetitions: u128, card: i128) -> i128 {
+        self.compile(techniques).pow(repetitions).apply(card)
+    }
+
+    /// Which card ends up at `position` after shuffling `repetitions`
+    /// times; `None` if a deal's increment shares a factor with the deck
+    /// size, as then some positions get more than one card.
+    fn card_at(&self, techniques: &[Technique], repetitions: u128, position: i128) -> Option<i128> {
+        self.compile(techniques)
+            .pow(repetitions)
+            .inverse()
+            .map(|inverse| inverse.apply(position))
+    }
+
+    fn shuffle(&self, input: &str, card: i128) -> i128 {
+        self.position_of(&self.get_techniques(input), 1, card)
+    }
+
+    fn shuffle_repeatedly(&self, input: &str, repetitions: u128, position: i128) -> Option<i128> {
+        self.card_at(&self.get_techniques(input), repetitions, position)
+    }
+
+    fn get_techniques(&self, input: &str) -> Vec<Technique> {
+        let deal_with_increment = Regex::new(r"deal with increment (\d+)").unwrap();
+        let cut = Regex::new(r"cut (-?\d+)").unwrap();
+
+        input
+            .lines()
+            .map(|line| match line {
+                line if line.eq("deal into new stack") => Technique::DealIntoNewStack,
+                line if deal_with_increment.is_match(line) => {
+                    let increment = deal_with_increment.captures(line).unwrap()[1]
+                        .parse::<i128>()
+                        .unwrap();
+                    Technique::DealWithIncrement(increment)
+                }
+                line if cut.is_match(line) => {
+                    let cut = cut.captures(line).unwrap()[1].parse::<i128>().unwrap();
+                    Technique::CutNCards(cut)
+                }
+                line => {
+                    panic!("Unknown shuffle instruction: {}", line);
+                }
+            })
+            .collect()
+    }
+}
+
+pub fn get_part_one(input: &str) -> i128 {
+    Deck::new(10007).shuffle(input, 2019)
+}
+
+pub fn get_part_two(input: &str) -> Result<i128, String> {
+    Deck::new(119_315_717_514_047_i128)
+        .shuffle_repeatedly(input, 101_741_582_076_661, 2020)
+        .ok_or_else(|| String::from("The shuffle can't be undone"))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_math::gcd;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    /// Shuffles a deck of `count` cards one card at a time.
+    fn shuffle_slowly(count: i128, techniques: &[Technique]) -> Vec<i128> {
+        techniques
+            .iter()
+            .fold((0..count).collect(), |cards: Vec<i128>, technique| {
+                let mut shuffled = cards.clone();
+                match *technique {
+                    Technique::DealIntoNewStack => shuffled.reverse(),
+                    Technique::CutNCards(n) => {
+                        shuffled.rotate_left(n.rem_euclid(count) as usize);
+                    }
+                    Technique::DealWithIncrement(n) => {
+                        for (i, card) in cards.into_iter().enumerate() {
+                            shuffled[(i as i128 * n % count) as usize] = card;
+                        }
+                    }
+                }
+                shuffled
+            })
+    }
+
+    fn technique(count: i128) -> impl Strategy<Value = Technique> {
+        prop_oneof![
+            Just(Technique::DealIntoNewStack),
+            (-count..count).prop_map(Technique::CutNCards),
+            (1..count)
+                .prop_filter("increment must be coprime", move |n| gcd(*n, count)
+                    == Some(1))
+                .prop_map(Technique::DealWithIncrement),
+        ]
+    }
+
+    fn shuffle() -> impl Strategy<Value = (i128, Vec<Technique>, u128)> {
+        (2..60_i128).prop_flat_map(|count| {
+            (
+                Just(count),
+                prop::collection::vec(technique(count), 0..8),
+                0..5_u128,
+            )
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_position_of((count, techniques, repetitions) in shuffle()) {
+            let deck = Deck::new(count);
+            let mut cards = (0..count).collect::<Vec<_>>();
+            for _ in 0..repetitions {
+                let shuffled = shuffle_slowly(count, &techniques);
+                cards = shuffled.iter().map(|i| cards[*i as usize]).collect();
+            }
+
+            for (position, card) in cards.iter().enumerate() {
+                prop_assert_eq!(position as i128, deck.position_of(&techniques, repetitions, *card));
+                prop_assert_eq!(Some(*card), deck.card_at(&techniques, repetitions, position as i128));
+            }
+        }
+    }
+
+    #[test]
+    fn test_deal_into_new_stack() {
+        let deck = Deck::new(10);
+
+        // 9 8 7 6 5 4 3 2 1 0
+        assert_eq!(9, deck.shuffle("deal into new stack", 0));
+        assert_eq!(0, deck.shuffle("deal into new stack", 9));
+    }
+
+    #[test]
+    fn test_cut_n_cards() {
+        let deck = Deck::new(10);
+
+        // 3 4 5 6 7 8 9 0 1 2
+        assert_eq!(7, deck.shuffle("cut 3", 0));
+        assert_eq!(0, deck.shuffle("cut 3", 3));
+    }
+
+    #[test]
+    fn test_cut_negative_n_cards() {
+        let deck = Deck::new(10);
+
+        // 6 7 8 9 0 1 2 3 4 5
+        assert_eq!(7, deck.shuffle("cut -4", 3));
+        assert_eq!(0, deck.shuffle("cut -4", 6));
+    }
+
+    #[test]
+    fn test_deal_with_increment_n() {
+        let deck = Deck::new(10);
+
+        // 0 7 4 1 8 5 2 9 6 3
+        assert_eq!(0, deck.shuffle("deal with increment 3", 0));
+        assert_eq!(9, deck.shuffle("deal with increment 3", 3));
+    }
+
+    #[test]
+    fn test_0369258147() {
+        let deck = Deck::new(10);
+        let instructions = r#"deal with increment 7
+deal into new stack
+deal into new stack"#;
+
+        // 0 3 6 9 2 5 8 1 4 7
+        assert_eq!(0, deck.shuffle(&instructions, 0));
+        assert_eq!(1, deck.shuffle(&instructions, 3));
+        assert_eq!(9, deck.shuffle(&instructions, 7));
+    }
+
+    #[test]
+    fn test_3074185296() {
+        let deck = Deck::new(10);
+        let instructions = r#"cut 6
+deal with increment 7
+deal into new stack"#;
+
+        // 3 0 7 4 1 8 5 2 9 6
+        assert_eq!(0, deck.shuffle(&instructions, 3));
+        assert_eq!(1, deck.shuffle(&instructions, 0));
+        assert_eq!(9, deck.shuffle(&instructions, 6));
+    }
+
+    #[test]
+    fn test_6307418529() {
+        let deck = Deck::new(10);
+        let instructions = r#"deal with increment 7
+deal with increment 9
+cut -2"#;
+
+        // 6 3 0 7 4 1 8 5 2 9
+        assert_eq!(0, deck.shuffle(&instructions, 6));
+        assert_eq!(1, deck.shuffle(&instructions, 3));
+        assert_eq!(9, deck.shuffle(&instructions, 9));
+    }
+
+    #[test]
+    fn test_9258147036() {
+        let deck = Deck::new(10);
+        let instructions = r#"deal into new stack
+cut -2
+deal with increment 7
+cut 8
+cut -4
+deal with increment 7
+cut 3
+deal with increment 9
+deal with increment 3
+cut -1"#;
+
+        // 9 2 5 8 1 4 7 0 3 6
+        assert_eq!(0, deck.shuffle(&instructions, 9));
+        assert_eq!(1, deck.shuffle(&instructions, 2));
+        assert_eq!(9, deck.shuffle(&instructions, 6));
+    }
+}
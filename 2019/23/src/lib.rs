@@ -0,0 +1,218 @@
+use std::collections::VecDeque;
+use std::str::FromStr;
+
+use intcode::{Intcode, State};
+
+use crate::threaded::Config;
+pub use crate::threaded::Goal;
+
+mod threaded;
+
+struct Computer {
+    intcode: Intcode,
+    idle: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Packet {
+    destination: i64,
+    x: i64,
+    y: i64,
+}
+
+impl Packet {
+    fn from_vec(instructions: Vec<i64>) -> Packet {
+        Packet {
+            destination: instructions[0],
+            x: instructions[1],
+            y: instructions[2],
+        }
+    }
+}
+
+impl Computer {
+    fn new(input: &str, address: usize) -> Self {
+        let mut computer = Computer {
+            intcode: Intcode::from_str(input).unwrap(),
+            idle: false,
+        };
+        computer.intcode.push_input(address as i64);
+        computer
+    }
+
+    fn run(&mut self) -> Option<Packet> {
+        let mut instructions = Vec::with_capacity(3);
+
+        loop {
+            match self.intcode.run().unwrap() {
+                State::Output(output) => {
+                    instructions.push(output);
+                    if instructions.len() == 3 {
+                        return Some(Packet::from_vec(instructions));
+                    }
+                }
+                State::NeedsInput => {
+                    self.idle = true;
+                    return None;
+                }
+                State::Halted => return None,
+            }
+        }
+    }
+
+    fn receive(&mut self, x: i64, y: i64) {
+        self.intcode.push_input(x);
+        self.intcode.push_input(y);
+        self.idle = false;
+    }
+
+    fn poll(&mut self) {
+        if self.idle {
+            self.intcode.push_input(-1);
+            self.idle = false;
+        }
+    }
+}
+
+fn network(mut computers: Vec<Computer>) -> i64 {
+    let mut packets: VecDeque<Packet> = VecDeque::new();
+    loop {
+        for computer in computers.iter_mut() {
+            if let Some(packet) = computer.run() {
+                packets.push_back(packet);
+            }
+        }
+        while !packets.is_empty() {
+            let packet = packets.pop_front().unwrap();
+            if packet.destination == 255 {
+                return packet.y;
+            }
+            computers[packet.destination as usize].receive(packet.x, packet.y);
+        }
+        for computer in computers.iter_mut() {
+            computer.poll();
+        }
+    }
+}
+
+fn natwork(mut computers: Vec<Computer>) -> i64 {
+    let mut nat: Vec<Packet> = Vec::new();
+    let mut y: i64 = -1;
+    let mut packets: VecDeque<Packet> = VecDeque::new();
+
+    loop {
+        // Receive packets.
+        for computer in computers.iter_mut() {
+            if let Some(packet) = computer.run() {
+                packets.push_back(packet);
+            }
+        }
+
+        // Dispatch packets.
+        while !packets.is_empty() {
+            let packet = packets.pop_front().unwrap();
+            if packet.destination == 255 {
+                nat.push(packet);
+            } else {
+                computers[packet.destination as usize].receive(packet.x, packet.y);
+            }
+        }
+
+        // If all computers are idle, NAT it.
+        let waiting = computers.iter().filter(|computer| computer.idle).count();
+        if waiting == computers.len() && !nat.is_empty() {
+            let packet = nat.pop().unwrap();
+            nat.clear();
+            if y == packet.y {
+                return y;
+            } else {
+                y = packet.y;
+            }
+            computers[0].receive(packet.x, packet.y);
+        }
+
+        // Pass -1 to all computers awaiting input.
+        for computer in computers.iter_mut() {
+            computer.poll();
+        }
+    }
+}
+
+fn get_computers(input: &str, count: usize) -> Vec<Computer> {
+    (0..count)
+        .map(|address| Computer::new(input, address))
+        .collect()
+}
+
+/// Runs the network on a thread per NIC, printing the trace to stderr if
+/// `trace` is set.
+pub fn run_threaded(input: &str, goal: Goal, trace: bool) -> Result<i64, String> {
+    let (answer, events) = threaded::run(get_computers(input, 50), Config::default(), goal);
+    if trace {
+        for event in events {
+            eprintln!("{}", event);
+        }
+    }
+
+    answer.ok_or_else(|| "The network stopped without an answer".to_owned())
+}
+
+pub fn get_part_one(input: &str) -> i64 {
+    network(get_computers(input, 50))
+}
+
+pub fn get_part_two(input: &str) -> i64 {
+    natwork(get_computers(input, 50))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Address 0 starts by sending X=0, Y=7 to address 1; every NIC adds
+    /// its address to the Y of each packet it receives and sends that on
+    /// to the NAT.
+    const NIC: &str = "3,100,1005,100,11,104,1,104,0,104,7,\
+                       3,101,1008,101,-1,102,1005,102,11,3,103,\
+                       1,103,100,104,104,255,4,101,4,104,1105,1,11";
+
+    #[test]
+    fn test_network() {
+        let (answer, trace) = threaded::run(
+            get_computers(NIC, 3),
+            Config::default(),
+            Goal::FirstNatPacket,
+        );
+
+        assert_eq!(8, network(get_computers(NIC, 3)));
+        assert_eq!(Some(8), answer);
+        assert_eq!(
+            "  0 ->   1: X=0 Y=7",
+            trace
+                .iter()
+                .find(|event| matches!(event, threaded::Trace::Sent { .. }))
+                .unwrap()
+                .to_string(),
+        );
+    }
+
+    #[test]
+    fn test_natwork() {
+        for idle_polls in 1..=4 {
+            let (answer, trace) = threaded::run(
+                get_computers(NIC, 3),
+                Config { idle_polls },
+                Goal::RepeatedWake,
+            );
+
+            assert_eq!(Some(natwork(get_computers(NIC, 3))), answer);
+            assert_eq!(
+                2,
+                trace
+                    .iter()
+                    .filter(|event| matches!(event, threaded::Trace::Wake(_)))
+                    .count(),
+            );
+        }
+    }
+}
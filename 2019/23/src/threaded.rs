@@ -0,0 +1,234 @@
+use std::convert::TryFrom;
+use std::fmt;
+use std::sync::mpsc::{self, Receiver, Sender, TryRecvError};
+use std::thread;
+
+use crate::{Computer, Packet};
+
+/// The address the NAT listens on.
+const NAT: i64 = 255;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Config {
+    /// How many times in a row a NIC has to find nothing to receive
+    /// before it counts as idle.
+    pub idle_polls: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config { idle_polls: 3 }
+    }
+}
+
+/// When to stop the network, and what to report.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Goal {
+    /// The Y value of the first packet sent to the NAT.
+    FirstNatPacket,
+    /// The first Y value the NAT delivers to address 0 twice in a row.
+    RepeatedWake,
+}
+
+/// What happened on the network, in the order the router saw it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Trace {
+    Sent { source: usize, packet: Packet },
+    Idle(usize),
+    Wake(Packet),
+}
+
+impl fmt::Display for Trace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Trace::Sent { source, packet } => write!(
+                f,
+                "{:>3} -> {:>3}: X={} Y={}",
+                source, packet.destination, packet.x, packet.y
+            ),
+            Trace::Idle(address) => write!(f, "{:>3} idle", address),
+            Trace::Wake(packet) => write!(f, "NAT -> {:>3}: X={} Y={}", 0, packet.x, packet.y),
+        }
+    }
+}
+
+/// Keeps the last packet sent to it, to wake address 0 with once the
+/// whole network is idle.
+#[derive(Debug, Default)]
+pub struct Nat {
+    packet: Option<Packet>,
+    delivered: Option<i64>,
+}
+
+impl Nat {
+    fn receive(&mut self, packet: Packet) {
+        self.packet = Some(packet);
+    }
+
+    /// The packet to wake address 0 with, if one's arrived, and whether
+    /// its Y value is the same as the last one delivered.
+    fn wake(&mut self) -> Option<(Packet, bool)> {
+        let packet = Packet {
+            destination: 0,
+            ..self.packet?
+        };
+        let repeated = self.delivered == Some(packet.y);
+        self.delivered = Some(packet.y);
+
+        Some((packet, repeated))
+    }
+}
+
+enum Event {
+    Sent(usize, Packet),
+    /// A NIC has gone idle, having received this many packets.
+    Idle(usize, usize),
+}
+
+/// Runs a NIC until the network shuts down or its program halts.
+///
+/// Once idle, it stops polling and waits for a packet instead; the
+/// count it reports lets the router tell whether anything's arrived
+/// since.
+fn nic(
+    address: usize,
+    mut computer: Computer,
+    inbox: Receiver<(i64, i64)>,
+    events: Sender<Event>,
+    config: Config,
+) {
+    let (mut received, mut polls) = (0, 0);
+
+    loop {
+        if let Some(packet) = computer.run() {
+            polls = 0;
+            if events.send(Event::Sent(address, packet)).is_err() {
+                return;
+            }
+            continue;
+        }
+        if !computer.idle {
+            return;
+        }
+
+        let next = match inbox.try_recv() {
+            Ok(next) => Some(next),
+            Err(TryRecvError::Disconnected) => return,
+            Err(TryRecvError::Empty) if polls + 1 < config.idle_polls => None,
+            Err(TryRecvError::Empty) => {
+                if events.send(Event::Idle(address, received)).is_err() {
+                    return;
+                }
+                match inbox.recv() {
+                    Ok(next) => Some(next),
+                    Err(_) => return,
+                }
+            }
+        };
+        match next {
+            Some((x, y)) => {
+                computer.receive(x, y);
+                received += 1;
+                polls = 0;
+            }
+            None => {
+                computer.poll();
+                polls += 1;
+            }
+        }
+    }
+}
+
+/// The router's view of each NIC.
+struct Router {
+    inboxes: Vec<Sender<(i64, i64)>>,
+    /// How many packets have been sent to each NIC.
+    delivered: Vec<usize>,
+    idle: Vec<bool>,
+}
+
+impl Router {
+    /// Passes `packet` on, returning `None` if there's no NIC to take it.
+    fn deliver(&mut self, packet: Packet) -> Option<()> {
+        let address = usize::try_from(packet.destination).ok()?;
+        self.inboxes.get(address)?.send((packet.x, packet.y)).ok()?;
+        self.delivered[address] += 1;
+        self.idle[address] = false;
+
+        Some(())
+    }
+}
+
+/// Runs each computer on its own thread, with packets routed through
+/// channels, until the `goal` is reached.
+///
+/// Returns the answer, if the network got there, and the trace of
+/// everything that happened on the way.
+pub fn run(computers: Vec<Computer>, config: Config, goal: Goal) -> (Option<i64>, Vec<Trace>) {
+    let (events, receiver) = mpsc::channel();
+    let mut router = Router {
+        inboxes: Vec::with_capacity(computers.len()),
+        delivered: vec![0; computers.len()],
+        idle: vec![false; computers.len()],
+    };
+    let mut handles = Vec::with_capacity(computers.len());
+    for (address, computer) in computers.into_iter().enumerate() {
+        let (inbox, packets) = mpsc::channel();
+        let events = events.clone();
+        router.inboxes.push(inbox);
+        handles.push(thread::spawn(move || {
+            nic(address, computer, packets, events, config)
+        }));
+    }
+    drop(events);
+
+    let mut nat = Nat::default();
+    let mut trace = Vec::new();
+    let answer = loop {
+        let Ok(event) = receiver.recv() else {
+            break None;
+        };
+        match event {
+            Event::Sent(source, packet) => {
+                trace.push(Trace::Sent { source, packet });
+                router.idle[source] = false;
+                if packet.destination == NAT {
+                    if goal == Goal::FirstNatPacket {
+                        break Some(packet.y);
+                    }
+                    nat.receive(packet);
+                } else if router.deliver(packet).is_none() {
+                    break None;
+                }
+            }
+            Event::Idle(address, received) => {
+                if received != router.delivered[address] {
+                    continue;
+                }
+                trace.push(Trace::Idle(address));
+                router.idle[address] = true;
+                if router.idle.iter().all(|idle| *idle) {
+                    let Some((packet, repeated)) = nat.wake() else {
+                        break None;
+                    };
+                    trace.push(Trace::Wake(packet));
+                    if repeated {
+                        break Some(packet.y);
+                    }
+                    if router.deliver(packet).is_none() {
+                        break None;
+                    }
+                }
+            }
+        }
+    };
+
+    // Hanging up on every NIC is what tells them to stop.
+    drop(router);
+    drop(receiver);
+    for handle in handles {
+        let _ = handle.join();
+    }
+
+    (answer, trace)
+}
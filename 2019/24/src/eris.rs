@@ -0,0 +1,452 @@
+use std::fmt;
+use std::str::FromStr;
+
+use aoc_error::{Context, Error, Result};
+
+const SIZE: usize = 5;
+const TILES: usize = SIZE * SIZE;
+/// The middle tile, which in the recursive topology is the next level in.
+const CENTRE: u32 = 1 << 12;
+
+/// Which neighbour counts bring a bug to life in an empty tile, and which
+/// keep one alive, as bit sets over the count.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rules {
+    birth: u16,
+    survival: u16,
+}
+
+impl Rules {
+    /// _"A bug dies…unless there is exactly one bug adjacent to it…An
+    /// empty space becomes infested…if exactly one or two bugs are
+    /// adjacent to it."_
+    pub const ERIS: Rules = Rules {
+        birth: 0b110,
+        survival: 0b10,
+    };
+
+    /// No tile has more than eight neighbours, so every count must be at
+    /// most `8`.
+    pub fn new(birth: &[usize], survival: &[usize]) -> Result<Self> {
+        let set = |counts: &[usize]| {
+            counts.iter().try_fold(0, |set, &count| match count {
+                0..=8 => Ok(set | 1 << count),
+                _ => Err(Error::invalid_input(format!(
+                    "invalid neighbour count {}",
+                    count
+                ))),
+            })
+        };
+
+        Ok(Rules {
+            birth: set(birth)?,
+            survival: set(survival)?,
+        })
+    }
+
+    /// Whether bugs are born with no neighbours at all, as with `B0`.
+    pub fn is_spontaneous(&self) -> bool {
+        self.birth & 1 == 1
+    }
+
+    fn next(&self, bug: bool, neighbours: u32) -> bool {
+        let set = if bug { self.survival } else { self.birth };
+        set >> neighbours & 1 == 1
+    }
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Rules::ERIS
+    }
+}
+
+/// Written as in Life-like automata, e.g. `B12/S1`.
+impl FromStr for Rules {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (birth, survival) = s.split_once('/').context("expected rules such as B12/S1")?;
+        let counts = |part: &str, prefix: char| -> Result<Vec<usize>> {
+            part.strip_prefix(prefix)
+                .with_context(|| format!("expected {} before {:?}", prefix, part))?
+                .chars()
+                .map(|c| {
+                    c.to_digit(10)
+                        .map(|count| count as usize)
+                        .with_context(|| format!("invalid neighbour count {:?}", c))
+                })
+                .collect()
+        };
+
+        Rules::new(&counts(birth, 'B')?, &counts(survival, 'S')?)
+    }
+}
+
+impl fmt::Display for Rules {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let counts = |set: u16| {
+            (0..=8)
+                .filter(|count| set >> count & 1 == 1)
+                .map(|count| count.to_string())
+                .collect::<String>()
+        };
+
+        write!(f, "B{}/S{}", counts(self.birth), counts(self.survival))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Topology {
+    /// A single level, its edges the end of the world.
+    Flat,
+    /// Levels nested in each other's centre tiles, from `-depth` out to
+    /// `depth` in; any beyond are taken to stay empty, so the memory (and
+    /// time per minute) needed is bounded however long it runs.
+    Recursive { depth: usize },
+}
+
+/// For each tile, the bits of its neighbours on its own level, on the
+/// level containing it and on the level it contains.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Masks {
+    same: [u32; TILES],
+    outer: [u32; TILES],
+    inner: [u32; TILES],
+}
+
+impl Masks {
+    fn new(topology: Topology) -> Self {
+        let bit = |x: usize, y: usize| 1 << (y * SIZE + x);
+        let row = |y: usize| (0..SIZE).fold(0, |mask, x| mask | bit(x, y));
+        let column = |x: usize| (0..SIZE).fold(0, |mask, y| mask | bit(x, y));
+        let recursive = matches!(topology, Topology::Recursive { .. });
+
+        let mut masks = Masks {
+            same: [0; TILES],
+            outer: [0; TILES],
+            inner: [0; TILES],
+        };
+        for (i, (x, y)) in (0..SIZE)
+            .flat_map(|y| (0..SIZE).map(move |x| (x, y)))
+            .enumerate()
+        {
+            if x > 0 {
+                masks.same[i] |= bit(x - 1, y);
+            }
+            if x < SIZE - 1 {
+                masks.same[i] |= bit(x + 1, y);
+            }
+            if y > 0 {
+                masks.same[i] |= bit(x, y - 1);
+            }
+            if y < SIZE - 1 {
+                masks.same[i] |= bit(x, y + 1);
+            }
+            if !recursive {
+                continue;
+            }
+
+            masks.same[i] &= !CENTRE;
+            if x == 0 {
+                masks.outer[i] |= bit(1, 2);
+            }
+            if x == SIZE - 1 {
+                masks.outer[i] |= bit(3, 2);
+            }
+            if y == 0 {
+                masks.outer[i] |= bit(2, 1);
+            }
+            if y == SIZE - 1 {
+                masks.outer[i] |= bit(2, 3);
+            }
+            masks.inner[i] = match (x, y) {
+                (2, 1) => row(0),
+                (2, 3) => row(SIZE - 1),
+                (1, 2) => column(0),
+                (3, 2) => column(SIZE - 1),
+                _ => 0,
+            };
+        }
+
+        masks
+    }
+}
+
+/// Eris, one 25-bit bitboard per level, bit `y * 5 + x` being the tile at
+/// `(x, y)`; read as a number, a level is its own biodiversity rating.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Eris {
+    /// From the outermost level in; the one scanned is in the middle.
+    levels: Vec<u32>,
+    topology: Topology,
+    rules: Rules,
+    masks: Masks,
+}
+
+/// The first layout to appear twice.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Repeat {
+    /// The minute it first appears.
+    pub first: usize,
+    /// How many minutes later it appears again, and every so often after.
+    pub period: usize,
+    pub eris: Eris,
+}
+
+impl Eris {
+    pub fn new(scan: u32, topology: Topology, rules: Rules) -> Self {
+        let depth = match topology {
+            Topology::Flat => 0,
+            Topology::Recursive { depth } => depth,
+        };
+        let mut levels = vec![0; 2 * depth + 1];
+        levels[depth] = match topology {
+            Topology::Flat => scan,
+            Topology::Recursive { .. } => scan & !CENTRE,
+        };
+
+        Eris {
+            levels,
+            topology,
+            rules,
+            masks: Masks::new(topology),
+        }
+    }
+
+    /// The scanned level; for a flat Eris, the only one.
+    pub fn level(&self) -> u32 {
+        self.levels[self.levels.len() / 2]
+    }
+
+    pub fn biodiversity(&self) -> u32 {
+        self.level()
+    }
+
+    pub fn bugs(&self) -> usize {
+        self.levels
+            .iter()
+            .map(|level| level.count_ones() as usize)
+            .sum()
+    }
+
+    pub fn step(&mut self) {
+        // Unless bugs can appear out of nothing, only levels holding bugs,
+        // or next to one that does, can change.
+        let (low, high) = if self.rules.is_spontaneous() {
+            (0, self.levels.len() - 1)
+        } else {
+            let first = match self.levels.iter().position(|level| *level != 0) {
+                Some(first) => first,
+                None => return,
+            };
+            let last = self
+                .levels
+                .iter()
+                .rposition(|level| *level != 0)
+                .unwrap_or(first);
+            (
+                first.saturating_sub(1),
+                (last + 1).min(self.levels.len() - 1),
+            )
+        };
+
+        let next = (low..=high)
+            .map(|index| {
+                let outer = index.checked_sub(1).map_or(0, |outer| self.levels[outer]);
+                let inner = self.levels.get(index + 1).copied().unwrap_or(0);
+                self.next_level(outer, self.levels[index], inner)
+            })
+            .collect::<Vec<_>>();
+        self.levels[low..=high].copy_from_slice(&next);
+    }
+
+    fn next_level(&self, outer: u32, level: u32, inner: u32) -> u32 {
+        let mut next = 0;
+        for i in 0..TILES {
+            let neighbours = (level & self.masks.same[i]).count_ones()
+                + (outer & self.masks.outer[i]).count_ones()
+                + (inner & self.masks.inner[i]).count_ones();
+            if self.rules.next(level >> i & 1 == 1, neighbours) {
+                next |= 1 << i;
+            }
+        }
+
+        match self.topology {
+            Topology::Flat => next,
+            Topology::Recursive { .. } => next & !CENTRE,
+        }
+    }
+
+    pub fn run(&mut self, minutes: usize) {
+        (0..minutes).for_each(|_| self.step());
+    }
+
+    /// Finds the first layout to appear twice within `limit` minutes,
+    /// by Brent's algorithm, which only ever keeps two layouts at a time.
+    pub fn first_repeat(&self, limit: usize) -> Option<Repeat> {
+        let mut power = 1;
+        let mut period = 1;
+        let mut minutes = 1;
+        let mut tortoise = self.clone();
+        let mut hare = self.clone();
+        hare.step();
+        while tortoise.levels != hare.levels {
+            if minutes >= limit {
+                return None;
+            }
+            if power == period {
+                tortoise = hare.clone();
+                power *= 2;
+                period = 0;
+            }
+            hare.step();
+            period += 1;
+            minutes += 1;
+        }
+
+        let mut tortoise = self.clone();
+        let mut hare = self.clone();
+        hare.run(period);
+        let mut first = 0;
+        while tortoise.levels != hare.levels {
+            tortoise.step();
+            hare.step();
+            first += 1;
+        }
+
+        Some(Repeat {
+            first,
+            period,
+            eris: hare,
+        })
+    }
+}
+
+/// Reads a single scan, `#` for bugs; the centre is ignored in the
+/// recursive topology.
+pub fn get_scan(input: &str) -> Result<u32> {
+    let tiles = input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .flat_map(str::chars)
+        .collect::<Vec<_>>();
+    if tiles.len() != TILES {
+        return Err(Error::invalid_input(format!(
+            "expected {} tiles but found {}",
+            TILES,
+            tiles.len()
+        )));
+    }
+
+    tiles
+        .iter()
+        .enumerate()
+        .try_fold(0, |scan, (i, c)| match c {
+            '#' => Ok(scan | 1 << i),
+            '.' | '?' => Ok(scan),
+            _ => Err(Error::invalid_input(format!("unknown tile {:?}", c))),
+        })
+}
+
+/// Draws a level as scanned, the centre of a recursive one as `?`.
+fn write_level(f: &mut fmt::Formatter<'_>, level: u32, recursive: bool) -> fmt::Result {
+    for y in 0..SIZE {
+        if y > 0 {
+            writeln!(f)?;
+        }
+        for x in 0..SIZE {
+            let bit = 1 << (y * SIZE + x);
+            let c = match (recursive && bit == CENTRE, level & bit != 0) {
+                (true, _) => '?',
+                (false, true) => '#',
+                (false, false) => '.',
+            };
+            write!(f, "{}", c)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// A flat Eris as its one level; a recursive one as the scanned level and
+/// any others with bugs, each headed with its depth.
+impl fmt::Display for Eris {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.topology {
+            Topology::Flat => write_level(f, self.level(), false),
+            Topology::Recursive { depth } => {
+                let mut levels = self
+                    .levels
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, level)| **level != 0 || *index == depth)
+                    .peekable();
+                while let Some((index, level)) = levels.next() {
+                    writeln!(f, "Depth {}:", index as isize - depth as isize)?;
+                    write_level(f, *level, true)?;
+                    if levels.peek().is_some() {
+                        write!(f, "\n\n")?;
+                    }
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_masks() {
+        let flat = Masks::new(Topology::Flat);
+        let recursive = Masks::new(Topology::Recursive { depth: 1 });
+
+        assert_eq!(0b100010, flat.same[0]);
+        assert_eq!(1 << 7 | 1 << 11 | 1 << 13 | 1 << 17, flat.same[12]);
+        assert_eq!(1 << 7 | 1 << 11, recursive.outer[0]);
+        assert_eq!(1 << 2 | 1 << 6 | 1 << 8, recursive.same[7]);
+        assert_eq!(0b11111, recursive.inner[7]);
+        assert!((0..TILES).all(|i| recursive.same[i] & CENTRE == 0));
+    }
+
+    #[test]
+    fn test_rules() -> Result<()> {
+        assert_eq!(Rules::ERIS, Rules::from_str("B12/S1")?);
+        assert_eq!("B3/S23", Rules::new(&[3], &[2, 3])?.to_string());
+        assert!(Rules::new(&[3], &[16]).is_err());
+        assert!(Rules::from_str("B9/S1").is_err());
+        assert!(Rules::from_str("12/1").is_err());
+
+        let mut eris = Eris::new(
+            0,
+            Topology::Recursive { depth: 1 },
+            Rules::from_str("B0/S")?,
+        );
+        eris.step();
+        assert_eq!(3 * 24, eris.bugs());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bounded() -> Result<()> {
+        let scan = get_scan("....#\n#..#.\n#..##\n..#..\n#....")?;
+        let eris = Eris::new(scan, Topology::Recursive { depth: 1 }, Rules::ERIS);
+        let repeat = eris.first_repeat(usize::MAX).context("no repeat")?;
+
+        let mut first = eris.clone();
+        first.run(repeat.first);
+        let mut again = first.clone();
+        again.run(repeat.period);
+        assert_eq!(3, repeat.eris.levels.len());
+        assert_eq!(repeat.eris, first);
+        assert_eq!(repeat.eris, again);
+
+        Ok(())
+    }
+}
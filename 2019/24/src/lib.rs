@@ -0,0 +1,179 @@
+use aoc_error::{Error, Result};
+
+use crate::eris::{get_scan, Eris, Rules, Topology};
+
+pub mod eris;
+
+/// How long to look for a repeat in the recursive variant.
+pub const LIMIT: usize = 10_000_000;
+
+/// The biodiversity rating of the first layout to appear twice.
+pub fn get_first_repeat_biodiversity(input: &str, rules: Rules) -> Result<u32> {
+    Eris::new(get_scan(input)?, Topology::Flat, rules)
+        .first_repeat(usize::MAX)
+        .map(|repeat| repeat.eris.biodiversity())
+        .ok_or_else(|| Error::no_solution("No layout appears twice"))
+}
+
+/// The bugs present in the recursive variant after 200 minutes; rules
+/// with `B0` would fill infinitely many levels, so are rejected.
+pub fn get_recursive_bug_count(input: &str, rules: Rules) -> Result<usize> {
+    if rules.is_spontaneous() {
+        return Err(Error::invalid_input(format!(
+            "{} breeds bugs on infinitely many levels",
+            rules
+        )));
+    }
+
+    // Without B0, bugs spread at most a level every other minute.
+    let minutes = 200;
+    let mut eris = Eris::new(
+        get_scan(input)?,
+        Topology::Recursive {
+            depth: minutes / 2 + 1,
+        },
+        rules,
+    );
+    eris.run(minutes);
+
+    Ok(eris.bugs())
+}
+
+pub fn get_part_one(input: &str) -> Result<u32> {
+    get_first_repeat_biodiversity(input, Rules::default())
+}
+
+pub fn get_part_two(input: &str) -> Result<usize> {
+    get_recursive_bug_count(input, Rules::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCAN: &str = r#"....#
+#..#.
+#..##
+..#..
+#...."#;
+
+    fn get_next_minute(input: &str) -> String {
+        let mut eris = Eris::new(get_scan(input).unwrap(), Topology::Flat, Rules::ERIS);
+        eris.step();
+
+        eris.to_string()
+    }
+
+    #[test]
+    fn test_one_minute() {
+        assert_eq!(
+            get_next_minute(SCAN),
+            r#"#..#.
+####.
+###.#
+##.##
+.##.."#
+        );
+    }
+
+    #[test]
+    fn test_two_minutes() {
+        let input = r#"#..#.
+####.
+###.#
+##.##
+.##.."#;
+        assert_eq!(
+            get_next_minute(input),
+            r#"#####
+....#
+....#
+...#.
+#.###"#
+        );
+    }
+
+    #[test]
+    fn test_three_minutes() {
+        let input = r#"#####
+....#
+....#
+...#.
+#.###"#;
+        assert_eq!(
+            get_next_minute(input),
+            r#"#....
+####.
+...##
+#.##.
+.##.#"#
+        );
+    }
+
+    #[test]
+    fn test_four_minutes() {
+        let input = r#"#....
+####.
+...##
+#.##.
+.##.#"#;
+        assert_eq!(
+            get_next_minute(input),
+            r#"####.
+....#
+##..#
+.....
+##..."#
+        );
+    }
+
+    #[test]
+    fn test_biodiversity_rating() {
+        let input = r#".....
+.....
+.....
+#....
+.#...
+"#;
+        let eris = Eris::new(get_scan(input).unwrap(), Topology::Flat, Rules::ERIS);
+        assert_eq!(2129920, eris.biodiversity());
+    }
+
+    #[test]
+    fn test_first_repeat() {
+        let eris = Eris::new(get_scan(SCAN).unwrap(), Topology::Flat, Rules::ERIS);
+        let repeat = eris.first_repeat(usize::MAX).unwrap();
+
+        assert_eq!(2129920, repeat.eris.biodiversity());
+        assert_eq!(None, eris.first_repeat(repeat.first + repeat.period - 1));
+    }
+
+    #[test]
+    fn test_recursive_grid() {
+        let scan = get_scan(SCAN).unwrap();
+        let mut eris = Eris::new(scan, Topology::Recursive { depth: 6 }, Rules::ERIS);
+        eris.run(10);
+
+        assert_eq!(99, eris.bugs());
+        assert!(eris.to_string().starts_with(
+            r#"Depth -5:
+..#..
+.#.#.
+..?.#
+.#.#.
+..#.."#
+        ));
+    }
+
+    #[test]
+    fn test_spontaneous_rules() {
+        let rules = "B01/S1".parse::<Rules>().unwrap();
+
+        assert_eq!(
+            Err(Error::invalid_input(
+                "B01/S1 breeds bugs on infinitely many levels"
+            )),
+            get_recursive_bug_count(SCAN, rules),
+        );
+    }
+}
@@ -0,0 +1,265 @@
+use std::collections::HashMap;
+use std::io::{self, Error};
+use std::str::FromStr;
+
+use aoc_search::{bfs, State};
+use intcode::{Intcode, State as IntcodeState};
+
+use crate::room::{Direction, Report, Room, Weight};
+
+mod room;
+
+/// Items that end the game, or leave the droid unable to move, when
+/// taken.
+const DEADLY: [&str; 5] = [
+    "escape pod",
+    "giant electromagnet",
+    "infinite loop",
+    "molten lava",
+    "photons",
+];
+
+/// The rooms explored so far and the doors between them, along with the
+/// door onto the pressure-sensitive floor once it's found; the floor
+/// itself is left off the map as the droid can't stay there.
+#[derive(Debug, Default)]
+struct Ship {
+    doors: HashMap<String, HashMap<Direction, String>>,
+    floor: Option<(String, Direction)>,
+}
+
+impl Ship {
+    fn connect(&mut self, from: &str, direction: Direction, to: &str) {
+        self.doors
+            .entry(from.to_string())
+            .or_default()
+            .insert(direction, to.to_string());
+        self.doors
+            .entry(to.to_string())
+            .or_default()
+            .insert(direction.opposite(), from.to_string());
+    }
+
+    /// The doors to go through to get from `from` to the checkpoint next
+    /// to the pressure-sensitive floor.
+    fn route(&self, from: &str) -> Option<Vec<Direction>> {
+        let path = bfs(Location(from.to_string()), self).path?;
+
+        path.states
+            .windows(2)
+            .map(|pair| {
+                self.doors
+                    .get(&pair[0].0)?
+                    .iter()
+                    .find(|(_, room)| **room == pair[1].0)
+                    .map(|(direction, _)| *direction)
+            })
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct Location(String);
+
+impl State for Location {
+    type Context = Ship;
+
+    fn successors(&self, ship: &Ship) -> Vec<(Self, usize)> {
+        ship.doors
+            .get(&self.0)
+            .map(|doors| {
+                doors
+                    .values()
+                    .map(|room| (Location(room.clone()), 1))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn is_goal(&self, ship: &Ship) -> bool {
+        ship.floor
+            .as_ref()
+            .is_some_and(|(checkpoint, _)| self.0 == *checkpoint)
+    }
+}
+
+struct Droid {
+    intcode: Intcode,
+}
+
+impl Droid {
+    fn new(input: &str) -> Self {
+        Droid {
+            intcode: Intcode::from_str(input).unwrap(),
+        }
+    }
+
+    fn read_input(&self) -> Result<String, Error> {
+        let mut buffer = String::new();
+        io::stdin().read_line(&mut buffer)?;
+        Ok(buffer)
+    }
+
+    fn run(&mut self) {
+        loop {
+            match self.intcode.run().unwrap() {
+                IntcodeState::Output(output) => print!("{}", char::from(output as u8)),
+                IntcodeState::NeedsInput => {
+                    let input = self.read_input().unwrap();
+                    self.intcode.push_ascii(input.as_str());
+                }
+                IntcodeState::Halted => break,
+            }
+        }
+    }
+
+    /// Everything the droid says until it next waits for a command.
+    fn listen(&mut self) -> Report {
+        let output = self
+            .intcode
+            .collect_outputs()
+            .unwrap()
+            .into_iter()
+            .map(|output| char::from(output as u8))
+            .collect::<String>();
+
+        Report::parse(&output)
+    }
+
+    fn command(&mut self, command: &str) -> Report {
+        self.intcode.push_ascii(command);
+        self.intcode.push_ascii("\n");
+
+        self.listen()
+    }
+
+    /// Goes through every door not yet on the `ship`'s map, depth first,
+    /// picking up every item that isn't deadly, and comes back to `room`.
+    fn explore(&mut self, room: &Room, ship: &mut Ship, inventory: &mut Vec<String>) -> Option<()> {
+        for item in room.items.iter() {
+            if !DEADLY.contains(&item.as_str()) {
+                self.command(&format!("take {}", item));
+                inventory.push(item.clone());
+            }
+        }
+
+        for &direction in room.doors.iter() {
+            let explored = ship
+                .doors
+                .get(&room.name)
+                .is_some_and(|doors| doors.contains_key(&direction));
+            let ejected = ship.floor == Some((room.name.clone(), direction));
+            if explored || ejected {
+                continue;
+            }
+
+            let report = self.command(&direction.to_string());
+            let next = report.rooms.first()?;
+            if report.rooms.last()?.name == room.name {
+                ship.floor = Some((room.name.clone(), direction));
+                continue;
+            }
+
+            let seen = ship.doors.contains_key(&next.name);
+            ship.connect(&room.name, direction, &next.name);
+            if !seen {
+                self.explore(next, ship, inventory)?;
+            }
+            self.command(&direction.opposite().to_string());
+        }
+
+        Some(())
+    }
+
+    /// Tries each combination of `items`, all of which are held to begin
+    /// with, on the floor through `direction`, giving the password once
+    /// one is accepted.
+    ///
+    /// Combinations follow a Gray code so only one item is taken or
+    /// dropped between attempts, skipping any known to be too heavy or
+    /// too light from those already tried.
+    fn crack(&mut self, items: &[String], direction: Direction) -> Option<String> {
+        let all = (1u32 << items.len()) - 1;
+        let mut held = all;
+        let (mut too_heavy, mut too_light) = (Vec::new(), Vec::new());
+
+        for i in 0..=all {
+            let combination = all ^ (i ^ (i >> 1));
+            for (bit, item) in items.iter().enumerate() {
+                let mask = 1 << bit;
+                match (held & mask != 0, combination & mask != 0) {
+                    (false, true) => self.command(&format!("take {}", item)),
+                    (true, false) => self.command(&format!("drop {}", item)),
+                    _ => continue,
+                };
+            }
+            held = combination;
+
+            if too_heavy.iter().any(|heavy| combination & heavy == *heavy)
+                || too_light
+                    .iter()
+                    .any(|light| combination & light == combination)
+            {
+                continue;
+            }
+            let report = self.command(&direction.to_string());
+            if report.password.is_some() {
+                return report.password;
+            }
+            match report.alert? {
+                Weight::TooHeavy => too_heavy.push(combination),
+                Weight::TooLight => too_light.push(combination),
+            }
+        }
+
+        None
+    }
+}
+
+/// Maps the ship, collecting everything safe on the way, then heads for
+/// the checkpoint to work out which items get past the floor.
+fn get_password(input: &str) -> Option<String> {
+    let mut droid = Droid::new(input);
+    let start = droid.listen().rooms.pop()?;
+
+    let mut ship = Ship::default();
+    ship.doors.insert(start.name.clone(), HashMap::new());
+    let mut inventory = Vec::new();
+    droid.explore(&start, &mut ship, &mut inventory)?;
+
+    for direction in ship.route(&start.name)? {
+        droid.command(&direction.to_string());
+    }
+    let (_, direction) = ship.floor.clone()?;
+
+    droid.crack(&inventory, direction)
+}
+
+/// Hands the droid over to stdin and stdout.
+pub fn play(input: &str) {
+    Droid::new(input.trim()).run();
+}
+
+pub fn get_part_one(input: &str) -> Result<String, String> {
+    get_password(input.trim()).ok_or_else(|| String::from("Couldn't get past the floor"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_route() {
+        let mut ship = Ship::default();
+        ship.connect("Hull Breach", Direction::North, "Stables");
+        ship.connect("Stables", Direction::West, "Kitchen");
+        ship.connect("Hull Breach", Direction::South, "Arcade");
+        ship.connect("Kitchen", Direction::North, "Security Checkpoint");
+        ship.floor = Some((String::from("Security Checkpoint"), Direction::West));
+
+        assert_eq!(
+            Some(vec![Direction::North, Direction::West, Direction::North]),
+            ship.route("Hull Breach"),
+        );
+    }
+}
@@ -0,0 +1,192 @@
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    South,
+    East,
+    West,
+}
+
+impl Direction {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "north" => Some(Direction::North),
+            "south" => Some(Direction::South),
+            "east" => Some(Direction::East),
+            "west" => Some(Direction::West),
+            _ => None,
+        }
+    }
+
+    pub fn opposite(&self) -> Self {
+        match self {
+            Direction::North => Direction::South,
+            Direction::South => Direction::North,
+            Direction::East => Direction::West,
+            Direction::West => Direction::East,
+        }
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Direction::North => "north",
+            Direction::South => "south",
+            Direction::East => "east",
+            Direction::West => "west",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Room {
+    pub name: String,
+    pub doors: Vec<Direction>,
+    pub items: Vec<String>,
+}
+
+/// How the pressure-sensitive floor judged the droid.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Weight {
+    TooLight,
+    TooHeavy,
+}
+
+/// What the droid says in response to a command.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Report {
+    /// Every room described, in order; more than one if the droid was
+    /// thrown back out of where it went.
+    pub rooms: Vec<Room>,
+    pub alert: Option<Weight>,
+    pub password: Option<String>,
+}
+
+impl Report {
+    pub fn parse(output: &str) -> Self {
+        let mut report = Report::default();
+        let mut doors = false;
+
+        for line in output.lines().map(str::trim) {
+            if let Some(name) = line
+                .strip_prefix("== ")
+                .and_then(|line| line.strip_suffix(" =="))
+            {
+                report.rooms.push(Room {
+                    name: name.to_string(),
+                    ..Room::default()
+                });
+            } else if line == "Doors here lead:" {
+                doors = true;
+            } else if line == "Items here:" {
+                doors = false;
+            } else if let (Some(entry), Some(room)) =
+                (line.strip_prefix("- "), report.rooms.last_mut())
+            {
+                match (doors, Direction::from_name(entry)) {
+                    (true, Some(direction)) => room.doors.push(direction),
+                    _ => room.items.push(entry.to_string()),
+                }
+            } else if line.contains("heavier than the detected value") {
+                report.alert = Some(Weight::TooLight);
+            } else if line.contains("lighter than the detected value") {
+                report.alert = Some(Weight::TooHeavy);
+            } else if let Some((_, rest)) = line.split_once("typing ") {
+                report.password = rest
+                    .split_whitespace()
+                    .next()
+                    .filter(|password| password.chars().all(|c| c.is_ascii_digit()))
+                    .map(str::to_string);
+            }
+        }
+
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_room() {
+        let output = r#"
+
+== Hull Breach ==
+You got in through a hole in the floor here. To keep your ship from also freezing, the hole has been sealed.
+
+Doors here lead:
+- north
+- west
+
+Items here:
+- mug
+- infinite loop
+
+Command?
+"#;
+
+        assert_eq!(
+            Report {
+                rooms: vec![Room {
+                    name: String::from("Hull Breach"),
+                    doors: vec![Direction::North, Direction::West],
+                    items: vec![String::from("mug"), String::from("infinite loop")],
+                }],
+                alert: None,
+                password: None,
+            },
+            Report::parse(output),
+        );
+    }
+
+    #[test]
+    fn test_floor() {
+        let ejected = r#"
+
+== Pressure-Sensitive Floor ==
+Analyzing...
+
+Doors here lead:
+- south
+
+A loud, robotic voice says "Alert! Droids on this ship are lighter than the detected value!" and you are ejected back to the checkpoint.
+
+
+
+== Security Checkpoint ==
+In the next room, a pressure-sensitive floor will verify your identity.
+
+Doors here lead:
+- north
+- south
+
+Command?
+"#;
+        let report = Report::parse(ejected);
+
+        assert_eq!(
+            vec!["Pressure-Sensitive Floor", "Security Checkpoint"],
+            report
+                .rooms
+                .iter()
+                .map(|room| room.name.as_str())
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(Some(Weight::TooHeavy), report.alert);
+
+        let accepted = r#"A loud, robotic voice says "Analysis complete! You may proceed." and you enter the cockpit.
+Santa notices your small droid, looks puzzled for a moment, realizes what has happened, and radios your ship directly.
+"Oh, hello! You should be able to get in by typing 2424308736 on the keypad at the main airlock."
+"#;
+
+        assert_eq!(
+            Some(String::from("2424308736")),
+            Report::parse(accepted).password
+        );
+    }
+}
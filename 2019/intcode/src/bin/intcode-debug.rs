@@ -0,0 +1,241 @@
+use std::env;
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::process::ExitCode;
+use std::str::FromStr;
+
+use intcode::debug::{Debugger, Event};
+use intcode::disassemble::{Disassembly, Line};
+use intcode::{Intcode, IntcodeError, State};
+
+const HELP: &str = "\
+s [n]             step n instructions (default 1)
+c                 continue until a breakpoint, watch, input or halt
+b <address>       toggle a breakpoint
+w <address>       toggle a watch on a memory cell
+m <address> [n]   show n memory cells (default 1)
+r                 show the registers, breakpoints and watches
+l [address] [n]   disassemble n instructions (default: 10 from the current one)
+t [n]             show the last n executed instructions; sets the trace length
+i <text>          queue a line of ASCII input
+n <number>        queue a number as input
+h                 show this help
+q                 quit";
+
+enum Command {
+    Step(usize),
+    Continue,
+    Breakpoint(usize),
+    Watch(usize),
+    Memory(usize, usize),
+    Registers,
+    List(Option<usize>, usize),
+    Trace(usize),
+    Ascii(String),
+    Number(i64),
+    Help,
+    Quit,
+}
+
+impl FromStr for Command {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (command, rest) = s.trim().split_once(' ').unwrap_or((s.trim(), ""));
+        let arguments = rest
+            .split_whitespace()
+            .map(|argument| {
+                argument
+                    .parse::<usize>()
+                    .map_err(|_| format!("Expected a number, not {}", argument))
+            })
+            .collect::<Result<Vec<_>, _>>();
+        let address = || -> Result<usize, String> {
+            arguments
+                .clone()?
+                .first()
+                .copied()
+                .ok_or_else(|| "Expected an address".to_owned())
+        };
+
+        match command {
+            "s" => Ok(Command::Step(arguments?.first().copied().unwrap_or(1))),
+            "c" => Ok(Command::Continue),
+            "b" => Ok(Command::Breakpoint(address()?)),
+            "w" => Ok(Command::Watch(address()?)),
+            "m" => Ok(Command::Memory(
+                address()?,
+                arguments?.get(1).copied().unwrap_or(1),
+            )),
+            "r" => Ok(Command::Registers),
+            "l" => {
+                let arguments = arguments?;
+                Ok(Command::List(
+                    arguments.first().copied(),
+                    arguments.get(1).copied().unwrap_or(10),
+                ))
+            }
+            "t" => Ok(Command::Trace(arguments?.first().copied().unwrap_or(20))),
+            "i" => Ok(Command::Ascii(format!("{}\n", rest))),
+            "n" => rest
+                .trim()
+                .parse()
+                .map(Command::Number)
+                .map_err(|_| format!("Expected a number, not {}", rest)),
+            "h" | "?" => Ok(Command::Help),
+            "q" => Ok(Command::Quit),
+            _ => Err(format!("Unknown command {}; h for help", command)),
+        }
+    }
+}
+
+fn print_output(output: i64) {
+    match u8::try_from(output) {
+        Ok(byte) if byte.is_ascii_graphic() || byte.is_ascii_whitespace() => {
+            print!("{}", char::from(byte))
+        }
+        _ => println!("<{}>", output),
+    }
+}
+
+/// Runs to the next stop, printing any output along the way rather than
+/// stopping for it.
+fn resume(debugger: &mut Debugger) -> Result<Event, IntcodeError> {
+    loop {
+        match debugger.resume()? {
+            Event::State(State::Output(output)) => print_output(output),
+            event => return Ok(event),
+        }
+    }
+}
+
+fn execute(debugger: &mut Debugger, command: Command) -> Result<(), IntcodeError> {
+    match command {
+        Command::Step(count) => {
+            for _ in 0..count {
+                let current = debugger.current();
+                println!("{:>6}: {}", current.address(), current);
+                match debugger.step()? {
+                    Some(Event::State(State::Output(output))) => print_output(output),
+                    Some(event) => {
+                        println!("Stopped: {}", event);
+                        break;
+                    }
+                    None => {}
+                }
+            }
+        }
+        Command::Continue => println!("Stopped: {}", resume(debugger)?),
+        Command::Breakpoint(address) => match debugger.toggle_breakpoint(address) {
+            true => println!("Breakpoint set at {}", address),
+            false => println!("Breakpoint cleared at {}", address),
+        },
+        Command::Watch(address) => match debugger.toggle_watch(address) {
+            true => println!("Watching [{}]", address),
+            false => println!("No longer watching [{}]", address),
+        },
+        Command::Memory(address, count) => {
+            // Past the end of memory everything reads as zero.
+            let end = address
+                .saturating_add(count)
+                .min(debugger.intcode().memory().len());
+            for address in address..end {
+                println!("[{}] = {}", address, debugger.intcode().read(address));
+            }
+        }
+        Command::Registers => {
+            let intcode = debugger.intcode();
+            println!("ip = {}", intcode.position());
+            println!("rb = {}", intcode.relative_base());
+            println!("steps = {}", debugger.steps());
+            println!("inputs = {:?}", intcode.inputs());
+            println!("breakpoints = {:?}", debugger.breakpoints());
+            println!("watches = {:?}", debugger.watches());
+        }
+        Command::List(address, count) => {
+            let memory = debugger.intcode().memory();
+            let mut address = address.unwrap_or(debugger.intcode().position());
+            for _ in 0..count {
+                if address >= memory.len() {
+                    break;
+                }
+                let line = Line::decode(memory, address);
+                println!("{:>6}: {}", address, line);
+                address += line.width();
+            }
+        }
+        Command::Trace(count) => {
+            debugger.set_trace_limit(count);
+            for step in debugger.trace() {
+                println!("{}", step);
+            }
+        }
+        Command::Ascii(text) => debugger.intcode_mut().push_ascii(&text),
+        Command::Number(number) => debugger.intcode_mut().push_input(number),
+        Command::Help => println!("{}", HELP),
+        Command::Quit => {}
+    }
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    let (path, disassemble) = match &args[..] {
+        [flag, path] if flag == "--disassemble" => (path, true),
+        [path] => (path, false),
+        _ => {
+            eprintln!("Usage: intcode-debug [--disassemble] <program>");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let intcode = match fs::read_to_string(path)
+        .map_err(|error| error.to_string())
+        .and_then(|program| Intcode::from_str(&program).map_err(|error| format!("{:?}", error)))
+    {
+        Ok(intcode) => intcode,
+        Err(error) => {
+            eprintln!("Error loading {}: {}", path, error);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if disassemble {
+        print!("{}", Disassembly::new(intcode.memory()));
+        return ExitCode::SUCCESS;
+    }
+
+    let mut debugger = Debugger::new(intcode);
+    debugger.set_trace_limit(20);
+    let stdin = io::stdin();
+    loop {
+        print!("(intcode {}) ", debugger.intcode().position());
+        if io::stdout().flush().is_err() {
+            return ExitCode::FAILURE;
+        }
+
+        let mut line = String::new();
+        match stdin.lock().read_line(&mut line) {
+            Ok(0) => return ExitCode::SUCCESS,
+            Ok(_) => {}
+            Err(error) => {
+                eprintln!("{}", error);
+                return ExitCode::FAILURE;
+            }
+        }
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        match Command::from_str(&line) {
+            Ok(Command::Quit) => return ExitCode::SUCCESS,
+            Ok(command) => {
+                if let Err(error) = execute(&mut debugger, command) {
+                    println!("Error: {:?}", error);
+                }
+            }
+            Err(error) => println!("{}", error),
+        }
+    }
+}
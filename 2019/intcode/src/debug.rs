@@ -0,0 +1,284 @@
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::fmt;
+
+use crate::disassemble::Line;
+use crate::{Intcode, IntcodeError, State};
+
+/// One executed instruction, as it looked just before it ran.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Step {
+    pub count: usize,
+    pub relative_base: i64,
+    pub line: Line,
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "#{} {}: {} (rb={})",
+            self.count,
+            self.line.address(),
+            self.line,
+            self.relative_base,
+        )
+    }
+}
+
+/// Why the debugger handed control back.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Event {
+    Breakpoint(usize),
+    Watch { address: usize, old: i64, new: i64 },
+    State(State),
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Event::Breakpoint(address) => write!(f, "breakpoint at {}", address),
+            Event::Watch { address, old, new } => {
+                write!(f, "[{}] changed from {} to {}", address, old, new)
+            }
+            Event::State(State::Halted) => write!(f, "halted"),
+            Event::State(State::NeedsInput) => write!(f, "waiting for input"),
+            Event::State(State::Output(output)) => write!(f, "output {}", output),
+        }
+    }
+}
+
+/// Runs an `Intcode` computer one instruction at a time, stopping at
+/// breakpoints and whenever a watched memory cell changes.
+#[derive(Clone, Debug)]
+pub struct Debugger {
+    intcode: Intcode,
+    breakpoints: BTreeSet<usize>,
+    watches: BTreeSet<usize>,
+    trace: VecDeque<Step>,
+    trace_limit: usize,
+    steps: usize,
+    /// The breakpoint last stopped at, which resuming mustn't stop at again.
+    stopped_at: Option<usize>,
+}
+
+impl Debugger {
+    pub fn new(intcode: Intcode) -> Self {
+        Debugger {
+            intcode,
+            breakpoints: BTreeSet::new(),
+            watches: BTreeSet::new(),
+            trace: VecDeque::new(),
+            trace_limit: 0,
+            steps: 0,
+            stopped_at: None,
+        }
+    }
+
+    pub fn intcode(&self) -> &Intcode {
+        &self.intcode
+    }
+
+    /// For poking at memory or queueing input between steps.
+    pub fn intcode_mut(&mut self) -> &mut Intcode {
+        &mut self.intcode
+    }
+
+    pub fn into_intcode(self) -> Intcode {
+        self.intcode
+    }
+
+    /// How many instructions have been executed so far.
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    pub fn breakpoints(&self) -> &BTreeSet<usize> {
+        &self.breakpoints
+    }
+
+    /// Toggles a breakpoint, returning whether it's now set.
+    pub fn toggle_breakpoint(&mut self, address: usize) -> bool {
+        if self.breakpoints.remove(&address) {
+            false
+        } else {
+            self.breakpoints.insert(address)
+        }
+    }
+
+    /// The current value of every watched cell.
+    pub fn watches(&self) -> BTreeMap<usize, i64> {
+        self.watches
+            .iter()
+            .map(|address| (*address, self.intcode.read(*address)))
+            .collect()
+    }
+
+    /// Toggles a watch, returning whether it's now set.
+    pub fn toggle_watch(&mut self, address: usize) -> bool {
+        if self.watches.remove(&address) {
+            false
+        } else {
+            self.watches.insert(address)
+        }
+    }
+
+    /// Keeps the last `limit` executed instructions; `0` turns tracing off.
+    pub fn set_trace_limit(&mut self, limit: usize) {
+        self.trace_limit = limit;
+        while self.trace.len() > limit {
+            self.trace.pop_front();
+        }
+    }
+
+    /// The most recently executed instructions, oldest first.
+    pub fn trace(&self) -> &VecDeque<Step> {
+        &self.trace
+    }
+
+    /// The instruction about to be executed.
+    pub fn current(&self) -> Line {
+        Line::decode(self.intcode.memory(), self.intcode.position())
+    }
+
+    /// Executes a single instruction, ignoring any breakpoint on it.
+    pub fn step(&mut self) -> Result<Option<Event>, IntcodeError> {
+        let before = self.watches();
+        let step = Step {
+            count: self.steps,
+            relative_base: self.intcode.relative_base(),
+            line: self.current(),
+        };
+
+        let state = self.intcode.step()?;
+        if let Some(State::Halted | State::NeedsInput) = state {
+            return Ok(state.map(Event::State));
+        }
+
+        self.steps += 1;
+        if self.trace_limit > 0 {
+            if self.trace.len() == self.trace_limit {
+                self.trace.pop_front();
+            }
+            self.trace.push_back(step);
+        }
+
+        let changed = before
+            .into_iter()
+            .map(|(address, old)| (address, old, self.intcode.read(address)))
+            .find(|(_, old, new)| old != new);
+        if let Some((address, old, new)) = changed {
+            return Ok(Some(Event::Watch { address, old, new }));
+        }
+
+        Ok(state.map(Event::State))
+    }
+
+    /// Runs until a breakpoint, a watch, an output, or the program halting
+    /// or needing input. Resuming from a breakpoint steps over it.
+    pub fn resume(&mut self) -> Result<Event, IntcodeError> {
+        loop {
+            let position = self.intcode.position();
+            if self.breakpoints.contains(&position) && self.stopped_at != Some(position) {
+                self.stopped_at = Some(position);
+                return Ok(Event::Breakpoint(position));
+            }
+            self.stopped_at = None;
+
+            if let Some(event) = self.step()? {
+                return Ok(event);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    const COUNTDOWN: &str = "1101,0,3,100,4,100,1001,100,-1,100,1005,100,4,99";
+
+    fn get_debugger() -> Result<Debugger, IntcodeError> {
+        Ok(Debugger::new(Intcode::from_str(COUNTDOWN)?))
+    }
+
+    #[test]
+    fn test_breakpoint() -> Result<(), IntcodeError> {
+        let mut debugger = get_debugger()?;
+        debugger.toggle_breakpoint(6);
+
+        assert_eq!(Ok(Event::State(State::Output(3))), debugger.resume());
+        assert_eq!(Ok(Event::Breakpoint(6)), debugger.resume());
+        assert_eq!(Ok(Event::State(State::Output(2))), debugger.resume());
+        assert_eq!(Ok(Event::Breakpoint(6)), debugger.resume());
+
+        assert!(!debugger.toggle_breakpoint(6));
+        assert_eq!(Ok(Event::State(State::Output(1))), debugger.resume());
+        assert_eq!(Ok(Event::State(State::Halted)), debugger.resume());
+        assert_eq!(10, debugger.steps());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_watch() -> Result<(), IntcodeError> {
+        let mut debugger = get_debugger()?;
+        debugger.toggle_watch(100);
+
+        assert_eq!(
+            Ok(Event::Watch {
+                address: 100,
+                old: 0,
+                new: 3
+            }),
+            debugger.resume(),
+        );
+        assert_eq!(Ok(Event::State(State::Output(3))), debugger.resume());
+        assert_eq!(
+            Ok(Event::Watch {
+                address: 100,
+                old: 3,
+                new: 2
+            }),
+            debugger.resume(),
+        );
+        assert_eq!(BTreeMap::from([(100, 2)]), debugger.watches());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_trace() -> Result<(), IntcodeError> {
+        let mut debugger = get_debugger()?;
+        debugger.set_trace_limit(2);
+        while debugger.resume()? != Event::State(State::Halted) {}
+
+        assert_eq!(
+            vec![
+                "#8 6: add [100], -1, [100] (rb=0)",
+                "#9 10: jt  [100], 4  ; -> 4 (rb=0)",
+            ],
+            debugger
+                .trace()
+                .iter()
+                .map(Step::to_string)
+                .collect::<Vec<_>>(),
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_needs_input() -> Result<(), IntcodeError> {
+        let mut debugger = Debugger::new(Intcode::from_str("3,0,4,0,99")?);
+
+        assert_eq!(Ok(Event::State(State::NeedsInput)), debugger.resume());
+        assert_eq!(0, debugger.steps());
+
+        debugger.intcode_mut().push_input(7);
+        assert_eq!(Ok(Event::State(State::Output(7))), debugger.resume());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,240 @@
+use std::collections::BTreeSet;
+use std::fmt;
+
+use crate::{Instruction, ParameterMode};
+
+impl Instruction {
+    pub fn mnemonic(&self) -> &'static str {
+        match self {
+            Instruction::Add(..) => "add",
+            Instruction::Multiply(..) => "mul",
+            Instruction::Input(_) => "in",
+            Instruction::Output(_) => "out",
+            Instruction::JumpIfTrue(..) => "jt",
+            Instruction::JumpIfFalse(..) => "jf",
+            Instruction::LessThan(..) => "lt",
+            Instruction::Equals(..) => "eq",
+            Instruction::AdjustRelativeBase(_) => "arb",
+            Instruction::Halt => "hlt",
+        }
+    }
+
+    /// Whether the instruction's last parameter is where it may jump to.
+    pub fn is_jump(&self) -> bool {
+        matches!(
+            self,
+            Instruction::JumpIfTrue(..) | Instruction::JumpIfFalse(..)
+        )
+    }
+}
+
+/// A single parameter, as written in the program.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Parameter {
+    pub mode: ParameterMode,
+    pub value: i64,
+}
+
+impl fmt::Display for Parameter {
+    /// Immediate values are bare, positions are `[n]` and relative
+    /// positions are `[rb+n]`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.mode {
+            ParameterMode::Immediate => write!(f, "{}", self.value),
+            ParameterMode::Position => write!(f, "[{}]", self.value),
+            ParameterMode::Relative if self.value < 0 => write!(f, "[rb{}]", self.value),
+            ParameterMode::Relative => write!(f, "[rb+{}]", self.value),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Line {
+    Instruction {
+        address: usize,
+        instruction: Instruction,
+        parameters: Vec<Parameter>,
+    },
+    /// A cell that doesn't decode as an instruction.
+    Data { address: usize, value: i64 },
+}
+
+impl Line {
+    /// Decodes whatever is at `address`, falling back to data if it isn't
+    /// an instruction or its parameters run off the end of `memory`.
+    pub fn decode(memory: &[i64], address: usize) -> Line {
+        let value = memory.get(address).copied().unwrap_or(0);
+
+        match Instruction::try_from(value) {
+            Ok(instruction) if address + instruction.width() <= memory.len() => {
+                let parameters = instruction
+                    .modes()
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, mode)| Parameter {
+                        mode,
+                        value: memory[address + i + 1],
+                    })
+                    .collect();
+
+                Line::Instruction {
+                    address,
+                    instruction,
+                    parameters,
+                }
+            }
+            _ => Line::Data { address, value },
+        }
+    }
+
+    pub fn address(&self) -> usize {
+        match self {
+            Line::Instruction { address, .. } | Line::Data { address, .. } => *address,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        match self {
+            Line::Instruction { instruction, .. } => instruction.width(),
+            Line::Data { .. } => 1,
+        }
+    }
+
+    /// Where the line jumps to, if it's a jump with an immediate target.
+    pub fn jump_target(&self) -> Option<usize> {
+        match self {
+            Line::Instruction {
+                instruction,
+                parameters,
+                ..
+            } if instruction.is_jump() => parameters
+                .last()
+                .filter(|target| target.mode == ParameterMode::Immediate)
+                .and_then(|target| usize::try_from(target.value).ok()),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Line {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Line::Instruction {
+                instruction,
+                parameters,
+                ..
+            } if parameters.is_empty() => write!(f, "{}", instruction.mnemonic()),
+            Line::Instruction {
+                instruction,
+                parameters,
+                ..
+            } => {
+                let parameters = parameters
+                    .iter()
+                    .map(Parameter::to_string)
+                    .collect::<Vec<_>>();
+                write!(f, "{:<4}{}", instruction.mnemonic(), parameters.join(", "))?;
+                if let Some(target) = self.jump_target() {
+                    write!(f, "  ; -> {}", target)?;
+                }
+
+                Ok(())
+            }
+            Line::Data { value, .. } => write!(f, "data {}", value),
+        }
+    }
+}
+
+/// A linear sweep of a program into instructions.
+///
+/// Intcode doesn't separate code from data, so anything that happens to
+/// decode as an instruction is shown as one; in practice the data tends to
+/// sit after the last `hlt` and is easy to spot.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Disassembly {
+    pub lines: Vec<Line>,
+    /// The addresses jumped to by immediate-mode jumps.
+    pub targets: BTreeSet<usize>,
+}
+
+impl Disassembly {
+    pub fn new(memory: &[i64]) -> Self {
+        let mut lines = Vec::new();
+        let mut address = 0;
+        while address < memory.len() {
+            let line = Line::decode(memory, address);
+            address += line.width();
+            lines.push(line);
+        }
+
+        let targets = lines.iter().filter_map(Line::jump_target).collect();
+
+        Disassembly { lines, targets }
+    }
+}
+
+impl fmt::Display for Disassembly {
+    /// One line per instruction, with jump targets marked by a `>`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self
+            .lines
+            .last()
+            .map_or(1, |line| line.address().to_string().len());
+
+        for line in self.lines.iter() {
+            let marker = if self.targets.contains(&line.address()) {
+                '>'
+            } else {
+                ' '
+            };
+            writeln!(
+                f,
+                "{} {:>width$}: {}",
+                marker,
+                line.address(),
+                line,
+                width = width
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_disassemble() {
+        let program = [3, 9, 8, 9, 10, 9, 4, 9, 99, -1, 8];
+
+        assert_eq!(
+            "   0: in  [9]\n   2: eq  [9], [10], [9]\n   6: out [9]\n   8: hlt\n   9: data -1\n  10: data 8\n",
+            Disassembly::new(&program).to_string(),
+        );
+    }
+
+    #[test]
+    fn test_jump_targets() {
+        let program = [109, -3, 1105, 1, 7, 204, 2, 99];
+        let disassembly = Disassembly::new(&program);
+
+        assert_eq!(BTreeSet::from([7]), disassembly.targets);
+        assert_eq!(
+            "  0: arb -3\n  2: jt  1, 7  ; -> 7\n  5: out [rb+2]\n> 7: hlt\n",
+            disassembly.to_string(),
+        );
+    }
+
+    #[test]
+    fn test_truncated_instruction() {
+        assert_eq!(
+            vec![Line::Data {
+                address: 0,
+                value: 1101
+            }],
+            Disassembly::new(&[1101]).lines,
+        );
+    }
+}
@@ -0,0 +1,415 @@
+#![deny(clippy::expect_used, clippy::unwrap_used)]
+
+use std::collections::VecDeque;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+pub mod debug;
+pub mod disassemble;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum IntcodeError {
+    ParseIntError(ParseIntError),
+    InvalidInstruction(i64),
+    InvalidParameterMode(i64),
+    InvalidAddress(i64),
+    ImmediateWrite(usize),
+}
+
+impl From<ParseIntError> for IntcodeError {
+    fn from(error: ParseIntError) -> Self {
+        IntcodeError::ParseIntError(error)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParameterMode {
+    Position,
+    Immediate,
+    Relative,
+}
+
+impl TryFrom<i64> for ParameterMode {
+    type Error = IntcodeError;
+
+    fn try_from(mode: i64) -> Result<Self, Self::Error> {
+        match mode {
+            0 => Ok(ParameterMode::Position),
+            1 => Ok(ParameterMode::Immediate),
+            2 => Ok(ParameterMode::Relative),
+            _ => Err(IntcodeError::InvalidParameterMode(mode)),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Instruction {
+    Add(ParameterMode, ParameterMode, ParameterMode),
+    Multiply(ParameterMode, ParameterMode, ParameterMode),
+    Input(ParameterMode),
+    Output(ParameterMode),
+    JumpIfTrue(ParameterMode, ParameterMode),
+    JumpIfFalse(ParameterMode, ParameterMode),
+    LessThan(ParameterMode, ParameterMode, ParameterMode),
+    Equals(ParameterMode, ParameterMode, ParameterMode),
+    AdjustRelativeBase(ParameterMode),
+    Halt,
+}
+
+impl Instruction {
+    /// The parameter modes of this instruction, one per parameter.
+    pub fn modes(&self) -> Vec<ParameterMode> {
+        match *self {
+            Instruction::Add(a, b, c)
+            | Instruction::Multiply(a, b, c)
+            | Instruction::LessThan(a, b, c)
+            | Instruction::Equals(a, b, c) => vec![a, b, c],
+            Instruction::JumpIfTrue(a, b) | Instruction::JumpIfFalse(a, b) => vec![a, b],
+            Instruction::Input(a) | Instruction::Output(a) | Instruction::AdjustRelativeBase(a) => {
+                vec![a]
+            }
+            Instruction::Halt => vec![],
+        }
+    }
+
+    /// The number of memory cells occupied by the instruction and its
+    /// parameters.
+    pub fn width(&self) -> usize {
+        1 + self.modes().len()
+    }
+}
+
+impl TryFrom<i64> for Instruction {
+    type Error = IntcodeError;
+
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        if value < 0 {
+            return Err(IntcodeError::InvalidInstruction(value));
+        }
+        let mode = |n: u32| ParameterMode::try_from((value / 10_i64.pow(n + 1)) % 10);
+
+        let instruction = match value % 100 {
+            1 => Instruction::Add(mode(1)?, mode(2)?, mode(3)?),
+            2 => Instruction::Multiply(mode(1)?, mode(2)?, mode(3)?),
+            3 => Instruction::Input(mode(1)?),
+            4 => Instruction::Output(mode(1)?),
+            5 => Instruction::JumpIfTrue(mode(1)?, mode(2)?),
+            6 => Instruction::JumpIfFalse(mode(1)?, mode(2)?),
+            7 => Instruction::LessThan(mode(1)?, mode(2)?, mode(3)?),
+            8 => Instruction::Equals(mode(1)?, mode(2)?, mode(3)?),
+            9 => Instruction::AdjustRelativeBase(mode(1)?),
+            99 => Instruction::Halt,
+            _ => return Err(IntcodeError::InvalidInstruction(value)),
+        };
+
+        Ok(instruction)
+    }
+}
+
+/// Why the computer stopped running.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum State {
+    Halted,
+    NeedsInput,
+    Output(i64),
+}
+
+#[derive(Clone, Debug)]
+pub struct Intcode {
+    memory: Vec<i64>,
+    position: usize,
+    relative_base: i64,
+    inputs: VecDeque<i64>,
+}
+
+impl FromStr for Intcode {
+    type Err = IntcodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let memory = s
+            .trim()
+            .split(',')
+            .map(|value| value.trim().parse())
+            .collect::<Result<_, _>>()?;
+
+        Ok(Intcode::new(memory))
+    }
+}
+
+impl Intcode {
+    pub fn new(memory: Vec<i64>) -> Self {
+        Intcode {
+            memory,
+            position: 0,
+            relative_base: 0,
+            inputs: VecDeque::new(),
+        }
+    }
+
+    pub fn memory(&self) -> &[i64] {
+        &self.memory
+    }
+
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn relative_base(&self) -> i64 {
+        self.relative_base
+    }
+
+    pub fn inputs(&self) -> &VecDeque<i64> {
+        &self.inputs
+    }
+
+    /// Reads a single memory cell; anything beyond the end of the
+    /// program reads as `0`.
+    pub fn read(&self, address: usize) -> i64 {
+        self.memory.get(address).copied().unwrap_or(0)
+    }
+
+    /// Writes a single memory cell, growing memory as necessary.
+    pub fn write(&mut self, address: usize, value: i64) {
+        if address >= self.memory.len() {
+            self.memory.resize(address + 1, 0);
+        }
+        self.memory[address] = value;
+    }
+
+    pub fn push_input(&mut self, value: i64) {
+        self.inputs.push_back(value);
+    }
+
+    /// Queues each character of `input` as its ASCII code.
+    pub fn push_ascii(&mut self, input: &str) {
+        self.inputs.extend(input.bytes().map(i64::from));
+    }
+
+    /// Decodes the instruction at the current position.
+    pub fn instruction(&self) -> Result<Instruction, IntcodeError> {
+        Instruction::try_from(self.read(self.position))
+    }
+
+    fn address(&self, parameter: usize, mode: ParameterMode) -> Result<usize, IntcodeError> {
+        let value = self.read(self.position + parameter);
+        let address = match mode {
+            ParameterMode::Position => value,
+            ParameterMode::Relative => self.relative_base + value,
+            ParameterMode::Immediate => return Err(IntcodeError::ImmediateWrite(self.position)),
+        };
+
+        usize::try_from(address).map_err(|_| IntcodeError::InvalidAddress(address))
+    }
+
+    fn value(&self, parameter: usize, mode: ParameterMode) -> Result<i64, IntcodeError> {
+        match mode {
+            ParameterMode::Immediate => Ok(self.read(self.position + parameter)),
+            _ => Ok(self.read(self.address(parameter, mode)?)),
+        }
+    }
+
+    /// Executes a single instruction.
+    ///
+    /// Returns `None` if the computer can carry on; otherwise the
+    /// `State` that stopped it. Neither `Halted` nor `NeedsInput` move
+    /// the instruction pointer, so stepping again repeats the check.
+    pub fn step(&mut self) -> Result<Option<State>, IntcodeError> {
+        let instruction = self.instruction()?;
+
+        let state = match instruction {
+            Instruction::Add(a, b, c) => {
+                let value = self.value(1, a)? + self.value(2, b)?;
+                self.write(self.address(3, c)?, value);
+                None
+            }
+            Instruction::Multiply(a, b, c) => {
+                let value = self.value(1, a)? * self.value(2, b)?;
+                self.write(self.address(3, c)?, value);
+                None
+            }
+            Instruction::Input(a) => match self.inputs.pop_front() {
+                Some(value) => {
+                    self.write(self.address(1, a)?, value);
+                    None
+                }
+                None => return Ok(Some(State::NeedsInput)),
+            },
+            Instruction::Output(a) => Some(State::Output(self.value(1, a)?)),
+            Instruction::JumpIfTrue(a, b) => {
+                if self.value(1, a)? != 0 {
+                    self.position = self.jump_target(2, b)?;
+                    return Ok(None);
+                }
+                None
+            }
+            Instruction::JumpIfFalse(a, b) => {
+                if self.value(1, a)? == 0 {
+                    self.position = self.jump_target(2, b)?;
+                    return Ok(None);
+                }
+                None
+            }
+            Instruction::LessThan(a, b, c) => {
+                let value = i64::from(self.value(1, a)? < self.value(2, b)?);
+                self.write(self.address(3, c)?, value);
+                None
+            }
+            Instruction::Equals(a, b, c) => {
+                let value = i64::from(self.value(1, a)? == self.value(2, b)?);
+                self.write(self.address(3, c)?, value);
+                None
+            }
+            Instruction::AdjustRelativeBase(a) => {
+                self.relative_base += self.value(1, a)?;
+                None
+            }
+            Instruction::Halt => return Ok(Some(State::Halted)),
+        };
+        self.position += instruction.width();
+
+        Ok(state)
+    }
+
+    fn jump_target(&self, parameter: usize, mode: ParameterMode) -> Result<usize, IntcodeError> {
+        let target = self.value(parameter, mode)?;
+
+        usize::try_from(target).map_err(|_| IntcodeError::InvalidAddress(target))
+    }
+
+    /// Runs until the program produces an output, needs input or halts.
+    pub fn run(&mut self) -> Result<State, IntcodeError> {
+        loop {
+            if let Some(state) = self.step()? {
+                return Ok(state);
+            }
+        }
+    }
+
+    /// Runs until the program needs input or halts, collecting every
+    /// output along the way.
+    pub fn collect_outputs(&mut self) -> Result<Vec<i64>, IntcodeError> {
+        let mut outputs = Vec::new();
+        while let State::Output(output) = self.run()? {
+            outputs.push(output);
+        }
+
+        Ok(outputs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_final_memory(program: &str) -> Result<Vec<i64>, IntcodeError> {
+        let mut intcode = Intcode::from_str(program)?;
+        intcode.run()?;
+
+        Ok(intcode.memory().to_vec())
+    }
+
+    fn get_outputs(program: &str, inputs: &[i64]) -> Result<Vec<i64>, IntcodeError> {
+        let mut intcode = Intcode::from_str(program)?;
+        inputs.iter().for_each(|input| intcode.push_input(*input));
+
+        intcode.collect_outputs()
+    }
+
+    #[test]
+    fn test_instruction() {
+        assert_eq!(
+            Ok(Instruction::Multiply(
+                ParameterMode::Position,
+                ParameterMode::Immediate,
+                ParameterMode::Position,
+            )),
+            Instruction::try_from(1002),
+        );
+        assert_eq!(
+            Ok(Instruction::AdjustRelativeBase(ParameterMode::Relative)),
+            Instruction::try_from(209),
+        );
+        assert_eq!(Ok(Instruction::Halt), Instruction::try_from(99));
+        assert_eq!(
+            Err(IntcodeError::InvalidInstruction(42)),
+            Instruction::try_from(42),
+        );
+        assert_eq!(
+            Err(IntcodeError::InvalidParameterMode(3)),
+            Instruction::try_from(301),
+        );
+    }
+
+    #[test]
+    fn test_final_memory() {
+        assert_eq!(Ok(vec![2, 0, 0, 0, 99]), get_final_memory("1,0,0,0,99"));
+        assert_eq!(Ok(vec![2, 3, 0, 6, 99]), get_final_memory("2,3,0,3,99"));
+        assert_eq!(
+            Ok(vec![2, 4, 4, 5, 99, 9801]),
+            get_final_memory("2,4,4,5,99,0")
+        );
+        assert_eq!(
+            Ok(vec![30, 1, 1, 4, 2, 5, 6, 0, 99]),
+            get_final_memory("1,1,1,4,99,5,6,0,99"),
+        );
+        assert_eq!(
+            Ok(vec![1101, 100, -1, 4, 99]),
+            get_final_memory("1101,100,-1,4,0")
+        );
+    }
+
+    #[test]
+    fn test_comparisons() {
+        let program = "3,21,1008,21,8,20,1005,20,22,107,8,21,20,1006,20,31,1106,0,36,98,0,0,1002,21,125,20,4,20,1105,1,46,104,999,1105,1,46,1101,1000,1,20,4,20,1105,1,46,98,99";
+
+        assert_eq!(Ok(vec![999]), get_outputs(program, &[7]));
+        assert_eq!(Ok(vec![1000]), get_outputs(program, &[8]));
+        assert_eq!(Ok(vec![1001]), get_outputs(program, &[9]));
+    }
+
+    #[test]
+    fn test_relative_base() {
+        let program = "109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99";
+
+        assert_eq!(
+            program
+                .split(',')
+                .map(|value| value.parse())
+                .collect::<Result<Vec<i64>, _>>()
+                .map_err(IntcodeError::from),
+            get_outputs(program, &[]),
+        );
+        assert_eq!(
+            Ok(vec![1_219_070_632_396_864]),
+            get_outputs("1102,34915192,34915192,7,4,7,99,0", &[]),
+        );
+        assert_eq!(
+            Ok(vec![1_125_899_906_842_624]),
+            get_outputs("104,1125899906842624,99", &[]),
+        );
+    }
+
+    #[test]
+    fn test_needs_input() -> Result<(), IntcodeError> {
+        let mut intcode = Intcode::from_str("3,0,4,0,99")?;
+
+        assert_eq!(Ok(State::NeedsInput), intcode.run());
+        assert_eq!(0, intcode.position());
+
+        intcode.push_input(42);
+        assert_eq!(Ok(State::Output(42)), intcode.run());
+        assert_eq!(Ok(State::Halted), intcode.run());
+        assert_eq!(Ok(State::Halted), intcode.run());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_address() -> Result<(), IntcodeError> {
+        let mut intcode = Intcode::from_str("1,-1,0,0,99")?;
+
+        assert_eq!(Err(IntcodeError::InvalidAddress(-1)), intcode.run());
+
+        Ok(())
+    }
+}
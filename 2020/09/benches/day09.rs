@@ -2,7 +2,7 @@ use std::fs;
 
 use criterion::{criterion_group, criterion_main, Criterion};
 
-use ::day09::*;
+use ::aoc_2020_09::*;
 
 pub fn benchmark_get_first_invalid_number(c: &mut Criterion) {
     let input = fs::read_to_string("input.txt").expect("Error reading input.txt");
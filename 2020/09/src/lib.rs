@@ -43,6 +43,16 @@ pub fn get_encryption_weakness(numbers: &[usize], invalid_number: usize) -> usiz
     contiguous_set.iter().min().unwrap() + contiguous_set.iter().max().unwrap()
 }
 
+pub fn get_part_one(input: &str) -> usize {
+    get_first_invalid_number(&get_numbers(input), 25)
+}
+
+pub fn get_part_two(input: &str) -> usize {
+    let numbers = get_numbers(input);
+
+    get_encryption_weakness(&numbers, get_first_invalid_number(&numbers, 25))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
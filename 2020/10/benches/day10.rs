@@ -2,7 +2,7 @@ use std::fs;
 
 use criterion::{criterion_group, criterion_main, Criterion};
 
-use ::day10::*;
+use ::aoc_2020_10::*;
 
 pub fn benchmark_get_joltage_differences(c: &mut Criterion) {
     let input = fs::read_to_string("input.txt").expect("Error reading input.txt");
@@ -47,6 +47,19 @@ pub fn get_distinct_ways(joltages: &[usize]) -> usize {
     *total
 }
 
+pub fn get_part_one(input: &str) -> Result<usize, String> {
+    let differences = get_joltage_differences(&get_adapter_joltages(input));
+
+    match (differences.get(&1), differences.get(&3)) {
+        (Some(ones), Some(threes)) => Ok(ones * threes),
+        _ => Err(String::from("Missing 1-jolt or 3-jolt differences")),
+    }
+}
+
+pub fn get_part_two(input: &str) -> usize {
+    get_distinct_ways(&get_adapter_joltages(input))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
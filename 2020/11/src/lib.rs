@@ -1,20 +1,7 @@
-use std::collections::HashMap;
 use std::fmt::{Display, Formatter, Result as FmtResult};
 use std::str::FromStr;
 
-type Point = (usize, usize);
-type Direction = (isize, isize);
-
-const LINE_OF_SIGHT: [Direction; 8] = [
-    (0, -1),
-    (1, -1),
-    (1, 0),
-    (1, 1),
-    (0, 1),
-    (-1, 1),
-    (-1, 0),
-    (-1, -1),
-];
+use aoc_grid::{Grid, Point, SURROUNDING};
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 enum Position {
@@ -25,7 +12,7 @@ enum Position {
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct SeatLayout {
-    positions: HashMap<Point, Position>,
+    positions: Grid<Position>,
     tolerance: usize,
 }
 
@@ -33,27 +20,13 @@ impl FromStr for SeatLayout {
     type Err = ();
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let positions = s
-            .trim()
-            .lines()
-            .enumerate()
-            .flat_map(move |(y, line)| {
-                line.chars()
-                    .enumerate()
-                    .map(|(x, char)| {
-                        (
-                            (x, y),
-                            match char {
-                                '.' => Position::Floor,
-                                'L' => Position::EmptySeat,
-                                '#' => Position::OccupiedSeat,
-                                _ => panic!("Unexpected character: {}", char),
-                            },
-                        )
-                    })
-                    .collect::<Vec<(Point, Position)>>()
-            })
-            .collect();
+        let positions = Grid::parse(s.trim(), |char| match char {
+            '.' => Ok(Position::Floor),
+            'L' => Ok(Position::EmptySeat),
+            '#' => Ok(Position::OccupiedSeat),
+            _ => Err(format!("Unexpected character: {}", char)),
+        })
+        .map_err(|_| ())?;
 
         Ok(Self {
             positions,
@@ -64,20 +37,11 @@ impl FromStr for SeatLayout {
 
 impl Display for SeatLayout {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        let (max_x, max_y) = self.positions.keys().max().unwrap();
-
-        let output = (0..=*max_y)
-            .map(|y| {
-                (0..=*max_x)
-                    .map(|x| match self.positions.get(&(x, y)).unwrap() {
-                        Position::OccupiedSeat => '#',
-                        Position::EmptySeat => 'L',
-                        Position::Floor => '.',
-                    })
-                    .collect::<String>()
-            })
-            .collect::<Vec<String>>()
-            .join("\n");
+        let output = self.positions.map(|position| match position {
+            Position::OccupiedSeat => '#',
+            Position::EmptySeat => 'L',
+            Position::Floor => '.',
+        });
 
         write!(f, "{}", output)
     }
@@ -96,65 +60,34 @@ impl SeatLayout {
     }
 
     fn get_occupied_neighbour_count(&self, point: &Point) -> usize {
-        let (px, py) = point;
-
-        (py.saturating_sub(1)..=py + 1)
-            .map(|ny| {
-                (px.saturating_sub(1)..=px + 1)
-                    .map(move |nx| (nx, ny))
-                    .filter(|neighbour| *neighbour != *point)
-                    .filter(|neighbour| {
-                        matches!(self.positions.get(neighbour), Some(Position::OccupiedSeat))
-                    })
-                    .count()
-            })
-            .sum()
+        self.positions
+            .neighbours8(*point)
+            .filter(|neighbour| matches!(self.positions[*neighbour], Position::OccupiedSeat))
+            .count()
     }
 
     fn get_visible_neighbour_count(&self, point: &Point) -> usize {
-        let (px, py) = point;
-
-        LINE_OF_SIGHT
+        SURROUNDING
             .iter()
-            .map(|(los_x, los_y)| {
-                let mut visible_seats = 0;
-                let (mut view_x, mut view_y) = ((*px as isize + los_x), (*py as isize + los_y));
-                while view_x >= 0 && view_y >= 0 {
-                    match self.positions.get(&(view_x as usize, view_y as usize)) {
-                        Some(Position::OccupiedSeat) => {
-                            visible_seats = 1;
-                            break;
-                        }
-                        None | Some(Position::EmptySeat) => {
-                            break;
-                        }
-                        _ => {
-                            view_x += los_x;
-                            view_y += los_y;
-                        }
-                    }
-                }
-                visible_seats
+            .filter(|line_of_sight| {
+                self.positions
+                    .ray(*point, **line_of_sight)
+                    .map(|seat| self.positions[seat])
+                    .find(|position| *position != Position::Floor)
+                    == Some(Position::OccupiedSeat)
             })
-            .sum()
+            .count()
     }
 
-    fn get_next_layout(&self) -> SeatLayout {
-        let positions = self
-            .positions
-            .iter()
-            .map(|(point, &position)| match position {
-                Position::EmptySeat => match self.get_occupied_neighbour_count(point) {
-                    0 => (*point, Position::OccupiedSeat),
-                    _ => (*point, Position::EmptySeat),
-                },
-                Position::OccupiedSeat => match self.get_occupied_neighbour_count(point) {
-                    n if n >= self.tolerance => (*point, Position::EmptySeat),
-                    _ => (*point, Position::OccupiedSeat),
-                },
-                _ => (*point, position),
-            })
-            .collect();
+    fn get_next_layout_by(&self, count: impl Fn(&Point) -> usize) -> SeatLayout {
+        let mut positions = self.positions.clone();
+        for (point, &position) in self.positions.iter() {
+            positions[point] = match position {
+                Position::EmptySeat if count(&point) == 0 => Position::OccupiedSeat,
+                Position::OccupiedSeat if count(&point) >= self.tolerance => Position::EmptySeat,
+                _ => position,
+            };
+        }
 
         SeatLayout {
             positions,
@@ -162,27 +95,12 @@ impl SeatLayout {
         }
     }
 
-    fn get_next_visible_layout(&self) -> SeatLayout {
-        let positions = self
-            .positions
-            .iter()
-            .map(|(point, &position)| match position {
-                Position::EmptySeat => match self.get_visible_neighbour_count(point) {
-                    0 => (*point, Position::OccupiedSeat),
-                    _ => (*point, Position::EmptySeat),
-                },
-                Position::OccupiedSeat => match self.get_visible_neighbour_count(point) {
-                    n if n >= self.tolerance => (*point, Position::EmptySeat),
-                    _ => (*point, Position::OccupiedSeat),
-                },
-                _ => (*point, position),
-            })
-            .collect();
+    fn get_next_layout(&self) -> SeatLayout {
+        self.get_next_layout_by(|point| self.get_occupied_neighbour_count(point))
+    }
 
-        SeatLayout {
-            positions,
-            tolerance: self.tolerance,
-        }
+    fn get_next_visible_layout(&self) -> SeatLayout {
+        self.get_next_layout_by(|point| self.get_visible_neighbour_count(point))
     }
 }
 
@@ -191,8 +109,6 @@ pub fn get_stable_layout(seat_layout: &SeatLayout) -> SeatLayout {
     loop {
         let next = current.get_next_layout();
 
-        println!("{}\n", &current);
-
         if next == current {
             return next;
         }
@@ -205,8 +121,6 @@ pub fn get_correct_stable_layout(seat_layout: &SeatLayout) -> SeatLayout {
     loop {
         let next = current.get_next_visible_layout();
 
-        println!("{}\n", &current);
-
         if next == current {
             return next;
         }
@@ -214,6 +128,20 @@ pub fn get_correct_stable_layout(seat_layout: &SeatLayout) -> SeatLayout {
     }
 }
 
+pub fn get_part_one(input: &str) -> Result<usize, String> {
+    SeatLayout::from_str(input)
+        .map(|seat_layout| get_stable_layout(&seat_layout).get_occupied_seat_count())
+        .map_err(|_| String::from("Invalid seat layout"))
+}
+
+pub fn get_part_two(input: &str) -> Result<usize, String> {
+    let mut seat_layout =
+        SeatLayout::from_str(input).map_err(|_| String::from("Invalid seat layout"))?;
+    seat_layout.set_tolerance(5);
+
+    Ok(get_correct_stable_layout(&seat_layout).get_occupied_seat_count())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -252,10 +180,7 @@ L.LLLLL.LL"#;
 ...#....."#;
         let seat_layout = SeatLayout::from_str(&input).unwrap();
 
-        assert_eq!(
-            Position::EmptySeat,
-            *seat_layout.positions.get(&(3, 4)).unwrap(),
-        );
+        assert_eq!(Position::EmptySeat, seat_layout.positions[(3, 4)],);
         assert_eq!(8, seat_layout.get_visible_neighbour_count(&(3, 4)),);
     }
 
@@ -270,10 +195,7 @@ L.LLLLL.LL"#;
 .##.##."#;
         let seat_layout = SeatLayout::from_str(&input).unwrap();
 
-        assert_eq!(
-            Position::EmptySeat,
-            *seat_layout.positions.get(&(3, 3)).unwrap(),
-        );
+        assert_eq!(Position::EmptySeat, seat_layout.positions[(3, 3)],);
         assert_eq!(0, seat_layout.get_visible_neighbour_count(&(3, 3)),);
     }
 
@@ -105,6 +105,21 @@ impl Ferry {
     }
 }
 
+fn get_navigated_ferry(input: &str) -> Result<Ferry, String> {
+    let mut ferry = Ferry::from_str(input).map_err(|_| String::from("Invalid instructions"))?;
+    ferry.navigate();
+
+    Ok(ferry)
+}
+
+pub fn get_part_one(input: &str) -> Result<i32, String> {
+    get_navigated_ferry(input).map(|ferry| ferry.get_position_manhattan_distance())
+}
+
+pub fn get_part_two(input: &str) -> Result<i32, String> {
+    get_navigated_ferry(input).map(|ferry| ferry.get_bearing_manhattan_distance())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
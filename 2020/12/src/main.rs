@@ -1,24 +1,17 @@
 use std::fs;
-use std::str::FromStr;
 
-use ::day12::*;
+use ::aoc_2020_12::*;
 
 fn main() {
     let input = fs::read_to_string("input.txt").expect("Error reading input.txt");
 
-    let mut ferry = Ferry::from_str(&input).unwrap();
-    ferry.navigate();
-
     println!(
         "What is the Manhattan distance between that location and the ship's starting position? {}",
-        ferry.get_position_manhattan_distance(),
+        get_part_one(&input).unwrap(),
     );
 
-    let mut ferry = Ferry::from_str(&input).unwrap();
-    ferry.navigate();
-
     println!(
         "What is the Manhattan distance between that location and the ship's starting position? {}",
-        ferry.get_bearing_manhattan_distance(),
+        get_part_two(&input).unwrap(),
     );
 }
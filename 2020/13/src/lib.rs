@@ -1,6 +1,6 @@
 use std::str::FromStr;
 
-use ring_algorithm::chinese_remainder_theorem;
+use aoc_math::crt;
 
 pub struct Notes {
     earliest_timestamp: isize,
@@ -44,26 +44,37 @@ impl Notes {
             .unwrap()
     }
 
-    #[allow(clippy::option_filter_map)]
+    /// Each bus `offset` minutes after the timestamp means the timestamp
+    /// is congruent to `-offset` modulo its ID.
     pub fn get_earliest_timestamp_with_offset(&self) -> isize {
-        let bus_ids = self
-            .bus_ids
-            .iter()
-            .filter(|bus_id| bus_id.is_some())
-            .map(|bus_id| bus_id.unwrap())
-            .collect::<Vec<isize>>();
-        let offsets = self
+        let congruences = self
             .bus_ids
             .iter()
             .enumerate()
-            .filter(|(_, bus_id)| bus_id.is_some())
-            .map(|(offset, _)| offset as isize)
-            .collect::<Vec<isize>>();
+            .filter_map(|(offset, bus_id)| bus_id.map(|bus_id| (-(offset as i128), bus_id as i128)))
+            .collect::<Vec<_>>();
 
-        chinese_remainder_theorem(&offsets, &bus_ids).unwrap().abs()
+        let (timestamp, _) = crt(&congruences).unwrap();
+        timestamp as isize
     }
 }
 
+pub fn get_part_one(input: &str) -> Result<isize, String> {
+    Notes::from_str(input)
+        .map(|notes| {
+            let (bus_id, wait) = notes.get_earliest_bus_wait();
+
+            bus_id * wait
+        })
+        .map_err(|_| String::from("Invalid notes"))
+}
+
+pub fn get_part_two(input: &str) -> Result<isize, String> {
+    Notes::from_str(input)
+        .map(|notes| notes.get_earliest_timestamp_with_offset())
+        .map_err(|_| String::from("Invalid notes"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
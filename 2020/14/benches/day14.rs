@@ -2,7 +2,7 @@ use std::fs;
 
 use criterion::{criterion_group, criterion_main, Criterion};
 
-use ::day14::*;
+use ::aoc_2020_14::*;
 
 pub fn benchmark_read_initialization_program(c: &mut Criterion) {
     let input = fs::read_to_string("input.txt").expect("Error reading input.txt");
@@ -97,6 +97,14 @@ pub fn read_initialization_program_v2(input: &str) -> usize {
     memory.values().sum()
 }
 
+pub fn get_part_one(input: &str) -> usize {
+    read_initialization_program(input)
+}
+
+pub fn get_part_two(input: &str) -> usize {
+    read_initialization_program_v2(input)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2,7 +2,7 @@ use std::fs;
 
 use criterion::{criterion_group, criterion_main, Criterion};
 
-use ::day15::*;
+use ::aoc_2020_15::*;
 
 pub fn benchmark_get_nth_number_for_input_2020(c: &mut Criterion) {
     let input = fs::read_to_string("input.txt").expect("Error reading input.txt");
@@ -26,6 +26,14 @@ pub fn get_nth_number_for_input(input: &[usize], nth: usize) -> usize {
     previous_number
 }
 
+pub fn get_part_one(input: &str) -> usize {
+    get_nth_number_for_input(&get_numbers(input), 2020)
+}
+
+pub fn get_part_two(input: &str) -> usize {
+    get_nth_number_for_input(&get_numbers(input), 30_000_000)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -172,6 +172,18 @@ impl Document {
     }
 }
 
+pub fn get_part_one(input: &str) -> Result<usize, String> {
+    Document::from_str(input)
+        .map(|document| document.get_ticket_scanning_error_rate())
+        .map_err(|_| String::from("Invalid document"))
+}
+
+pub fn get_part_two(input: &str) -> Result<usize, String> {
+    Document::from_str(input)
+        .map(|document| document.get_departure_product())
+        .map_err(|_| String::from("Invalid document"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,20 +1,17 @@
 use std::fs;
-use std::str::FromStr;
 
-use ::day16::*;
+use ::aoc_2020_16::*;
 
 fn main() {
     let input = fs::read_to_string("input.txt").expect("Error reading input.txt");
 
-    let document = Document::from_str(&input).unwrap();
-
     println!(
         "What is your ticket scanning error rate? {}",
-        document.get_ticket_scanning_error_rate(),
+        get_part_one(&input).unwrap(),
     );
 
     println!(
         "What do you get if you multiply those six values together? {}",
-        document.get_departure_product(),
+        get_part_two(&input).unwrap(),
     );
 }
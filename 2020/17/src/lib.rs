@@ -155,6 +155,20 @@ pub fn get_grid_after_cycles(grid: Grid, dimensions: usize, cycles: usize) -> Gr
     grid
 }
 
+fn get_active_cube_count(input: &str, dimensions: usize) -> Result<usize, String> {
+    Grid::from_str(input)
+        .map(|grid| get_grid_after_cycles(grid, dimensions, 6).get_active_cube_count())
+        .map_err(|_| String::from("Invalid grid"))
+}
+
+pub fn get_part_one(input: &str) -> Result<usize, String> {
+    get_active_cube_count(input, 3)
+}
+
+pub fn get_part_two(input: &str) -> Result<usize, String> {
+    get_active_cube_count(input, 4)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -49,6 +49,14 @@ pub fn get_advanced_homework_sum(input: &str) -> usize {
         .sum()
 }
 
+pub fn get_part_one(input: &str) -> usize {
+    get_homework_sum(input)
+}
+
+pub fn get_part_two(input: &str) -> usize {
+    get_advanced_homework_sum(input)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -144,6 +144,20 @@ impl Rule {
     }
 }
 
+pub fn get_part_one(input: &str) -> Result<i64, String> {
+    SatelliteConnection::from_str(input)
+        .map(|satellite| satellite.get_valid_message_count())
+        .map_err(|_| String::from("Invalid satellite connection"))
+}
+
+pub fn get_part_two(input: &str) -> Result<i64, String> {
+    let input = input
+        .replace("8: 42", "8: 42 | 42 8")
+        .replace("11: 42 31", "11: 42 31 | 42 11 31");
+
+    get_part_one(&input)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
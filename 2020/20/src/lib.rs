@@ -424,6 +424,18 @@ impl Tile {
     }
 }
 
+pub fn get_part_one(input: &str) -> Result<usize, String> {
+    Image::from_str(input)
+        .map(|mut image| image.get_corners().iter().product())
+        .map_err(|_| String::from("Invalid image"))
+}
+
+pub fn get_part_two(input: &str) -> Result<usize, String> {
+    Image::from_str(input)
+        .map(|mut image| image.get_final_image().find_sea_monsters())
+        .map_err(|_| String::from("Invalid image"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
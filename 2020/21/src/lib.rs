@@ -85,6 +85,18 @@ impl Food {
     }
 }
 
+pub fn get_part_one(input: &str) -> Result<usize, String> {
+    Food::from_str(input)
+        .map(|food| food.get_safe_count())
+        .map_err(|_| String::from("Invalid food"))
+}
+
+pub fn get_part_two(input: &str) -> Result<String, String> {
+    Food::from_str(input)
+        .map(|food| food.get_canonical_dangerous_ingredients())
+        .map_err(|_| String::from("Invalid food"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
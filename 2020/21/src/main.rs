@@ -1,20 +1,17 @@
 use std::fs;
-use std::str::FromStr;
 
-use ::day21::*;
+use ::aoc_2020_21::*;
 
 fn main() {
     let input = fs::read_to_string("input.txt").expect("Error reading input.txt");
 
-    let food = Food::from_str(&input).unwrap();
-
     println!(
         "How many times do any of those ingredients appear? {}",
-        food.get_safe_count(),
+        get_part_one(&input).unwrap(),
     );
 
     println!(
         "What is your canonical dangerous ingredient list? {}",
-        food.get_canonical_dangerous_ingredients(),
+        get_part_two(&input).unwrap(),
     );
 }
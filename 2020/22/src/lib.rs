@@ -136,6 +136,18 @@ impl Game {
     }
 }
 
+pub fn get_part_one(input: &str) -> Result<usize, String> {
+    Game::from_str(input)
+        .map(|mut game| game.get_combat_winning_score())
+        .map_err(|_| String::from("Invalid game"))
+}
+
+pub fn get_part_two(input: &str) -> Result<usize, String> {
+    Game::from_str(input)
+        .map(|mut game| game.get_recursive_combat_winning_score())
+        .map_err(|_| String::from("Invalid game"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -86,6 +86,20 @@ impl CrabCups {
     }
 }
 
+pub fn get_part_one(input: &str) -> String {
+    let mut crab_cups = CrabCups::from_str(input, input.trim().len());
+    crab_cups.make_moves(100);
+
+    crab_cups.get_labels()
+}
+
+pub fn get_part_two(input: &str) -> usize {
+    let mut crab_cups = CrabCups::from_str(input, 1_000_000);
+    crab_cups.make_moves(10_000_000);
+
+    crab_cups.get_cup_product_two_clockwise_of_one()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -115,6 +115,22 @@ impl Lobby {
     }
 }
 
+pub fn get_part_one(input: &str) -> Result<usize, String> {
+    Lobby::from_str(input)
+        .map(|lobby| lobby.get_black_tile_count())
+        .map_err(|_| String::from("Invalid lobby"))
+}
+
+pub fn get_part_two(input: &str) -> Result<usize, String> {
+    Lobby::from_str(input)
+        .map(|lobby| {
+            (1..=100)
+                .fold(lobby, |lobby, _| lobby.get_next_day())
+                .get_black_tile_count()
+        })
+        .map_err(|_| String::from("Invalid lobby"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,24 +1,17 @@
 use std::fs;
-use std::str::FromStr;
 
-use ::day24::*;
+use ::aoc_2020_24::*;
 
 fn main() {
     let input = fs::read_to_string("input.txt").expect("Error reading input.txt");
 
-    let lobby = Lobby::from_str(&input).unwrap();
-
     println!(
         "…how many tiles are left with the black side up? {}",
-        lobby.get_black_tile_count(),
+        get_part_one(&input).unwrap(),
     );
 
-    let mut lobby = Lobby::from_str(&input).unwrap();
-    for _ in 1..=100 {
-        lobby = lobby.get_next_day();
-    }
     println!(
         "How many tiles will be black after 100 days? {}",
-        lobby.get_black_tile_count(),
+        get_part_two(&input).unwrap(),
     );
 }
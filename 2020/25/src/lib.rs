@@ -1,24 +1,16 @@
+use aoc_math::{discrete_log, mod_pow};
+
 const DIVISOR: usize = 20201227;
 
 pub fn get_loop_size(public_key: usize) -> usize {
-    let mut value = 1;
-    for loop_size in 1.. {
-        value *= 7;
-        value %= DIVISOR;
-        if value == public_key {
-            return loop_size;
-        }
+    match discrete_log(7, public_key as i128, DIVISOR as i128) {
+        Some(loop_size) => loop_size as usize,
+        None => panic!(r#"¯\_(ツ)_/¯"#),
     }
-    panic!(r#"¯\_(ツ)_/¯"#)
 }
 
 pub fn get_encryption_key(public_key: usize, other_loop_size: usize) -> usize {
-    let mut value = 1;
-    for _ in 0..other_loop_size {
-        value *= public_key;
-        value %= DIVISOR;
-    }
-    value
+    mod_pow(public_key as i128, other_loop_size as u128, DIVISOR as i128) as usize
 }
 
 pub fn get_public_keys(input: &str) -> (usize, usize) {
@@ -33,6 +25,12 @@ pub fn get_public_keys(input: &str) -> (usize, usize) {
     }
 }
 
+pub fn get_part_one(input: &str) -> usize {
+    let (card_public_key, door_public_key) = get_public_keys(input);
+
+    get_encryption_key(door_public_key, get_loop_size(card_public_key))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
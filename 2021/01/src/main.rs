@@ -1,6 +1,6 @@
 use std::fs;
 
-use ::day01::*;
+use ::aoc_2021_01::*;
 
 fn main() {
     let input = fs::read_to_string("input.txt").expect("Error reading input.txt");
@@ -1,6 +1,6 @@
 use std::fs;
 
-use ::day03::*;
+use ::aoc_2021_03::*;
 
 fn main() {
     let input = fs::read_to_string("input.txt").expect("Error reading input.txt");
@@ -1,6 +1,6 @@
 use std::fs;
 
-use ::day06::*;
+use ::aoc_2021_06::*;
 
 fn main() {
     let input = fs::read_to_string("input.txt").expect("Error reading input.txt");
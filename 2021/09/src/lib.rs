@@ -1,44 +1,25 @@
 use std::collections::{HashSet, VecDeque};
 
-fn get_neighbours(x: usize, y: usize) -> Vec<(usize, usize)> {
-    vec![
-        (x, y.saturating_sub(1)),
-        (x.saturating_sub(1), y),
-        (x + 1, y),
-        (x, y + 1),
-    ]
+use aoc_grid::{Grid, Point};
+
+fn get_lava_tubes(input: &str) -> Result<Grid<usize>, String> {
+    Grid::parse(input.trim(), |c| {
+        c.to_digit(10)
+            .map(|digit| digit as usize)
+            .ok_or("invalid digit")
+    })
+    .map_err(|error| error.to_string())
 }
 
-fn get_lava_tubes(input: &str) -> Result<Vec<Vec<usize>>, String> {
-    input
-        .trim()
-        .lines()
-        .map(|line| {
-            line.chars()
-                .map(|c| c.to_digit(10).map(|digit| digit as usize))
-                .collect::<Option<Vec<_>>>()
-        })
-        .collect::<Option<Vec<_>>>()
-        .ok_or_else(|| "invalid digit".to_owned())
-}
-
-fn get_low_points(lava_tubes: &[Vec<usize>]) -> Vec<(usize, usize)> {
-    let width = lava_tubes[0].len();
-    let height = lava_tubes.len();
-
-    (0..height)
-        .flat_map(|y| {
-            (0..width)
-                .map(move |x| {
-                    (x, y)
-                })
-        })
-        .filter(|&(x, y)| {
-            get_neighbours(x, y)
-                .iter()
-                .filter(|(nx, ny)| (*nx, *ny) != (x, y) && *nx < width && *ny < height)
-                .all(|&(nx, ny)| lava_tubes[ny][nx] > lava_tubes[y][x])
+fn get_low_points(lava_tubes: &Grid<usize>) -> Vec<Point> {
+    lava_tubes
+        .iter()
+        .filter(|&(point, height)| {
+            lava_tubes
+                .neighbours4(point)
+                .all(|neighbour| lava_tubes[neighbour] > *height)
         })
+        .map(|(point, _)| point)
         .collect()
 }
 
@@ -46,7 +27,7 @@ pub fn get_part_one(input: &str) -> Result<usize, String> {
     let lava_tubes = get_lava_tubes(input)?;
     let low_points = get_low_points(&lava_tubes);
 
-    let sum = low_points.iter().map(|&(x, y)| lava_tubes[y][x] + 1).sum();
+    let sum = low_points.iter().map(|&point| lava_tubes[point] + 1).sum();
 
     Ok(sum)
 }
@@ -54,10 +35,8 @@ pub fn get_part_one(input: &str) -> Result<usize, String> {
 pub fn get_part_two(input: &str) -> Result<usize, String> {
     let lava_tubes = get_lava_tubes(input)?;
     let low_points = get_low_points(&lava_tubes);
-    let width = lava_tubes[0].len();
-    let height = lava_tubes.len();
 
-    let mut all_seen: HashSet<(usize, usize)> = HashSet::new();
+    let mut all_seen: HashSet<Point> = HashSet::new();
     let mut basin_sizes = Vec::new();
 
     for low_point in low_points {
@@ -68,16 +47,11 @@ pub fn get_part_two(input: &str) -> Result<usize, String> {
         let mut queue = VecDeque::new();
         queue.push_back(low_point);
 
-        while !queue.is_empty() {
-            let (x, y) = queue.pop_front().unwrap();
-            seen.insert((x, y));
-            let neighbours = get_neighbours(x, y)
-                .into_iter()
-                .filter(|(nx, ny)| (*nx, *ny) != (x, y) && *nx < width && *ny < height)
-                .collect::<Vec<_>>();
-            for (nx, ny) in neighbours {
-                if lava_tubes[ny][nx] != 9 && !seen.contains(&(nx, ny)) {
-                    queue.push_back((nx, ny));
+        while let Some(point) = queue.pop_front() {
+            seen.insert(point);
+            for neighbour in lava_tubes.neighbours4(point) {
+                if lava_tubes[neighbour] != 9 && !seen.contains(&neighbour) {
+                    queue.push_back(neighbour);
                 }
             }
         }
@@ -1,6 +1,6 @@
 use std::fs;
 
-use ::day09::*;
+use ::aoc_2021_09::*;
 
 fn main() -> Result<(), String> {
     let input = fs::read_to_string("input.txt").expect("Error reading input.txt");
@@ -1,6 +1,6 @@
 use std::fs;
 
-use ::day10::*;
+use ::aoc_2021_10::*;
 
 fn main() -> Result<(), String> {
     let input = fs::read_to_string("input.txt").expect("Error reading input.txt");
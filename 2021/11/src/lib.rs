@@ -1,5 +1,4 @@
 use std::collections::HashSet;
-use std::{thread, time};
 
 fn get_octopodes(input: &str) -> Result<Vec<Vec<i8>>, String> {
     input
@@ -50,24 +49,9 @@ fn flash_ah_aaaaaah(x: usize, y: usize, octopodes: &mut [Vec<i8>]) -> usize {
     flashes
 }
 
-fn display_step(octopodes: &[Vec<i8>]) {
-    print!("{}[2J", 27 as char);
-    print!("{esc}[2J{esc}[1;1H", esc = 27 as char);
-    octopodes.iter().for_each(|row| {
-        row.iter().for_each(|octopus| {
-            let output = match octopus {
-                0 => '💥',
-                _ => '🐙',
-            };
-
-            print!("{}", output);
-        });
-        println!();
-    });
-    thread::sleep(time::Duration::from_millis(100));
-}
-
-pub fn get_part_one(input: &str, display: bool) -> Result<usize, String> {
+/// Counts the flashes over 100 steps, handing the octopodes to `on_step`
+/// after each one.
+pub fn count_flashes(input: &str, mut on_step: impl FnMut(&[Vec<i8>])) -> Result<usize, String> {
     let mut octopodes = get_octopodes(input)?;
     let mut flash_count = 0;
 
@@ -92,15 +76,15 @@ pub fn get_part_one(input: &str, display: bool) -> Result<usize, String> {
                 .for_each(|octopus| *octopus = 0);
         });
 
-        if display {
-            display_step(&octopodes);
-        }
+        on_step(&octopodes);
     }
 
     Ok(flash_count)
 }
 
-pub fn get_part_two(input: &str, display: bool) -> Result<usize, String> {
+/// Finds the first step on which every octopus flashes, handing the
+/// octopodes to `on_step` after each earlier one.
+pub fn find_sync_step(input: &str, mut on_step: impl FnMut(&[Vec<i8>])) -> Result<usize, String> {
     let mut octopodes = get_octopodes(input)?;
     let mut sync_step = 0;
 
@@ -130,14 +114,20 @@ pub fn get_part_two(input: &str, display: bool) -> Result<usize, String> {
                 .for_each(|octopus| *octopus = 0);
         });
 
-        if display {
-            display_step(&octopodes);
-        }
+        on_step(&octopodes);
     }
 
     Ok(sync_step)
 }
 
+pub fn get_part_one(input: &str) -> Result<usize, String> {
+    count_flashes(input, |_| ())
+}
+
+pub fn get_part_two(input: &str) -> Result<usize, String> {
+    find_sync_step(input, |_| ())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -156,11 +146,11 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        assert_eq!(Ok(1656), get_part_one(INPUT, false));
+        assert_eq!(Ok(1656), get_part_one(INPUT));
     }
 
     #[test]
     fn test_part_two() {
-        assert_eq!(Ok(195), get_part_two(INPUT, false));
+        assert_eq!(Ok(195), get_part_two(INPUT));
     }
 }
@@ -1,8 +1,9 @@
 use std::fs;
+use std::{thread, time};
 
 use argh::FromArgs;
 
-use ::day11::*;
+use ::aoc_2021_11::*;
 
 #[derive(FromArgs)]
 #[argh(description = "Advent of Code 2021, day 11")]
@@ -11,18 +12,40 @@ struct Args {
     display: bool,
 }
 
+fn display_step(octopodes: &[Vec<i8>]) {
+    print!("{}[2J", 27 as char);
+    print!("{esc}[2J{esc}[1;1H", esc = 27 as char);
+    octopodes.iter().for_each(|row| {
+        row.iter().for_each(|octopus| {
+            let output = match octopus {
+                0 => '💥',
+                _ => '🐙',
+            };
+
+            print!("{}", output);
+        });
+        println!();
+    });
+    thread::sleep(time::Duration::from_millis(100));
+}
+
 fn main() -> Result<(), String> {
     let args: Args = argh::from_env();
     let input = fs::read_to_string("input.txt").expect("Error reading input.txt");
+    let on_step = |octopodes: &[Vec<i8>]| {
+        if args.display {
+            display_step(octopodes);
+        }
+    };
 
     println!(
         "How many total flashes are there after 100 steps? {}",
-        get_part_one(&input, args.display)?,
+        count_flashes(&input, on_step)?,
     );
 
     println!(
         "What is the first step during which all octopuses flash? {}",
-        get_part_two(&input, args.display)?,
+        find_sync_step(&input, on_step)?,
     );
 
     Ok(())
@@ -1,6 +1,6 @@
 use std::fs;
 
-use ::day12::*;
+use ::aoc_2021_12::*;
 
 fn main() -> Result<(), String> {
     let input = fs::read_to_string("input.txt").expect("Error reading input.txt");
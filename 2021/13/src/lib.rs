@@ -1,5 +1,7 @@
 use std::collections::HashSet;
 
+use aoc_error::Result;
+
 #[derive(Debug)]
 enum Fold {
     X(isize),
@@ -45,7 +47,7 @@ pub fn get_part_one(input: &str) -> usize {
         .len()
 }
 
-pub fn get_part_two(input: &str) -> String {
+pub fn get_paper(input: &str) -> String {
     let (dots, folds) = get_instructions(input);
 
     let dots = dots
@@ -78,6 +80,10 @@ pub fn get_part_two(input: &str) -> String {
         .join("\n")
 }
 
+pub fn get_part_two(input: &str) -> Result<String> {
+    aoc_ocr::recognise(&get_paper(input))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -123,6 +129,6 @@ fold along x=5
             .replace('#', "⬛")
             .to_owned();
 
-        assert_eq!(output, get_part_two(INPUT));
+        assert_eq!(output, get_paper(INPUT));
     }
 }
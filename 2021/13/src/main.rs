@@ -1,8 +1,10 @@
 use std::fs;
 
-use ::day13::*;
+use aoc_error::Error;
 
-fn main() {
+use ::aoc_2021_13::*;
+
+fn main() -> Result<(), Error> {
     let input = fs::read_to_string("input.txt").expect("Error reading input.txt");
 
     println!(
@@ -11,7 +13,9 @@ fn main() {
     );
 
     println!(
-        "What code do you use to activate the infrared thermal imaging camera system?\n{}",
-        get_part_two(&input),
+        "What code do you use to activate the infrared thermal imaging camera system? {}",
+        get_part_two(&input)?,
     );
+
+    Ok(())
 }
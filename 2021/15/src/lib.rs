@@ -1,5 +1,4 @@
-use std::cmp::Reverse;
-use std::collections::{BinaryHeap, HashSet};
+use aoc_search::{dijkstra, State};
 
 fn get_cavern(input: &str) -> Vec<Vec<usize>> {
     input
@@ -20,43 +19,51 @@ fn get_risk_at((x, y): (usize, usize), cavern: &[Vec<usize>]) -> usize {
     (((cavern[y_original_tile][x_original_tile] + y_tile + x_tile) - 1) % 9) + 1
 }
 
-fn get_lowest_risk(cavern: Vec<Vec<usize>>, tiles: usize) -> usize {
-    let mut heap = BinaryHeap::new();
-    let mut seen = HashSet::new();
-    seen.insert((0, 0));
+struct TiledCavern {
+    cavern: Vec<Vec<usize>>,
+    tiles: usize,
+}
+
+impl TiledCavern {
+    fn width(&self) -> usize {
+        self.cavern[0].len() * self.tiles
+    }
+
+    fn height(&self) -> usize {
+        self.cavern.len() * self.tiles
+    }
+}
 
-    heap.push(Reverse((0_usize, (0_usize, 0_usize))));
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct Position((usize, usize));
 
-    while !heap.is_empty() {
-        let (risk, (x, y)) = heap.pop().unwrap().0;
+impl State for Position {
+    type Context = TiledCavern;
 
-        if (x, y) == ((cavern[0].len() * tiles) - 1, (cavern.len() * tiles) - 1) {
-            return risk;
-        }
+    fn successors(&self, tiled: &TiledCavern) -> Vec<(Self, usize)> {
+        let (x, y) = self.0;
 
-        let positions = [
+        [
             (x, y + 1),
             (x + 1, y),
-            (x, y.saturating_sub(1)),
-            (x.saturating_sub(1), y),
+            (x, y.wrapping_sub(1)),
+            (x.wrapping_sub(1), y),
         ]
         .into_iter()
-        .filter(|&(px, py)| {
-            !seen.contains(&(px, py))
-                && (px, py) != (x, y)
-                && px < cavern[0].len() * tiles
-                && py < cavern.len() * tiles
-        })
-        .collect::<HashSet<_>>();
+        .filter(|&(px, py)| px < tiled.width() && py < tiled.height())
+        .map(|position| (Position(position), get_risk_at(position, &tiled.cavern)))
+        .collect()
+    }
 
-        for (nx, ny) in positions {
-            let next_risk = get_risk_at((nx, ny), &cavern);
-            heap.push(Reverse((next_risk + risk, (nx, ny))));
-            seen.insert((nx, ny));
-        }
+    fn is_goal(&self, tiled: &TiledCavern) -> bool {
+        self.0 == (tiled.width() - 1, tiled.height() - 1)
     }
+}
 
-    unreachable!();
+fn get_lowest_risk(cavern: Vec<Vec<usize>>, tiles: usize) -> usize {
+    dijkstra(Position((0, 0)), &TiledCavern { cavern, tiles })
+        .cost()
+        .unwrap()
 }
 
 pub fn get_part_one(input: &str) -> usize {
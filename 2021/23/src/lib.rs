@@ -1,30 +1,31 @@
 use std::cmp::Ordering;
-use std::collections::{BinaryHeap, HashSet};
-use std::hash::{Hash, Hasher};
 
-#[derive(Clone, Eq, PartialEq)]
+use aoc_search::{dijkstra, State};
+
+#[derive(Clone, Eq, Hash, PartialEq)]
 struct Situation {
-    cost: usize,
     rooms: [Vec<char>; 4],
     hallway: [char; 11],
 }
 
-#[allow(clippy::derive_hash_xor_eq)]
-impl Hash for Situation {
-    fn hash<H: Hasher>(&self, hasher: &mut H) {
-        self.rooms.hash(hasher);
-        self.hallway.hash(hasher);
-    }
-}
+impl State for Situation {
+    type Context = ();
+
+    fn successors(&self, _: &()) -> Vec<(Self, usize)> {
+        let mut next = Vec::from_iter(get_amphipod_to_room(self));
 
-impl PartialOrd<Self> for Situation {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
+        for (room_index, room) in self.rooms.iter().enumerate() {
+            if is_room_organised(room_index, room) {
+                continue;
+            }
+            next.extend(get_amphipod_to_hallway(self, room_index));
+        }
+
+        next
     }
-}
-impl Ord for Situation {
-    fn cmp(&self, other: &Self) -> Ordering {
-        other.cost.cmp(&self.cost)
+
+    fn is_goal(&self, _: &()) -> bool {
+        is_organised(&self.rooms)
     }
 }
 
@@ -79,7 +80,7 @@ fn get_room_position(room: usize) -> usize {
     2 + (2 * room)
 }
 
-fn get_amphipod_to_room(situation: &Situation) -> Option<Situation> {
+fn get_amphipod_to_room(situation: &Situation) -> Option<(Situation, usize)> {
     for (step, &c) in situation.hallway.iter().enumerate() {
         if c == '.' {
             continue;
@@ -91,23 +92,19 @@ fn get_amphipod_to_room(situation: &Situation) -> Option<Situation> {
             {
                 let mut hallway = situation.hallway;
                 let mut rooms = situation.rooms.clone();
-                let cost = situation.cost + get_cost_for_amphipod(c) * (steps_to + steps_in);
+                let cost = get_cost_for_amphipod(c) * (steps_to + steps_in);
 
                 hallway[step] = '.';
                 rooms[target_room][steps_in - 1] = c;
 
-                return Some(Situation {
-                    cost,
-                    rooms,
-                    hallway,
-                });
+                return Some((Situation { rooms, hallway }, cost));
             }
         }
     }
     None
 }
 
-fn get_amphipod_to_hallway(situation: &Situation, room_index: usize) -> Vec<Situation> {
+fn get_amphipod_to_hallway(situation: &Situation, room_index: usize) -> Vec<(Situation, usize)> {
     let mut next = Vec::new();
 
     let (i, &amphipod) = situation.rooms[room_index]
@@ -120,16 +117,12 @@ fn get_amphipod_to_hallway(situation: &Situation, room_index: usize) -> Vec<Situ
         {
             let mut hallway = situation.hallway;
             let mut rooms = situation.rooms.clone();
-            let cost = situation.cost + get_cost_for_amphipod(amphipod) * (s_move + i + 1);
+            let cost = get_cost_for_amphipod(amphipod) * (s_move + i + 1);
 
             hallway[h] = amphipod;
             rooms[room_index][i] = '.';
 
-            next.push(Situation {
-                cost,
-                rooms,
-                hallway,
-            })
+            next.push((Situation { rooms, hallway }, cost))
         }
     }
 
@@ -137,39 +130,12 @@ fn get_amphipod_to_hallway(situation: &Situation, room_index: usize) -> Vec<Situ
 }
 
 fn get_least_energy(rooms: [Vec<char>; 4]) -> usize {
-    let mut heap = BinaryHeap::new();
-    let mut seen = HashSet::new();
-
     let situation = Situation {
-        cost: 0,
         rooms,
         hallway: ['.'; 11],
     };
-    heap.push(situation);
 
-    while let Some(situation) = heap.pop() {
-        if seen.contains(&situation) {
-            continue;
-        }
-
-        seen.insert(situation.clone());
-
-        if is_organised(&situation.rooms) {
-            return situation.cost;
-        }
-
-        if let Some(next) = get_amphipod_to_room(&situation) {
-            heap.push(next);
-        }
-
-        for (room_index, room) in situation.rooms.iter().enumerate() {
-            if is_room_organised(room_index, room) {
-                continue;
-            }
-            heap.extend(get_amphipod_to_hallway(&situation, room_index));
-        }
-    }
-    unreachable!()
+    dijkstra(situation, &()).cost().unwrap()
 }
 
 pub fn get_part_one(input: &str) -> usize {
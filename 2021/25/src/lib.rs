@@ -1,9 +1,8 @@
 use std::fmt::{Display, Formatter, Result as FmtResult};
 use std::str::FromStr;
-use std::{thread, time};
 
 #[derive(Eq, PartialEq)]
-struct Region {
+pub struct Region {
     locations: Vec<Vec<char>>,
     width: usize,
     height: usize,
@@ -81,26 +80,18 @@ impl Region {
     }
 }
 
-pub fn get_part_one(input: &str, display: bool) -> usize {
+/// Finds the first step on which no sea cucumbers move, handing the region
+/// to `on_step` before the first step and after each one.
+pub fn find_still_step(input: &str, mut on_step: impl FnMut(&Region)) -> usize {
     let mut region = Region::from_str(input).unwrap();
 
-    if display {
-        print!("{}[2J", 27 as char);
-        print!("{esc}[2J{esc}[1;1H", esc = 27 as char);
-        println!("{}", region);
-        thread::sleep(time::Duration::from_millis(100));
-    }
+    on_step(&region);
 
     (1..)
         .find(|_| {
             let next = region.get_step();
 
-            if display {
-                print!("{}[2J", 27 as char);
-                print!("{esc}[2J{esc}[1;1H", esc = 27 as char);
-                println!("{}", next);
-                thread::sleep(time::Duration::from_millis(100));
-            }
+            on_step(&next);
 
             match region == next {
                 true => true,
@@ -113,6 +104,10 @@ pub fn get_part_one(input: &str, display: bool) -> usize {
         .unwrap()
 }
 
+pub fn get_part_one(input: &str) -> usize {
+    find_still_step(input, |_| ())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -130,6 +125,6 @@ v.v..>>v.v
 
     #[test]
     fn test_part_one() {
-        assert_eq!(58, get_part_one(INPUT, true));
+        assert_eq!(58, get_part_one(INPUT));
     }
 }
@@ -1,8 +1,9 @@
 use std::fs;
+use std::{thread, time};
 
 use argh::FromArgs;
 
-use ::day25::*;
+use ::aoc_2021_25::*;
 
 #[derive(FromArgs)]
 #[argh(description = "Advent of Code 2021, day 25")]
@@ -11,12 +12,24 @@ struct Args {
     display: bool,
 }
 
+fn display_step(region: &Region) {
+    print!("{}[2J", 27 as char);
+    print!("{esc}[2J{esc}[1;1H", esc = 27 as char);
+    println!("{}", region);
+    thread::sleep(time::Duration::from_millis(100));
+}
+
 fn main() {
     let args: Args = argh::from_env();
     let input = fs::read_to_string("input.txt").expect("Error reading input.txt");
+    let on_step = |region: &Region| {
+        if args.display {
+            display_step(region);
+        }
+    };
 
     println!(
         "What is the first step on which no sea cucumbers move? {}",
-        get_part_one(&input, args.display),
+        find_still_step(&input, on_step),
     );
 }
@@ -1,6 +1,6 @@
 use std::fs;
 
-use ::day01::*;
+use ::aoc_2022_01::*;
 
 fn main() -> Result<(), String> {
     let input = fs::read_to_string("input.txt").expect("Error reading input.txt");
@@ -1,22 +1,9 @@
 #![deny(clippy::expect_used, clippy::unwrap_used)]
 
 use std::collections::VecDeque;
-use std::num::ParseIntError;
 use std::str::FromStr;
 
-#[derive(Debug, PartialEq, Eq)]
-pub enum AdventOfCodeError {
-    InvalidInputError,
-    ParseIntError(ParseIntError),
-    UnexpectedStackError(usize),
-    EmptyStackError(usize),
-}
-
-impl From<ParseIntError> for AdventOfCodeError {
-    fn from(error: ParseIntError) -> Self {
-        AdventOfCodeError::ParseIntError(error)
-    }
-}
+use aoc_error::{parse_lines, Context, Error, Result};
 
 #[derive(Debug)]
 struct Move {
@@ -26,31 +13,34 @@ struct Move {
 }
 
 impl FromStr for Move {
-    type Err = AdventOfCodeError;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let parts = s.split_ascii_whitespace().collect::<Vec<_>>();
+        if parts.len() != 6 {
+            return Err(Error::invalid_input(format!("invalid move {:?}", s)));
+        }
 
         Ok(Move {
             count: parts[1].parse()?,
             from: parts[3]
                 .chars()
                 .next()
-                .ok_or(AdventOfCodeError::InvalidInputError)?
-                .to_digit(10)
-                .ok_or(AdventOfCodeError::InvalidInputError)? as usize,
+                .and_then(|c| c.to_digit(10))
+                .context("invalid source stack")? as usize,
             to: parts[5]
                 .chars()
                 .next()
-                .ok_or(AdventOfCodeError::InvalidInputError)?
-                .to_digit(10)
-                .ok_or(AdventOfCodeError::InvalidInputError)? as usize,
+                .and_then(|c| c.to_digit(10))
+                .context("invalid destination stack")? as usize,
         })
     }
 }
 
-fn get_moves(input: &str) -> Result<Vec<Move>, AdventOfCodeError> {
-    input.lines().map(Move::from_str).collect()
+/// The moves, with any error placed relative to `first_line`, where they
+/// start in the whole input.
+fn get_moves(input: &str, first_line: usize) -> Result<Vec<Move>> {
+    parse_lines(input, Move::from_str).map_err(|error| error.on_line(first_line))
 }
 
 fn transpose<T: Clone + Copy>(original: Vec<Vec<T>>) -> Vec<Vec<T>> {
@@ -64,7 +54,7 @@ fn transpose<T: Clone + Copy>(original: Vec<Vec<T>>) -> Vec<Vec<T>> {
     transposed
 }
 
-fn get_cargo(input: &str) -> Result<Vec<VecDeque<char>>, AdventOfCodeError> {
+fn get_cargo(input: &str) -> Result<Vec<VecDeque<char>>> {
     let rows = input
         .lines()
         .rev()
@@ -82,13 +72,13 @@ fn get_cargo(input: &str) -> Result<Vec<VecDeque<char>>, AdventOfCodeError> {
     Ok(cargo)
 }
 
-pub fn get_part_one(input: &str) -> Result<String, AdventOfCodeError> {
+pub fn get_part_one(input: &str) -> Result<String> {
     let (cargo, moves) = input
         .trim_end()
         .split_once("\n\n")
-        .ok_or(AdventOfCodeError::InvalidInputError)?;
+        .context("expected a drawing of the crates followed by moves")?;
 
-    let moves = get_moves(moves)?;
+    let moves = get_moves(moves, cargo.lines().count() + 2)?;
     let mut cargo = get_cargo(cargo)?;
 
     for move_ in moves {
@@ -107,7 +97,7 @@ pub fn get_part_one(input: &str) -> Result<String, AdventOfCodeError> {
         .map(|(i, stack)| {
             stack
                 .back()
-                .ok_or(AdventOfCodeError::EmptyStackError(i + 1))
+                .with_context(|| format!("stack {} is empty", i + 1))
         })
         .collect::<Result<Vec<_>, _>>()?
         .into_iter()
@@ -116,13 +106,13 @@ pub fn get_part_one(input: &str) -> Result<String, AdventOfCodeError> {
     Ok(top)
 }
 
-pub fn get_part_two(input: &str) -> Result<String, AdventOfCodeError> {
+pub fn get_part_two(input: &str) -> Result<String> {
     let (cargo, moves) = input
         .trim_end()
         .split_once("\n\n")
-        .ok_or(AdventOfCodeError::InvalidInputError)?;
+        .context("expected a drawing of the crates followed by moves")?;
 
-    let moves = get_moves(moves)?;
+    let moves = get_moves(moves, cargo.lines().count() + 2)?;
     let mut cargo = get_cargo(cargo)?;
 
     for move_ in moves {
@@ -140,7 +130,7 @@ pub fn get_part_two(input: &str) -> Result<String, AdventOfCodeError> {
         .map(|(i, stack)| {
             stack
                 .back()
-                .ok_or(AdventOfCodeError::EmptyStackError(i + 1))
+                .with_context(|| format!("stack {} is empty", i + 1))
         })
         .collect::<Result<Vec<_>, _>>()?
         .into_iter()
@@ -151,6 +141,8 @@ pub fn get_part_two(input: &str) -> Result<String, AdventOfCodeError> {
 
 #[cfg(test)]
 mod tests {
+    use aoc_error::Location;
+
     use super::*;
 
     const INPUT: &str = r#"    [D]    
@@ -173,4 +165,14 @@ move 1 from 1 to 2
     fn test_part_two() {
         assert_eq!(Ok("MCD".to_owned()), get_part_two(INPUT));
     }
+
+    #[test]
+    fn test_move_location() {
+        let input = INPUT.replace("move 3 from 1 to 3", "move 3 from 1");
+        let location = get_part_one(&input)
+            .err()
+            .and_then(|error| error.location());
+
+        assert_eq!(Some(Location::line(7)), location);
+    }
 }
@@ -1,8 +1,10 @@
 use std::fs;
 
-use ::day05::*;
+use aoc_error::Error;
 
-fn main() -> Result<(), AdventOfCodeError> {
+use ::aoc_2022_05::*;
+
+fn main() -> Result<(), Error> {
     let input = fs::read_to_string("input.txt").expect("Error reading input.txt");
 
     println!(
@@ -1,6 +1,6 @@
 use std::fs;
 
-use day06::*;
+use aoc_2022_06::*;
 
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
@@ -1,9 +1,6 @@
-#[derive(Debug, PartialEq, Eq)]
-pub enum AdventOfCodeError {
-    InvalidStreamError(String),
-}
+use aoc_error::{Error, Result};
 
-fn find_marker(input: &str, length: usize) -> Result<usize, AdventOfCodeError> {
+fn find_marker(input: &str, length: usize) -> Result<usize> {
     let (offset, _) = input
         .as_bytes()
         .windows(length)
@@ -15,16 +12,16 @@ fn find_marker(input: &str, length: usize) -> Result<usize, AdventOfCodeError> {
 
             chars.len() == length
         })
-        .ok_or_else(|| AdventOfCodeError::InvalidStreamError(input.to_owned()))?;
+        .ok_or_else(|| Error::no_solution(format!("no marker of length {}", length)))?;
 
     Ok(offset + length)
 }
 
-pub fn get_part_one(input: &str) -> Result<usize, AdventOfCodeError> {
+pub fn get_part_one(input: &str) -> Result<usize> {
     find_marker(input, 4)
 }
 
-pub fn get_part_two(input: &str) -> Result<usize, AdventOfCodeError> {
+pub fn get_part_two(input: &str) -> Result<usize> {
     find_marker(input, 14)
 }
 
@@ -1,22 +1,18 @@
 #![deny(clippy::expect_used, clippy::unwrap_used)]
 
 use std::collections::HashMap;
-use std::num::ParseIntError;
 use std::path::PathBuf;
 
-#[derive(Debug, PartialEq, Eq)]
-pub enum AdventOfCodeError {
-    InvalidFileSystemError,
-    ParseIntError(ParseIntError),
-}
+use aoc_error::{Context, Error, Result};
 
-fn get_sizes(input: &str) -> Result<HashMap<PathBuf, usize>, AdventOfCodeError> {
+fn get_sizes(input: &str) -> Result<HashMap<PathBuf, usize>> {
     let mut cwd = PathBuf::new();
 
     input
         .trim()
         .lines()
-        .try_fold(HashMap::new(), |mut acc, line| {
+        .enumerate()
+        .try_fold(HashMap::new(), |mut acc, (i, line)| {
             let mut parts = line.split(' ');
 
             let (first, second, third) = (parts.next(), parts.next(), parts.next());
@@ -52,7 +48,7 @@ fn get_sizes(input: &str) -> Result<HashMap<PathBuf, usize>, AdventOfCodeError>
 
                                 Ok(acc)
                             }
-                            Err(e) => Err(AdventOfCodeError::ParseIntError(e)),
+                            Err(e) => Err(Error::from(e).on_line(i + 1)),
                         }
                     }
                     None => Ok(acc),
@@ -62,7 +58,7 @@ fn get_sizes(input: &str) -> Result<HashMap<PathBuf, usize>, AdventOfCodeError>
         })
 }
 
-pub fn get_part_one(input: &str) -> Result<usize, AdventOfCodeError> {
+pub fn get_part_one(input: &str) -> Result<usize> {
     let sizes = get_sizes(input)?;
 
     let sum = sizes.values().filter(|&size| *size <= 100_000).sum();
@@ -70,7 +66,7 @@ pub fn get_part_one(input: &str) -> Result<usize, AdventOfCodeError> {
     Ok(sum)
 }
 
-pub fn get_part_two(input: &str) -> Result<usize, AdventOfCodeError> {
+pub fn get_part_two(input: &str) -> Result<usize> {
     let total_disk_space = 70_000_000;
     let required_disk_space = 30_000_000;
 
@@ -79,13 +75,13 @@ pub fn get_part_two(input: &str) -> Result<usize, AdventOfCodeError> {
     let unused_disk_space = total_disk_space
         - sizes
             .get(&PathBuf::from("/"))
-            .ok_or(AdventOfCodeError::InvalidFileSystemError)?;
+            .context("no root directory")?;
 
     let delete = *sizes
         .values()
         .filter(|&size| unused_disk_space + *size >= required_disk_space)
         .min()
-        .ok_or(AdventOfCodeError::InvalidFileSystemError)?;
+        .ok_or_else(|| Error::no_solution("no directory frees up enough space"))?;
 
     Ok(delete)
 }
@@ -2,7 +2,7 @@ use std::{fs, str::FromStr};
 
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
-use day08::*;
+use aoc_2022_08::*;
 
 fn criterion_benchmark(c: &mut Criterion) {
     let input = fs::read_to_string("input.txt").expect("Error reading input.txt");
@@ -1,6 +1,6 @@
 use std::fs;
 
-use day09::*;
+use aoc_2022_09::*;
 
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
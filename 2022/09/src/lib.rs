@@ -1,21 +1,9 @@
 use std::collections::HashSet;
-use std::num::ParseIntError;
 use std::str::FromStr;
 
+use aoc_error::{parse_lines, Context, Error, Result};
 use num_complex::Complex;
 
-#[derive(Debug, PartialEq, Eq)]
-pub enum AdventOfCodeError {
-    ParseIntError(ParseIntError),
-    InvalidInputError(String),
-}
-
-impl From<ParseIntError> for AdventOfCodeError {
-    fn from(error: ParseIntError) -> Self {
-        AdventOfCodeError::ParseIntError(error)
-    }
-}
-
 struct Motion {
     direction: Complex<i32>,
     distance: i32,
@@ -51,10 +39,12 @@ impl Rope {
 }
 
 impl FromStr for Motion {
-    type Err = AdventOfCodeError;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (direction, distance) = s.split_once(' ').unwrap();
+        let (direction, distance) = s
+            .split_once(' ')
+            .context("expected a direction and distance")?;
         let direction = match direction {
             "U" => Some(Complex::new(0, -1)),
             "L" => Some(Complex::new(-1, 0)),
@@ -62,7 +52,7 @@ impl FromStr for Motion {
             "R" => Some(Complex::new(1, 0)),
             _ => None,
         }
-        .ok_or_else(|| AdventOfCodeError::InvalidInputError(direction.to_owned()))?;
+        .with_context(|| format!("invalid direction {:?}", direction))?;
         let distance = distance.parse::<i32>()?;
 
         Ok(Self {
@@ -72,26 +62,20 @@ impl FromStr for Motion {
     }
 }
 
-fn get_tail_visits(input: &str, knots: usize) -> Result<usize, AdventOfCodeError> {
-    let rope = input
-        .trim()
-        .lines()
-        .try_fold(Rope::from_knots(knots), |mut rope, line| {
-            let motion = Motion::from_str(line)?;
-
-            rope.move_knots(motion);
-
-            Ok::<Rope, AdventOfCodeError>(rope)
-        })?;
+fn get_tail_visits(input: &str, knots: usize) -> Result<usize> {
+    let mut rope = Rope::from_knots(knots);
+    for motion in parse_lines(input.trim(), Motion::from_str)? {
+        rope.move_knots(motion);
+    }
 
     Ok(rope.seen.len())
 }
 
-pub fn get_part_one(input: &str) -> Result<usize, AdventOfCodeError> {
+pub fn get_part_one(input: &str) -> Result<usize> {
     get_tail_visits(input, 2)
 }
 
-pub fn get_part_two(input: &str) -> Result<usize, AdventOfCodeError> {
+pub fn get_part_two(input: &str) -> Result<usize> {
     get_tail_visits(input, 10)
 }
 
@@ -1,17 +1,6 @@
 #![deny(clippy::expect_used, clippy::unwrap_used)]
 
-use std::num::ParseIntError;
-
-#[derive(Debug, PartialEq, Eq)]
-pub enum AdventOfCodeError {
-    ParseIntError(ParseIntError),
-}
-
-impl From<ParseIntError> for AdventOfCodeError {
-    fn from(error: ParseIntError) -> Self {
-        AdventOfCodeError::ParseIntError(error)
-    }
-}
+use aoc_error::{Error, Result};
 
 struct Cpu {
     x_register: isize,
@@ -37,42 +26,54 @@ impl Cpu {
     }
 }
 
-pub fn get_part_one(input: &str) -> Result<isize, AdventOfCodeError> {
-    let cpu = input.trim().lines().try_fold(Cpu::new(), |mut cpu, line| {
-        if cpu.cycle % 40 == 20 {
-            cpu.signal_strengths += cpu.cycle * cpu.x_register;
-        }
-        cpu.cycle += 1;
-
-        if let Some(("addx", value)) = line.split_once(' ') {
+pub fn get_part_one(input: &str) -> Result<isize> {
+    let cpu = input
+        .trim()
+        .lines()
+        .enumerate()
+        .try_fold(Cpu::new(), |mut cpu, (i, line)| {
             if cpu.cycle % 40 == 20 {
                 cpu.signal_strengths += cpu.cycle * cpu.x_register;
             }
-
-            cpu.x_register += value.parse::<isize>()?;
             cpu.cycle += 1;
-        }
 
-        Ok::<Cpu, AdventOfCodeError>(cpu)
-    })?;
+            if let Some(("addx", value)) = line.split_once(' ') {
+                if cpu.cycle % 40 == 20 {
+                    cpu.signal_strengths += cpu.cycle * cpu.x_register;
+                }
+
+                cpu.x_register += value
+                    .parse::<isize>()
+                    .map_err(|error| Error::from(error).on_line(i + 1))?;
+                cpu.cycle += 1;
+            }
+
+            Ok::<Cpu, Error>(cpu)
+        })?;
 
     Ok(cpu.signal_strengths)
 }
 
-pub fn get_part_two(input: &str) -> Result<String, AdventOfCodeError> {
-    let cpu = input.trim().lines().try_fold(Cpu::new(), |mut cpu, line| {
-        cpu.set_pixel();
-        cpu.cycle += 1;
-
-        if let Some(("addx", value)) = line.split_once(' ') {
+pub fn get_image(input: &str) -> Result<String> {
+    let cpu = input
+        .trim()
+        .lines()
+        .enumerate()
+        .try_fold(Cpu::new(), |mut cpu, (i, line)| {
             cpu.set_pixel();
-
-            cpu.x_register += value.parse::<isize>()?;
             cpu.cycle += 1;
-        }
 
-        Ok::<Cpu, AdventOfCodeError>(cpu)
-    })?;
+            if let Some(("addx", value)) = line.split_once(' ') {
+                cpu.set_pixel();
+
+                cpu.x_register += value
+                    .parse::<isize>()
+                    .map_err(|error| Error::from(error).on_line(i + 1))?;
+                cpu.cycle += 1;
+            }
+
+            Ok::<Cpu, Error>(cpu)
+        })?;
 
     let display = cpu
         .screen
@@ -88,6 +89,10 @@ pub fn get_part_two(input: &str) -> Result<String, AdventOfCodeError> {
     Ok(display)
 }
 
+pub fn get_part_two(input: &str) -> Result<String> {
+    aoc_ocr::recognise(&get_image(input)?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -253,6 +258,6 @@ noop
 
     #[test]
     fn test_part_two() {
-        assert_eq!(Ok(IMAGE.to_owned()), get_part_two(LARGER_INPUT));
+        assert_eq!(Ok(IMAGE.to_owned()), get_image(LARGER_INPUT));
     }
 }
@@ -1,8 +1,10 @@
 use std::fs;
 
-use ::day10::*;
+use aoc_error::Error;
 
-fn main() -> Result<(), AdventOfCodeError> {
+use ::aoc_2022_10::*;
+
+fn main() -> Result<(), Error> {
     let input = fs::read_to_string("input.txt").expect("Error reading input.txt");
 
     println!(
@@ -11,7 +13,7 @@ fn main() -> Result<(), AdventOfCodeError> {
     );
 
     println!(
-        "What eight capital letters appear on your CRT?\n\n{}",
+        "What eight capital letters appear on your CRT? {}",
         get_part_two(&input)?,
     );
 
@@ -2,20 +2,7 @@
 
 use std::collections::VecDeque;
 
-use peg::error::ParseError;
-use peg::str::LineCol;
-
-#[derive(Debug, PartialEq, Eq)]
-pub enum AdventOfCodeError {
-    InvalidMonkeyError(ParseError<LineCol>),
-    InsufficientMonkeysError(usize),
-}
-
-impl From<ParseError<LineCol>> for AdventOfCodeError {
-    fn from(error: ParseError<LineCol>) -> Self {
-        AdventOfCodeError::InvalidMonkeyError(error)
-    }
-}
+use aoc_error::{Context, Result};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Operation {
@@ -131,7 +118,7 @@ peg::parser! {
     }
 }
 
-pub fn get_part_one(input: &str) -> Result<usize, AdventOfCodeError> {
+pub fn get_part_one(input: &str) -> Result<usize> {
     let mut monkeys = monkeys::monkeys(input.trim())?;
 
     for _ in 0..20 {
@@ -139,7 +126,7 @@ pub fn get_part_one(input: &str) -> Result<usize, AdventOfCodeError> {
             loop {
                 let monkey = monkeys
                     .get_mut(i)
-                    .ok_or(AdventOfCodeError::InsufficientMonkeysError(i))?;
+                    .with_context(|| format!("there is no monkey {}", i))?;
 
                 if monkey.items.is_empty() {
                     break;
@@ -148,7 +135,7 @@ pub fn get_part_one(input: &str) -> Result<usize, AdventOfCodeError> {
                 if let Some((worry, target)) = monkey.get_target() {
                     let target_monkey = monkeys
                         .get_mut(target)
-                        .ok_or(AdventOfCodeError::InsufficientMonkeysError(target))?;
+                        .with_context(|| format!("there is no monkey {}", target))?;
 
                     target_monkey.items.push_back(worry);
                 }
@@ -167,7 +154,7 @@ pub fn get_part_one(input: &str) -> Result<usize, AdventOfCodeError> {
     Ok(monkey_business)
 }
 
-pub fn get_part_two(input: &str) -> Result<usize, AdventOfCodeError> {
+pub fn get_part_two(input: &str) -> Result<usize> {
     let mut monkeys = monkeys::monkeys(input.trim())?;
 
     let least_common_multiple: usize = monkeys.iter().map(|m| m.test_divisible_by).product();
@@ -175,16 +162,18 @@ pub fn get_part_two(input: &str) -> Result<usize, AdventOfCodeError> {
     for _ in 0..10_000 {
         for i in 0..monkeys.len() {
             loop {
-                let monkey = monkeys.get_mut(i)
-                    .ok_or(AdventOfCodeError::InsufficientMonkeysError(i))?;
+                let monkey = monkeys
+                    .get_mut(i)
+                    .with_context(|| format!("there is no monkey {}", i))?;
 
                 if monkey.items.is_empty() {
                     break;
                 }
 
                 if let Some((worry, target)) = monkey.get_worrisome_target(least_common_multiple) {
-                    let target_monkey = monkeys.get_mut(target)
-                        .ok_or(AdventOfCodeError::InsufficientMonkeysError(target))?;
+                    let target_monkey = monkeys
+                        .get_mut(target)
+                        .with_context(|| format!("there is no monkey {}", target))?;
 
                     target_monkey.items.push_back(worry);
                 }
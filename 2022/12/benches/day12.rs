@@ -1,6 +1,6 @@
 use std::{fs, str::FromStr};
 
-use day12::*;
+use aoc_2022_12::*;
 
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
@@ -1,16 +1,9 @@
 #![deny(clippy::expect_used, clippy::unwrap_used)]
 
-use std::{
-    cmp::Ordering,
-    collections::{HashSet, VecDeque},
-    str::FromStr,
-};
-
-#[derive(Debug, PartialEq, Eq)]
-pub enum AdventOfCodeError {
-    InvalidHeightMapError,
-    TotallyLostError,
-}
+use std::{cmp::Ordering, collections::HashSet, str::FromStr};
+
+use aoc_error::{Context, Error, Result};
+use aoc_search::{bfs, State};
 
 pub struct HeightMap {
     elevations: Vec<Vec<usize>>,
@@ -40,8 +33,50 @@ impl HeightMap {
     }
 }
 
+/// Climbing from the start, never more than one higher per step.
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct Ascent((usize, usize));
+
+impl State for Ascent {
+    type Context = HeightMap;
+
+    fn successors(&self, heightmap: &HeightMap) -> Vec<(Self, usize)> {
+        heightmap
+            .get_steps(self.0, Ordering::Less)
+            .into_iter()
+            .map(|step| (Ascent(step), 1))
+            .collect()
+    }
+
+    fn is_goal(&self, heightmap: &HeightMap) -> bool {
+        self.0 == heightmap.end
+    }
+}
+
+/// Retracing an ascent backwards from the best signal to any lowest point.
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct Descent((usize, usize));
+
+impl State for Descent {
+    type Context = HeightMap;
+
+    fn successors(&self, heightmap: &HeightMap) -> Vec<(Self, usize)> {
+        heightmap
+            .get_steps(self.0, Ordering::Greater)
+            .into_iter()
+            .map(|step| (Descent(step), 1))
+            .collect()
+    }
+
+    fn is_goal(&self, heightmap: &HeightMap) -> bool {
+        let (x, y) = self.0;
+
+        heightmap.elevations[y][x] == 0
+    }
+}
+
 impl FromStr for HeightMap {
-    type Err = AdventOfCodeError;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut start = None;
@@ -72,67 +107,28 @@ impl FromStr for HeightMap {
 
         Ok(HeightMap {
             elevations,
-            width: width.ok_or(AdventOfCodeError::InvalidHeightMapError)?,
+            width: width.context("empty heightmap")?,
             height,
-            start: start.ok_or(AdventOfCodeError::InvalidHeightMapError)?,
-            end: end.ok_or(AdventOfCodeError::InvalidHeightMapError)?,
+            start: start.context("no start position")?,
+            end: end.context("no best signal position")?,
         })
     }
 }
 
-pub fn get_part_one(input: &str) -> Result<usize, AdventOfCodeError> {
+pub fn get_part_one(input: &str) -> Result<usize> {
     let heightmap = HeightMap::from_str(input)?;
 
-    let mut queue = VecDeque::new();
-    let mut seen = HashSet::new();
-
-    queue.push_back((heightmap.start, 0));
-    seen.insert(heightmap.start);
-    let mut fewest_steps = None;
-
-    while let Some((current, distance)) = queue.pop_front() {
-        if current == heightmap.end {
-            fewest_steps = Some(distance);
-            break;
-        }
-
-        for step in heightmap.get_steps(current, Ordering::Less) {
-            if !seen.contains(&step) {
-                queue.push_back((step, distance + 1));
-                seen.insert(step);
-            }
-        }
-    }
-
-    fewest_steps.ok_or(AdventOfCodeError::TotallyLostError)
+    bfs(Ascent(heightmap.start), &heightmap)
+        .cost()
+        .ok_or_else(|| Error::no_solution("the best signal is unreachable"))
 }
 
-pub fn get_part_two(input: &str) -> Result<usize, AdventOfCodeError> {
+pub fn get_part_two(input: &str) -> Result<usize> {
     let heightmap = HeightMap::from_str(input)?;
 
-    let mut fewest_steps = None;
-
-    let mut queue = VecDeque::new();
-    let mut seen = HashSet::new();
-
-    queue.push_back((heightmap.end, 0));
-    seen.insert(heightmap.end);
-
-    while let Some(((x, y), distance)) = queue.pop_front() {
-        if heightmap.elevations[y][x] == 0 {
-            fewest_steps = Some(distance);
-            break;
-        }
-
-        for step in heightmap.get_steps((x, y), Ordering::Greater) {
-            if !seen.contains(&step) {
-                queue.push_back((step, distance + 1));
-                seen.insert(step);
-            }
-        }
-    }
-
-    fewest_steps.ok_or(AdventOfCodeError::TotallyLostError)
+    bfs(Descent(heightmap.end), &heightmap)
+        .cost()
+        .ok_or_else(|| Error::no_solution("the best signal is unreachable"))
 }
 
 #[cfg(test)]
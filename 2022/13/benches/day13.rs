@@ -1,6 +1,6 @@
 use std::fs;
 
-use day13::*;
+use aoc_2022_13::*;
 
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
@@ -3,19 +3,7 @@
 use std::cmp::Ordering;
 use std::collections::HashSet;
 
-use peg::error::ParseError;
-use peg::str::LineCol;
-
-#[derive(Debug, PartialEq, Eq)]
-pub enum AdventOfCodeError {
-    InvalidPacketsError(ParseError<LineCol>),
-}
-
-impl From<ParseError<LineCol>> for AdventOfCodeError {
-    fn from(error: ParseError<LineCol>) -> Self {
-        AdventOfCodeError::InvalidPacketsError(error)
-    }
-}
+use aoc_error::Result;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum Value {
@@ -45,7 +33,7 @@ peg::parser! {
     }
 }
 
-pub fn get_part_one(input: &str) -> Result<usize, AdventOfCodeError> {
+pub fn get_part_one(input: &str) -> Result<usize> {
     let packets = packets::packets(input.trim())?;
 
     let sum = packets
@@ -94,7 +82,7 @@ fn is_ordered(left: &Value, right: &Value) -> Option<bool> {
     }
 }
 
-pub fn get_part_two(input: &str) -> Result<usize, AdventOfCodeError> {
+pub fn get_part_two(input: &str) -> Result<usize> {
     let mut packets = packets::packets(input.trim())?;
 
     let divider_packets = packets::packets("[[2]]\n[[6]]")?
@@ -162,7 +150,7 @@ mod tests {
 
     #[test]
     fn test_parser() {
-        let packets = packets::packets("[[1],[2,3,4]]").unwrap();
+        let packets = packets::packets("[[1],[2,3,4]]");
 
         let expected = vec![Value::List(vec![
             Value::List(vec![Value::Integer(1)]),
@@ -173,6 +161,6 @@ mod tests {
             ]),
         ])];
 
-        assert_eq!(packets, expected);
+        assert_eq!(packets, Ok(expected));
     }
 }
@@ -1,7 +1,7 @@
 use std::fs;
 use std::str::FromStr;
 
-use day14::*;
+use aoc_2022_14::*;
 
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
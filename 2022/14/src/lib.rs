@@ -1,19 +1,11 @@
 use std::collections::HashSet;
-use std::str::FromStr;
 #[cfg(feature = "display")]
-use std::{
-    fmt::{Display, Error as FmtError, Formatter, Result as FmtResult},
-    thread,
-    time::Duration,
-};
+use std::fmt::{Display, Error as FmtError, Formatter, Result as FmtResult};
+use std::str::FromStr;
 
+use aoc_error::{Context, Error, Result};
 use itertools::Itertools;
 
-#[derive(Debug, PartialEq, Eq)]
-pub enum AdventOfCodeError {
-    LackOfAbyssError,
-}
-
 pub struct Scan {
     rocks: HashSet<(usize, usize)>,
     sand: HashSet<(usize, usize)>,
@@ -50,7 +42,7 @@ impl Display for Scan {
 }
 
 impl FromStr for Scan {
-    type Err = AdventOfCodeError;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let rocks = s
@@ -88,32 +80,23 @@ impl Scan {
     }
 }
 
-pub fn get_part_one(input: &str) -> Result<usize, AdventOfCodeError> {
+/// Pours sand until it flows into the abyss, handing the scan to `on_step`
+/// as each unit falls.
+pub fn pour_into_abyss(input: &str, mut on_step: impl FnMut(&Scan)) -> Result<usize> {
     let mut scan = Scan::from_str(input)?;
-    #[cfg(feature = "display")]
-    {
-        print!("{esc}[2J{esc}[1;1H", esc = 27 as char);
-        println!("{}\n", &scan);
-        thread::sleep(Duration::from_millis(5_000));
-    }
 
     let void = scan
         .rocks
         .iter()
         .map(|(_, y)| *y)
         .max()
-        .ok_or(AdventOfCodeError::LackOfAbyssError)?;
+        .context("no rock in the scan")?;
     let mut units = 0;
 
     'outer: loop {
         scan.falling = (500, 0);
         loop {
-            #[cfg(feature = "display")]
-            {
-                print!("{esc}[2J{esc}[1;1H", esc = 27 as char);
-                println!("{}\n", &scan);
-                thread::sleep(Duration::from_millis(50));
-            }
+            on_step(&scan);
 
             if scan.falling.1 > void {
                 break 'outer;
@@ -137,7 +120,9 @@ pub fn get_part_one(input: &str) -> Result<usize, AdventOfCodeError> {
     Ok(units)
 }
 
-pub fn get_part_two(input: &str) -> Result<usize, AdventOfCodeError> {
+/// Pours sand until it blocks the source, handing the scan to `on_step` as
+/// each unit falls.
+pub fn pour_onto_floor(input: &str, mut on_step: impl FnMut(&Scan)) -> Result<usize> {
     let mut scan = Scan::from_str(input)?;
 
     let floor = scan
@@ -145,7 +130,7 @@ pub fn get_part_two(input: &str) -> Result<usize, AdventOfCodeError> {
         .iter()
         .map(|(_, y)| *y)
         .max()
-        .ok_or(AdventOfCodeError::LackOfAbyssError)?
+        .context("no rock in the scan")?
         + 2;
     let mut units = 0;
 
@@ -157,12 +142,7 @@ pub fn get_part_two(input: &str) -> Result<usize, AdventOfCodeError> {
         scan.falling = (500, 0);
 
         loop {
-            #[cfg(feature = "display")]
-            {
-                print!("{esc}[2J{esc}[1;1H", esc = 27 as char);
-                println!("{}\n", &scan);
-                thread::sleep(Duration::from_millis(100));
-            }
+            on_step(&scan);
 
             if scan.falling.1 + 1 == floor {
                 scan.rocks.insert(scan.falling);
@@ -185,6 +165,14 @@ pub fn get_part_two(input: &str) -> Result<usize, AdventOfCodeError> {
     Ok(units)
 }
 
+pub fn get_part_one(input: &str) -> Result<usize> {
+    pour_into_abyss(input, |_| ())
+}
+
+pub fn get_part_two(input: &str) -> Result<usize> {
+    pour_onto_floor(input, |_| ())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,19 +1,39 @@
 use std::fs;
+#[cfg(feature = "display")]
+use std::{str::FromStr, thread, time::Duration};
 
-use ::day14::*;
+use aoc_error::Error;
 
-fn main() -> Result<(), AdventOfCodeError> {
+use ::aoc_2022_14::*;
+
+#[cfg(feature = "display")]
+fn display_step(scan: &Scan, delay: u64) {
+    print!("{esc}[2J{esc}[1;1H", esc = 27 as char);
+    println!("{}\n", scan);
+    thread::sleep(Duration::from_millis(delay));
+}
+
+fn main() -> Result<(), Error> {
     let input = fs::read_to_string("input.txt").expect("Error reading input.txt");
 
+    #[cfg(feature = "display")]
+    let (part_one, part_two) = {
+        display_step(&Scan::from_str(&input)?, 5_000);
+
+        (
+            pour_into_abyss(&input, |scan| display_step(scan, 50))?,
+            pour_onto_floor(&input, |scan| display_step(scan, 100))?,
+        )
+    };
+    #[cfg(not(feature = "display"))]
+    let (part_one, part_two) = (get_part_one(&input)?, get_part_two(&input)?);
+
     println!(
         "How many units of sand come to rest before sand starts flowing into the abyss below? {}",
-        get_part_one(&input)?,
+        part_one,
     );
 
-    println!(
-        "How many units of sand come to rest? {}",
-        get_part_two(&input)?,
-    );
+    println!("How many units of sand come to rest? {}", part_two,);
 
     Ok(())
 }
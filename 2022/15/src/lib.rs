@@ -1,19 +1,6 @@
-use peg::error::ParseError;
-use peg::str::LineCol;
+use aoc_error::{Error, Result};
 use rayon::prelude::*;
 
-#[derive(Debug, PartialEq, Eq)]
-pub enum AdventOfCodeError {
-    InvalidSensorsError(ParseError<LineCol>),
-    PleaseMindTheGapError,
-}
-
-impl From<ParseError<LineCol>> for AdventOfCodeError {
-    fn from(error: ParseError<LineCol>) -> Self {
-        AdventOfCodeError::InvalidSensorsError(error)
-    }
-}
-
 #[derive(Debug)]
 struct Point {
     x: isize,
@@ -78,7 +65,7 @@ peg::parser! {
     }
 }
 
-pub fn get_part_one(input: &str, y: isize) -> Result<isize, AdventOfCodeError> {
+pub fn get_part_one(input: &str, y: isize) -> Result<isize> {
     let sensors = sensors::sensors(input.trim())?;
 
     let mut ranges = sensors
@@ -117,7 +104,7 @@ pub fn get_part_one(input: &str, y: isize) -> Result<isize, AdventOfCodeError> {
     Ok(overlapped.iter().map(|range| range.end - range.start).sum())
 }
 
-pub fn get_part_two(input: &str) -> Result<isize, AdventOfCodeError> {
+pub fn get_part_two(input: &str) -> Result<isize> {
     let sensors = sensors::sensors(input.trim())?;
 
     let (y, gap) = (0..=4_000_000)
@@ -157,7 +144,7 @@ pub fn get_part_two(input: &str) -> Result<isize, AdventOfCodeError> {
 
             None
         })
-        .ok_or(AdventOfCodeError::PleaseMindTheGapError)?;
+        .ok_or_else(|| Error::no_solution("no gap for the distress beacon"))?;
 
     Ok((gap * 4000000) + y)
 }
@@ -1,7 +1,7 @@
 use std::fs;
 use std::hint::black_box;
 
-use day16::*;
+use aoc_2022_16::*;
 
 use criterion::{criterion_group, criterion_main, Criterion};
 
@@ -1,12 +1,8 @@
 use std::collections::{HashMap, HashSet, VecDeque};
 
+use aoc_error::{Context, Error, Result};
 use rayon::prelude::*;
 
-#[derive(Debug, PartialEq, Eq)]
-pub enum AdventOfCodeError {
-    CouldNotFindValveError,
-}
-
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Valve<'a> {
     name: &'a str,
@@ -70,11 +66,7 @@ peg::parser! {
     }
 }
 
-fn get_distance(
-    start: &Valve,
-    end: &Valve,
-    valves: &HashMap<Valve, Vec<Valve>>,
-) -> Result<usize, AdventOfCodeError> {
+fn get_distance(start: &Valve, end: &Valve, valves: &HashMap<Valve, Vec<Valve>>) -> Result<usize> {
     let mut queue = VecDeque::new();
     let mut seen = HashSet::new();
 
@@ -90,7 +82,7 @@ fn get_distance(
 
         for tunnel in valves
             .get(valve)
-            .ok_or(AdventOfCodeError::CouldNotFindValveError)?
+            .with_context(|| format!("there is no valve {}", valve.name))?
         {
             if !seen.contains(tunnel) {
                 queue.push_back((tunnel, depth + 1));
@@ -98,7 +90,10 @@ fn get_distance(
         }
     }
 
-    Err(AdventOfCodeError::CouldNotFindValveError)
+    Err(Error::no_solution(format!(
+        "valve {} is unreachable",
+        end.name
+    )))
 }
 
 struct State {
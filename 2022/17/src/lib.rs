@@ -2,10 +2,7 @@
 
 use std::collections::HashMap;
 
-#[derive(Debug, PartialEq, Eq)]
-pub enum AdventOfCodeError {
-    InvalidStateError,
-}
+use aoc_error::{Context, Result};
 
 #[derive(Copy, Clone)]
 enum Rock {
@@ -122,14 +119,14 @@ impl Chamber {
         &mut self,
         jets: &mut impl Iterator<Item = (usize, isize)>,
         rock: Rock,
-    ) -> Result<(), AdventOfCodeError> {
+    ) -> Result<()> {
         // "Each rock appears so that its left edge is two units away from the left wall
         // and its bottom edge is three units above the highest rock in the room…"
         let mut rock_position = (2, self.max_height() + 4);
 
         loop {
             // "…it alternates between being pushed by a jet of hot gas one unit…"
-            let motion = jets.next().ok_or(AdventOfCodeError::InvalidStateError)?.1;
+            let motion = jets.next().context("no jet pattern")?.1;
             let pushed = (rock_position.0 + motion, rock_position.1);
             if !self.is_collision(rock, pushed) {
                 rock_position = (pushed.0, pushed.1);
@@ -149,7 +146,7 @@ impl Chamber {
     }
 }
 
-fn get_tower_height(input: &str, limit: usize) -> Result<isize, AdventOfCodeError> {
+fn get_tower_height(input: &str, limit: usize) -> Result<isize> {
     let mut chamber = Chamber::default();
 
     let mut jets = input
@@ -183,15 +180,12 @@ fn get_tower_height(input: &str, limit: usize) -> Result<isize, AdventOfCodeErro
     while rock_index < limit {
         rock_index += 1;
 
-        chamber.falling_rock(
-            &mut jets,
-            rocks.next().ok_or(AdventOfCodeError::InvalidStateError)?.1,
-        )?;
+        chamber.falling_rock(&mut jets, rocks.next().context("no rocks")?.1)?;
 
         // try and find a repeating cycle for Part 2…
         let chamber_state = ChamberState {
-            jet_index: jets.peek().ok_or(AdventOfCodeError::InvalidStateError)?.0,
-            rock_index: rocks.peek().ok_or(AdventOfCodeError::InvalidStateError)?.0,
+            jet_index: jets.peek().context("no jet pattern")?.0,
+            rock_index: rocks.peek().context("no rocks")?.0,
             columns_diff_to_max: chamber.get_columns_diff_to_max(),
         };
         let rock_state = RockState {
@@ -208,10 +202,7 @@ fn get_tower_height(input: &str, limit: usize) -> Result<isize, AdventOfCodeErro
             let total_growth = (cycles_remaining as isize) * growth_per_cycle;
 
             for _ in rock_index..limit {
-                chamber.falling_rock(
-                    &mut jets,
-                    rocks.next().ok_or(AdventOfCodeError::InvalidStateError)?.1,
-                )?;
+                chamber.falling_rock(&mut jets, rocks.next().context("no rocks")?.1)?;
             }
 
             return Ok(chamber.max_height() + 1 + total_growth);
@@ -221,11 +212,11 @@ fn get_tower_height(input: &str, limit: usize) -> Result<isize, AdventOfCodeErro
     Ok(chamber.max_height() + 1)
 }
 
-pub fn get_part_one(input: &str) -> Result<isize, AdventOfCodeError> {
+pub fn get_part_one(input: &str) -> Result<isize> {
     get_tower_height(input, 2_022)
 }
 
-pub fn get_part_two(input: &str) -> Result<isize, AdventOfCodeError> {
+pub fn get_part_two(input: &str) -> Result<isize> {
     get_tower_height(input, 1_000_000_000_000)
 }
 
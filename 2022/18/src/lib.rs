@@ -2,19 +2,7 @@
 
 use std::collections::{HashSet, VecDeque};
 
-use peg::error::ParseError;
-use peg::str::LineCol;
-
-#[derive(Debug, PartialEq, Eq)]
-pub enum AdventOfCodeError {
-    InvalidMonkeyError(ParseError<LineCol>),
-}
-
-impl From<ParseError<LineCol>> for AdventOfCodeError {
-    fn from(error: ParseError<LineCol>) -> Self {
-        AdventOfCodeError::InvalidMonkeyError(error)
-    }
-}
+use aoc_error::Result;
 
 #[derive(Copy, Clone, PartialEq, Eq, Hash)]
 pub struct Cube {
@@ -81,7 +69,7 @@ peg::parser! {
     }
 }
 
-pub fn get_part_one(input: &str) -> Result<usize, AdventOfCodeError> {
+pub fn get_part_one(input: &str) -> Result<usize> {
     let cubes = scanner::cubes(input.trim())?;
 
     let sides = cubes
@@ -97,12 +85,16 @@ pub fn get_part_one(input: &str) -> Result<usize, AdventOfCodeError> {
     Ok(sides)
 }
 
-pub fn get_part_two(input: &str) -> Result<usize, AdventOfCodeError> {
+pub fn get_part_two(input: &str) -> Result<usize> {
     let cubes = scanner::cubes(input.trim())?;
 
     let (mut maxx, mut maxy, mut maxz) = (0, 0, 0);
     cubes.iter().for_each(|cube| {
-        (maxx, maxy, maxz) = (maxx.max(cube.x + 1), maxy.max(cube.y + 1), maxz.max(cube.z + 1))
+        (maxx, maxy, maxz) = (
+            maxx.max(cube.x + 1),
+            maxy.max(cube.y + 1),
+            maxz.max(cube.z + 1),
+        )
     });
     let start = Cube {
         x: -1,
@@ -1,6 +1,6 @@
 use std::fs;
 
-use ::day19::*;
+use ::aoc_2022_19::*;
 
 fn main() {
     let input = fs::read_to_string("input.txt").expect("Error reading input.txt");
@@ -1,37 +1,19 @@
-use std::num::ParseIntError;
+use aoc_error::{parse_lines, Context, Result};
 
-#[derive(Debug, PartialEq, Eq)]
-pub enum AdventOfCodeError {
-    ParseIntError(ParseIntError),
-    InvalidIndexError(usize),
-    MisplacedZeroError,
-}
-
-impl From<ParseIntError> for AdventOfCodeError {
-    fn from(error: ParseIntError) -> Self {
-        AdventOfCodeError::ParseIntError(error)
-    }
-}
-
-fn get_numbers(input: &str) -> Result<(Vec<usize>, Vec<isize>), AdventOfCodeError> {
-    let (indices, numbers): (Vec<_>, Vec<Result<_, _>>) = input
-        .trim()
-        .lines()
-        .enumerate()
-        .map(|(i, line)| (i, line.parse::<isize>()))
-        .unzip();
+fn get_numbers(input: &str) -> Result<(Vec<usize>, Vec<isize>)> {
+    let numbers = parse_lines(input.trim(), str::parse::<isize>)?;
 
-    Ok((indices, numbers.into_iter().collect::<Result<Vec<_>, _>>()?))
+    Ok(((0..numbers.len()).collect(), numbers))
 }
 
-pub fn get_part_one(input: &str) -> Result<isize, AdventOfCodeError> {
+pub fn get_part_one(input: &str) -> Result<isize> {
     let (mut indices, numbers) = get_numbers(input)?;
 
     for i in 0..indices.len() {
         let index = indices
             .iter()
             .position(|p| *p == i)
-            .ok_or(AdventOfCodeError::InvalidIndexError(i))?;
+            .with_context(|| format!("number {} went missing", i))?;
 
         let number = numbers[i];
 
@@ -49,7 +31,7 @@ pub fn get_part_one(input: &str) -> Result<isize, AdventOfCodeError> {
     let zero = numbers
         .iter()
         .position(|n| *n == 0)
-        .ok_or(AdventOfCodeError::MisplacedZeroError)?;
+        .context("no zero in the file")?;
 
     let coordinates = [1_000, 2_000, 3_000]
         .iter()
@@ -59,7 +41,7 @@ pub fn get_part_one(input: &str) -> Result<isize, AdventOfCodeError> {
     Ok(coordinates)
 }
 
-pub fn get_part_two(input: &str) -> Result<isize, AdventOfCodeError> {
+pub fn get_part_two(input: &str) -> Result<isize> {
     let (mut indices, numbers) = get_numbers(input)?;
 
     let numbers = numbers.iter().map(|n| n * 811_589_153).collect::<Vec<_>>();
@@ -69,7 +51,7 @@ pub fn get_part_two(input: &str) -> Result<isize, AdventOfCodeError> {
             let index = indices
                 .iter()
                 .position(|p| *p == i)
-                .ok_or(AdventOfCodeError::InvalidIndexError(i))?;
+                .with_context(|| format!("number {} went missing", i))?;
 
             let number = numbers[i];
 
@@ -88,7 +70,7 @@ pub fn get_part_two(input: &str) -> Result<isize, AdventOfCodeError> {
     let zero = numbers
         .iter()
         .position(|n| *n == 0)
-        .ok_or(AdventOfCodeError::MisplacedZeroError)?;
+        .context("no zero in the file")?;
 
     let coordinates = [1_000, 2_000, 3_000]
         .iter()
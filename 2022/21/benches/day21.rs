@@ -1,6 +1,6 @@
 use std::fs;
 
-use day21::*;
+use aoc_2022_21::*;
 
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
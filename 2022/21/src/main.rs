@@ -1,6 +1,6 @@
 use std::fs;
 
-use ::day21::*;
+use ::aoc_2022_21::*;
 
 fn main() {
     let input = fs::read_to_string("input.txt").expect("Error reading input.txt");
@@ -1,6 +1,6 @@
 use std::fs;
 
-use ::day22::*;
+use ::aoc_2022_22::*;
 
 fn main() {
     let input = fs::read_to_string("input.txt").expect("Error reading input.txt");
@@ -1,6 +1,6 @@
 use std::fs;
 
-use day23::*;
+use aoc_2022_23::*;
 
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
@@ -5,14 +5,9 @@ use std::fmt::{Display, Error as FmtError, Formatter, Result as FmtResult};
 use std::ops::Add;
 use std::str::FromStr;
 
+use aoc_error::{Context, Error, Result};
 use itertools::{Itertools, MinMaxResult};
 
-#[derive(Debug, PartialEq, Eq)]
-pub enum AdventOfCodeError {
-    LackOfBoundariesError,
-    HyperactiveElvesError,
-}
-
 #[derive(Debug)]
 struct Scan {
     positions: HashSet<Position>,
@@ -50,15 +45,15 @@ impl Scan {
         }
     }
 
-    fn minmax(&self) -> Result<(Position, Position), AdventOfCodeError> {
+    fn minmax(&self) -> Result<(Position, Position)> {
         match self.positions.iter().map(|p| p.x).minmax() {
             MinMaxResult::MinMax(minx, maxx) => match self.positions.iter().map(|p| p.y).minmax() {
                 MinMaxResult::MinMax(miny, maxy) => {
                     Ok((Position::new(minx, miny), Position::new(maxx, maxy)))
                 }
-                _ => Err(AdventOfCodeError::LackOfBoundariesError),
+                _ => Err(Error::invalid_input("no elves in the scan")),
             },
-            _ => Err(AdventOfCodeError::LackOfBoundariesError),
+            _ => Err(Error::invalid_input("no elves in the scan")),
         }
     }
 
@@ -111,7 +106,7 @@ impl Scan {
 }
 
 impl FromStr for Scan {
-    type Err = AdventOfCodeError;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let positions = s
@@ -130,21 +125,9 @@ impl FromStr for Scan {
     }
 }
 
-impl From<AdventOfCodeError> for FmtResult {
-    fn from(_: AdventOfCodeError) -> Self {
-        FmtResult::Err(FmtError)
-    }
-}
-
-impl From<AdventOfCodeError> for FmtError {
-    fn from(_: AdventOfCodeError) -> Self {
-        FmtError
-    }
-}
-
 impl Display for Scan {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        let (min, max) = self.minmax()?;
+        let (min, max) = self.minmax().map_err(|_| FmtError)?;
 
         let output = (min.y..=max.y)
             .map(|y| {
@@ -185,7 +168,7 @@ impl Add for Position {
     }
 }
 
-pub fn get_part_one(input: &str) -> Result<usize, AdventOfCodeError> {
+pub fn get_part_one(input: &str) -> Result<usize> {
     let mut scan = Scan::from_str(input.trim())?;
 
     (0..10).for_each(|round| {
@@ -200,7 +183,7 @@ pub fn get_part_one(input: &str) -> Result<usize, AdventOfCodeError> {
     Ok(empty)
 }
 
-pub fn get_part_two(input: &str) -> Result<usize, AdventOfCodeError> {
+pub fn get_part_two(input: &str) -> Result<usize> {
     let mut scan = Scan::from_str(input.trim())?;
     let mut count = 1;
 
@@ -210,7 +193,7 @@ pub fn get_part_two(input: &str) -> Result<usize, AdventOfCodeError> {
 
             count == 0
         })
-        .ok_or(AdventOfCodeError::HyperactiveElvesError)?
+        .context("the elves never stop moving")?
         + 1;
 
     Ok(round)
@@ -1,6 +1,6 @@
 use std::fs;
 
-use ::day24::*;
+use ::aoc_2022_24::*;
 
 fn main() {
     let input = fs::read_to_string("input.txt").expect("Error reading input.txt");
@@ -1,6 +1,6 @@
 use std::fs;
 
-use ::day25::*;
+use ::aoc_2022_25::*;
 
 fn main() {
     let input = fs::read_to_string("input.txt").expect("Error reading input.txt");
@@ -1,63 +1,57 @@
+use aoc_error::{parse_lines, Context, Result};
+
 const NUMBERS: [&str; 9] = [
     "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
 ];
 
-pub fn get_part_one(input: &str) -> Result<usize, String> {
-    let calibration_values = input
-        .trim()
-        .lines()
-        .map(|line| {
-            let first = line.bytes().find(u8::is_ascii_digit);
-            let last = line.bytes().rfind(u8::is_ascii_digit);
+pub fn get_part_one(input: &str) -> Result<usize> {
+    let calibration_values = parse_lines(input.trim(), |line| {
+        let first = line.bytes().find(u8::is_ascii_digit);
+        let last = line.bytes().rfind(u8::is_ascii_digit);
 
-            match (first, last) {
-                (Some(first), Some(last)) => {
-                    Ok(((first as usize - 48) * 10) + (last as usize - 48))
-                }
-                _ => Err(format!("invalid line: {}", line)),
-            }
-        })
-        .collect::<Result<Vec<usize>, String>>()?;
+        match (first, last) {
+            (Some(first), Some(last)) => Some(((first as usize - 48) * 10) + (last as usize - 48)),
+            _ => None,
+        }
+        .context("expected a digit")
+    })?;
 
     Ok(calibration_values.iter().sum())
 }
 
-pub fn get_part_two(input: &str) -> Result<usize, String> {
-    let calibration_values = input
-        .trim()
-        .lines()
-        .map(|line| {
-            let first = line.char_indices().find_map(|(i, c)| {
-                if c.is_ascii_digit() {
-                    c.to_digit(10)
-                } else {
-                    NUMBERS.iter().enumerate().find_map(|(n, number)| {
-                        match line[i..].starts_with(number) {
-                            true => Some((n as u32) + 1),
-                            false => None,
-                        }
-                    })
-                }
-            });
-            let last = line.char_indices().rev().find_map(|(i, c)| {
-                if c.is_ascii_digit() {
-                    c.to_digit(10)
-                } else {
-                    NUMBERS.iter().enumerate().find_map(|(n, number)| {
-                        match line[i..].starts_with(number) {
-                            true => Some((n as u32) + 1),
-                            false => None,
-                        }
-                    })
-                }
-            });
-
-            match (first, last) {
-                (Some(first), Some(last)) => Ok((first as usize * 10) + last as usize),
-                _ => Err(format!("invalid line: {}", line)),
+pub fn get_part_two(input: &str) -> Result<usize> {
+    let calibration_values = parse_lines(input.trim(), |line| {
+        let first = line.char_indices().find_map(|(i, c)| {
+            if c.is_ascii_digit() {
+                c.to_digit(10)
+            } else {
+                NUMBERS.iter().enumerate().find_map(|(n, number)| {
+                    match line[i..].starts_with(number) {
+                        true => Some((n as u32) + 1),
+                        false => None,
+                    }
+                })
             }
-        })
-        .collect::<Result<Vec<usize>, String>>()?;
+        });
+        let last = line.char_indices().rev().find_map(|(i, c)| {
+            if c.is_ascii_digit() {
+                c.to_digit(10)
+            } else {
+                NUMBERS.iter().enumerate().find_map(|(n, number)| {
+                    match line[i..].starts_with(number) {
+                        true => Some((n as u32) + 1),
+                        false => None,
+                    }
+                })
+            }
+        });
+
+        match (first, last) {
+            (Some(first), Some(last)) => Some((first as usize * 10) + last as usize),
+            _ => None,
+        }
+        .context("expected a digit")
+    })?;
 
     Ok(calibration_values.iter().sum())
 }
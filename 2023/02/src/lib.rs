@@ -1,3 +1,5 @@
+use aoc_error::Result;
+
 #[derive(Default)]
 struct Set {
     red: usize,
@@ -65,9 +67,8 @@ peg::parser! {
     }
 }
 
-pub fn get_part_one(input: &str) -> Result<usize, String> {
-    Ok(game::games(input)
-        .map_err(|e| e.to_string())?
+pub fn get_part_one(input: &str) -> Result<usize> {
+    Ok(game::games(input)?
         .iter()
         .filter_map(|game| {
             (game.set.red <= 12 && game.set.green <= 13 && game.set.blue <= 14).then_some(game.id)
@@ -75,9 +76,8 @@ pub fn get_part_one(input: &str) -> Result<usize, String> {
         .sum())
 }
 
-pub fn get_part_two(input: &str) -> Result<usize, String> {
-    Ok(game::games(input)
-        .map_err(|e| e.to_string())?
+pub fn get_part_two(input: &str) -> Result<usize> {
+    Ok(game::games(input)?
         .iter()
         .map(|game| game.set.red * game.set.green * game.set.blue)
         .sum())
@@ -1,3 +1,5 @@
+use aoc_error::{Context, Error, Location, Result};
+
 #[derive(Debug)]
 enum Position {
     Number(usize, usize),
@@ -29,12 +31,18 @@ peg::parser! {
     }
 }
 
-pub fn get_part_one(input: &str) -> Result<usize, String> {
+fn get_number(input: &str, start: usize, end: usize) -> Result<usize> {
+    input[start..end]
+        .parse()
+        .map_err(|error| Error::from(error).at(Location::from_offset(input, start)))
+}
+
+pub fn get_part_one(input: &str) -> Result<usize> {
     let line_length = input
         .find(|c| c == '\n')
-        .ok_or_else(|| format!("invalid input: {}", input))?
+        .context("expected at least one line")?
         + 1;
-    let positions = schematic::symbols(input.trim()).map_err(|e| e.to_string())?;
+    let positions = schematic::symbols(input.trim())?;
 
     let part_numbers = positions
         .iter()
@@ -54,20 +62,19 @@ pub fn get_part_one(input: &str) -> Result<usize, String> {
                         && ((start % line_length).saturating_sub(1)..((end % line_length) + 1))
                             .contains(&sx) // x-range is within 1.
                 })
-                .map(|(start, end)| input[*start..*end].parse::<usize>())
+                .map(|(start, end)| get_number(input, *start, *end))
         })
-        .collect::<Result<Vec<_>, _>>()
-        .map_err(|e| e.to_string())?;
+        .collect::<Result<Vec<_>>>()?;
 
     Ok(part_numbers.iter().sum())
 }
 
-pub fn get_part_two(input: &str) -> Result<usize, String> {
+pub fn get_part_two(input: &str) -> Result<usize> {
     let line_length = input
         .find(|c| c == '\n')
-        .ok_or_else(|| format!("invalid input: {}", input))?
+        .context("expected at least one line")?
         + 1;
-    let positions = schematic::symbols(input.trim()).map_err(|e| e.to_string())?;
+    let positions = schematic::symbols(input.trim())?;
 
     let gears = positions
         .iter()
@@ -94,14 +101,10 @@ pub fn get_part_two(input: &str) -> Result<usize, String> {
         .map(|part_numbers| {
             part_numbers
                 .iter()
-                .map(|&(start, end)| {
-                    input[*start..*end]
-                        .parse::<usize>()
-                        .map_err(|e| e.to_string())
-                })
-                .collect::<Result<Vec<_>, String>>()
+                .map(|&(start, end)| get_number(input, *start, *end))
+                .collect::<Result<Vec<_>>>()
         })
-        .collect::<Result<Vec<Vec<_>>, String>>()?
+        .collect::<Result<Vec<Vec<_>>>>()?
         .iter()
         .map(|part_numbers| part_numbers.iter().product::<usize>())
         .sum();
@@ -1,5 +1,7 @@
 use std::collections::BTreeSet;
 
+use aoc_error::Result;
+
 peg::parser! {
     pub grammar scratchcard() for str {
         rule _() = [' ' | '\n']*
@@ -36,8 +38,8 @@ peg::parser! {
     }
 }
 
-pub fn get_part_one(input: &str) -> Result<usize, String> {
-    let scratchcards = scratchcard::cards(input.trim()).map_err(|e| e.to_string())?;
+pub fn get_part_one(input: &str) -> Result<usize> {
+    let scratchcards = scratchcard::cards(input.trim())?;
 
     let points = scratchcards
         .iter()
@@ -50,8 +52,8 @@ pub fn get_part_one(input: &str) -> Result<usize, String> {
     Ok(points)
 }
 
-pub fn get_part_two(input: &str) -> Result<usize, String> {
-    let scratchcards = scratchcard::cards(input.trim()).map_err(|e| e.to_string())?;
+pub fn get_part_two(input: &str) -> Result<usize> {
+    let scratchcards = scratchcard::cards(input.trim())?;
     let mut counts = vec![1; scratchcards.len()];
 
     scratchcards
@@ -1,8 +1,10 @@
 use std::fs;
 
-use ::day04::*;
+use aoc_error::Error;
 
-fn main() -> Result<(), String> {
+use ::aoc_2023_04::*;
+
+fn main() -> Result<(), Error> {
     let input = fs::read_to_string("input.txt").expect("Error reading input.txt");
 
     println!(
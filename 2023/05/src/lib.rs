@@ -1,3 +1,5 @@
+use aoc_error::{Error, Result};
+
 #[derive(Debug)]
 pub struct MapRange {
     destination_range_start: usize,
@@ -127,21 +129,21 @@ peg::parser! {
     }
 }
 
-pub fn get_part_one(input: &str) -> Result<usize, String> {
-    let almanac = almanac::almanac(input.trim()).map_err(|e| e.to_string())?;
+pub fn get_part_one(input: &str) -> Result<usize> {
+    let almanac = almanac::almanac(input.trim())?;
 
     let lowest_location = almanac
         .seeds
         .iter()
         .map(|seed| almanac.get_seed_location_number(*seed))
         .min()
-        .ok_or("could not find lowest location".to_owned())?;
+        .ok_or_else(|| Error::no_solution("could not find lowest location"))?;
 
     Ok(lowest_location)
 }
 
-pub fn get_part_two(input: &str) -> Result<usize, String> {
-    let almanac = almanac::almanac(input.trim()).map_err(|e| e.to_string())?;
+pub fn get_part_two(input: &str) -> Result<usize> {
+    let almanac = almanac::almanac(input.trim())?;
 
     let seed_ranges = almanac
         .seeds
@@ -160,7 +162,7 @@ pub fn get_part_two(input: &str) -> Result<usize, String> {
         })
         .map(|&seed| almanac.get_seed_location_number(seed))
         .min()
-        .ok_or("could not find lowest location".to_owned())?;
+        .ok_or_else(|| Error::no_solution("could not find lowest location"))?;
 
     Ok(min)
 }
@@ -1,3 +1,5 @@
+use aoc_error::Result;
+
 struct Race {
     time: usize,
     distance: usize,
@@ -55,8 +57,8 @@ peg::parser! {
     }
 }
 
-pub fn get_part_one(input: &str) -> Result<usize, String> {
-    let races = paper::races(input.trim()).map_err(|e| format!("bad input: {}", e))?;
+pub fn get_part_one(input: &str) -> Result<usize> {
+    let races = paper::races(input.trim())?;
 
     let product_of_ways = races
         .iter()
@@ -70,8 +72,8 @@ pub fn get_part_one(input: &str) -> Result<usize, String> {
     Ok(product_of_ways)
 }
 
-pub fn get_part_two(input: &str) -> Result<usize, String> {
-    let race = paper::race_rtfm(input.trim()).map_err(|e| format!("bad input: {}", e))?;
+pub fn get_part_two(input: &str) -> Result<usize> {
+    let race = paper::race_rtfm(input.trim())?;
 
     let time = race.time as f64;
     let distance = race.distance as f64;
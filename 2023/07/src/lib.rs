@@ -1,6 +1,8 @@
 use std::cmp::Ordering;
 use std::collections::BTreeMap;
 
+use aoc_error::{parse_lines, Context, Error, Result};
+
 #[derive(Debug, Eq, PartialEq)]
 struct Hand {
     cards: Vec<u32>,
@@ -59,10 +61,13 @@ impl PartialOrd for Hand {
 }
 
 impl Hand {
-    fn from_str(s: &str, jokers_wild: bool) -> Result<Self, String> {
-        let (cards, bid) = s.trim().split_once(' ').ok_or(format!("bad line: {}", s))?;
+    fn from_str(s: &str, jokers_wild: bool) -> Result<Self> {
+        let (cards, bid) = s
+            .trim()
+            .split_once(' ')
+            .context("expected cards and a bid")?;
 
-        let bid = bid.parse::<usize>().map_err(|e| e.to_string())?;
+        let bid = bid.parse::<usize>()?;
 
         let cards = cards
             .chars()
@@ -96,28 +101,24 @@ impl Hand {
         let r#type = match counts.pop().unwrap_or(0) + jokers {
             5 => Type::FiveOfAKind,
             4 => Type::FourOfAKind,
-            3 => match counts.pop().ok_or("bad count".to_owned())? {
+            3 => match counts.pop().context("bad count")? {
                 2 => Type::FullHouse,
                 _ => Type::ThreeOfAKind,
             },
-            2 => match counts.pop().ok_or("bad count".to_owned())? {
+            2 => match counts.pop().context("bad count")? {
                 2 => Type::TwoPair,
                 _ => Type::Pair,
             },
             1 => Type::HighCard,
-            _ => unreachable!(),
+            count => return Err(Error::invalid_input(format!("{} of a kind", count))),
         };
 
         Ok(Self { cards, bid, r#type })
     }
 }
 
-pub fn get_part_one(input: &str) -> Result<usize, String> {
-    let mut hands = input
-        .trim()
-        .lines()
-        .map(|line| Hand::from_str(line, false))
-        .collect::<Result<Vec<_>, String>>()?;
+pub fn get_part_one(input: &str) -> Result<usize> {
+    let mut hands = parse_lines(input.trim(), |line| Hand::from_str(line, false))?;
 
     hands.sort_unstable();
 
@@ -130,12 +131,8 @@ pub fn get_part_one(input: &str) -> Result<usize, String> {
     Ok(winnings)
 }
 
-pub fn get_part_two(input: &str) -> Result<usize, String> {
-    let mut hands = input
-        .trim()
-        .lines()
-        .map(|line| Hand::from_str(line, true))
-        .collect::<Result<Vec<_>, String>>()?;
+pub fn get_part_two(input: &str) -> Result<usize> {
+    let mut hands = parse_lines(input.trim(), |line| Hand::from_str(line, true))?;
 
     hands.sort_unstable();
 
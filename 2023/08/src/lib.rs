@@ -1,6 +1,6 @@
 //! The input has a very specific structure: each node ending in `A`
 //! has a corresponding node ending in `Z`, forming a cycle.
-//! 
+//!
 //! The period of this cycle reaching the node ending in `Z` is the
 //! least-common-multiple of the length of both directions with the
 //! length of the cycle.
@@ -9,93 +9,90 @@
 //! each cycle. The answer is the total length of the directions.
 use std::collections::{HashMap, HashSet, VecDeque};
 
+use aoc_error::{Context, Error, Location, Result};
+
+fn parse_node(line: &str) -> Result<(&str, (&str, &str))> {
+    match (line.get(..3), line.get(7..10), line.get(12..15)) {
+        (Some(start), Some(left), Some(right)) => Ok((start, (left, right))),
+        _ => Err(Error::invalid_input(format!("invalid node {:?}", line))),
+    }
+}
+
 /// Party one _should_ be a unique instance of the BFS for the nodes
 /// named `AAA` and `ZZZ`. However, this doesn't hold true for one of
 /// the test cases and has been left as the initial, naive approach.
-pub fn get_part_one(input: &str) -> Result<usize, String> {
+pub fn get_part_one(input: &str) -> Result<usize> {
     let mut lines = input.trim().lines();
 
-    let instructions = lines.next().ok_or(format!("bad input: {}", input))?;
+    let instructions = lines.next().context("expected instructions")?;
     let nodes = lines
+        .enumerate()
         .skip(1)
-        .map(|line| {
-            let start = &line[..3];
-            let left = &line[7..10];
-            let right = &line[12..15];
-
-            (start, (left, right))
-        })
-        .collect::<HashMap<_, _>>();
-
-    let instructions = instructions.chars().cycle();
-
-    let steps = instructions
-        .scan("AAA", |node, instruction| match *node {
-            "ZZZ" => None,
-            current => match nodes.get(current) {
-                None => unreachable!(),
-                Some((left, right)) => match instruction {
-                    'L' => {
-                        *node = left;
-                        Some(left)
-                    }
-                    'R' => {
-                        *node = right;
-                        Some(right)
-                    }
-                    _ => unreachable!(),
-                },
-            },
-        })
-        .count();
+        .map(|(i, line)| parse_node(line).map_err(|error| error.on_line(i + 2)))
+        .collect::<Result<HashMap<_, _>>>()?;
+
+    let mut node = "AAA";
+    let mut steps = 0;
+    for instruction in instructions.chars().cycle() {
+        if node == "ZZZ" {
+            break;
+        }
+
+        let (left, right) = nodes
+            .get(node)
+            .with_context(|| format!("no node {:?}", node))?;
+        node = match instruction {
+            'L' => left,
+            'R' => right,
+            _ => {
+                return Err(
+                    Error::invalid_input(format!("invalid instruction {:?}", instruction))
+                        .at(Location::line(1)),
+                )
+            }
+        };
+        steps += 1;
+    }
 
     Ok(steps)
 }
 
-pub fn get_part_two(input: &str) -> Result<usize, String> {
+pub fn get_part_two(input: &str) -> Result<usize> {
     let mut lines = input.trim().lines();
 
-    let mut steps = lines.next().ok_or(format!("bad input: {}", input))?.len();
+    let mut steps = lines.next().context("expected instructions")?.len();
     let nodes = lines
+        .enumerate()
         .skip(2)
-        .map(|line| {
-            let start = &line[..3];
-            let left = &line[7..10];
-            let right = &line[12..15];
-
-            (start, (left, right))
-        })
-        .collect::<HashMap<_, _>>();
+        .map(|(i, line)| parse_node(line).map_err(|error| error.on_line(i + 2)))
+        .collect::<Result<HashMap<_, _>>>()?;
 
     let mut queue = VecDeque::new();
     let mut seen = HashSet::new();
 
-    nodes
-        .keys()
-        .filter(|key| key.ends_with('A'))
-        .for_each(|start| {
-            queue.push_back((start, 0));
-            seen.insert(start);
-
-            while let Some((node, cost)) = queue.pop_front() {
-                if node.ends_with('Z') {
-                    steps = num_integer::lcm(steps, cost);
-                    break;
-                }
+    for start in nodes.keys().filter(|key| key.ends_with('A')) {
+        queue.push_back((start, 0));
+        seen.insert(start);
+
+        while let Some((node, cost)) = queue.pop_front() {
+            if node.ends_with('Z') {
+                steps = aoc_math::lcm(steps, cost).context("steps overflowed")?;
+                break;
+            }
 
-                if let Some((left, right)) = nodes.get(node) {
-                    if seen.insert(left) {
-                        queue.push_back((left, cost + 1));
-                    }
-                    if seen.insert(right) {
-                        queue.push_back((right, cost + 1));
-                    }
+            if let Some((left, right)) = nodes.get(node) {
+                if seen.insert(left) {
+                    queue.push_back((left, cost + 1));
+                }
+                if seen.insert(right) {
+                    queue.push_back((right, cost + 1));
                 }
             }
+        }
 
-            queue.clear();
-            seen.clear();
-        });
+        queue.clear();
+        seen.clear();
+    }
 
     Ok(steps)
 }
@@ -5,19 +5,31 @@
 //! Specifically,
 //! [Pascal's Triangle](https://en.wikipedia.org/wiki/Pascal%27s_triangle) can
 //! be used to simplify things.
+use aoc_error::{parse_lines, Context, Error, Result};
 
-pub fn get_part_one(input: &str) -> Result<isize, String> {
-    let report = input
-        .trim()
-        .lines()
-        .map(|line| {
-            line.split_ascii_whitespace()
-                .map(|digit| digit.parse::<isize>())
-                .collect::<Result<Vec<_>, _>>()
-        })
-        .collect::<Result<Vec<Vec<_>>, _>>()
-        .map_err(|e| e.to_string())?;
-    let num_values = report.first().ok_or(format!("bad input: {}", input))?.len() as isize;
+/// Every history must have as many values as the first for the triangle to
+/// line up.
+fn get_report(input: &str) -> Result<Vec<Vec<isize>>> {
+    let report = parse_lines(input.trim(), |line| {
+        line.split_ascii_whitespace()
+            .map(|digit| digit.parse::<isize>())
+            .collect::<Result<Vec<_>, _>>()
+    })?;
+
+    match report
+        .iter()
+        .position(|history| history.len() != report[0].len())
+    {
+        Some(i) => {
+            Err(Error::invalid_input(format!("expected {} values", report[0].len())).on_line(i + 1))
+        }
+        None => Ok(report),
+    }
+}
+
+pub fn get_part_one(input: &str) -> Result<isize> {
+    let report = get_report(input)?;
+    let num_values = report.first().context("expected a history")?.len() as isize;
 
     let mut coefficient = 1;
     let mut triangle = vec![coefficient];
@@ -42,18 +54,9 @@ pub fn get_part_one(input: &str) -> Result<isize, String> {
     Ok(sum.abs())
 }
 
-pub fn get_part_two(input: &str) -> Result<isize, String> {
-    let report = input
-        .trim()
-        .lines()
-        .map(|line| {
-            line.split_ascii_whitespace()
-                .map(|digit| digit.parse::<isize>())
-                .collect::<Result<Vec<_>, _>>()
-        })
-        .collect::<Result<Vec<Vec<_>>, _>>()
-        .map_err(|e| e.to_string())?;
-    let num_values = report.first().ok_or(format!("bad input: {}", input))?.len() as isize;
+pub fn get_part_two(input: &str) -> Result<isize> {
+    let report = get_report(input)?;
+    let num_values = report.first().context("expected a history")?.len() as isize;
 
     let mut coefficient = 1;
     let mut triangle = vec![coefficient];
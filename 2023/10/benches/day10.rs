@@ -1,7 +1,7 @@
 use std::fs;
 use std::hint::black_box;
 
-use day10::*;
+use aoc_2023_10::*;
 
 use criterion::{criterion_group, criterion_main, Criterion};
 
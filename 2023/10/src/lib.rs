@@ -1,5 +1,6 @@
 //! Uses the [Shoelace formula](https://en.wikipedia.org/wiki/Shoelace_formula)
 //! and [Pick's theorem](https://en.wikipedia.org/wiki/Pick%27s_theorem).
+use aoc_error::{Context, Result};
 use num_complex::Complex;
 
 const UP: Complex<isize> = Complex::new(0, -1);
@@ -7,7 +8,24 @@ const DOWN: Complex<isize> = Complex::new(0, 1);
 const LEFT: Complex<isize> = Complex::new(-1, 0);
 const RIGHT: Complex<isize> = Complex::new(1, 0);
 
-pub fn get_part_one(input: &str) -> Result<usize, String> {
+fn get_tile(sketch: &[Vec<char>], position: Complex<isize>) -> Result<char> {
+    let x = usize::try_from(position.re).ok();
+    let y = usize::try_from(position.im).ok();
+
+    y.and_then(|y| sketch.get(y))
+        .zip(x)
+        .and_then(|(row, x)| row.get(x))
+        .copied()
+        .with_context(|| {
+            format!(
+                "the loop leaves the sketch at line {}, column {}",
+                position.im + 1,
+                position.re + 1,
+            )
+        })
+}
+
+pub fn get_part_one(input: &str) -> Result<usize> {
     let sketch = input
         .lines()
         .map(|line| line.chars().collect::<Vec<_>>())
@@ -18,7 +36,7 @@ pub fn get_part_one(input: &str) -> Result<usize, String> {
             (0..sketch[y].len())
                 .find_map(|x| (sketch[y][x] == 'S').then_some(Complex::new(x as isize, y as isize)))
         })
-        .ok_or(format!("no start: {}", input))?;
+        .context("no S marking the start")?;
 
     // TODO: determine?
     let mut direction = RIGHT;
@@ -26,14 +44,12 @@ pub fn get_part_one(input: &str) -> Result<usize, String> {
     let mut steps = 1;
 
     loop {
-        while sketch[position.im as usize][position.re as usize] == '-'
-            || sketch[position.im as usize][position.re as usize] == '|'
-        {
+        while matches!(get_tile(&sketch, position)?, '-' | '|') {
             position += direction;
             steps += 1;
         }
 
-        direction = match sketch[position.im as usize][position.re as usize] {
+        direction = match get_tile(&sketch, position)? {
             '7' if direction == UP => LEFT,
             'F' if direction == UP => RIGHT,
             'J' if direction == DOWN => LEFT,
@@ -54,7 +70,7 @@ fn determinant(a: Complex<isize>, b: Complex<isize>) -> isize {
     a.re * b.im - a.im * b.re
 }
 
-pub fn get_part_two(input: &str) -> Result<isize, String> {
+pub fn get_part_two(input: &str) -> Result<isize> {
     let sketch = input
         .lines()
         .map(|line| line.chars().collect::<Vec<_>>())
@@ -65,10 +81,10 @@ pub fn get_part_two(input: &str) -> Result<isize, String> {
             (0..sketch[y].len())
                 .find_map(|x| (sketch[y][x] == 'S').then_some(Complex::new(x as isize, y as isize)))
         })
-        .ok_or(format!("no start: {}", input))?;
+        .context("no S marking the start")?;
 
     // TODO: true for tests/input but universally…?
-    let mut direction = match sketch[(corner + RIGHT).im as usize][(corner + RIGHT).re as usize] {
+    let mut direction = match get_tile(&sketch, corner + RIGHT)? {
         'F' => DOWN,
         _ => RIGHT,
     };
@@ -77,14 +93,12 @@ pub fn get_part_two(input: &str) -> Result<isize, String> {
     let mut steps = 1;
 
     loop {
-        while sketch[position.im as usize][position.re as usize] == '-'
-            || sketch[position.im as usize][position.re as usize] == '|'
-        {
+        while matches!(get_tile(&sketch, position)?, '-' | '|') {
             position += direction;
             steps += 1;
         }
 
-        direction = match sketch[position.im as usize][position.re as usize] {
+        direction = match get_tile(&sketch, position)? {
             '7' if direction == UP => LEFT,
             'F' if direction == UP => RIGHT,
             'J' if direction == DOWN => LEFT,
@@ -1,6 +1,8 @@
 use std::collections::BTreeSet;
 use std::str::FromStr;
 
+use aoc_error::{Context, Error, Location, Result};
+
 struct GiantImage {
     galaxies: Vec<(usize, usize)>,
     empty_columns: BTreeSet<usize>,
@@ -8,30 +10,29 @@ struct GiantImage {
 }
 
 impl FromStr for GiantImage {
-    type Err = String;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let galaxies = s
-            .trim()
-            .lines()
-            .enumerate()
-            .flat_map(|(y, line)| {
-                line.char_indices().filter_map(move |(x, c)| match c {
-                    '#' => Some((x, y)),
-                    _ => None,
-                })
-            })
-            .collect::<Vec<_>>();
+        let mut galaxies = Vec::new();
+        for (y, line) in s.trim().lines().enumerate() {
+            for (x, c) in line.char_indices() {
+                match c {
+                    '#' => galaxies.push((x, y)),
+                    '.' => {}
+                    _ => {
+                        return Err(Error::invalid_input(format!("unexpected {:?}", c))
+                            .at(Location::line_column(y + 1, x + 1)))
+                    }
+                }
+            }
+        }
 
         let width = galaxies
             .iter()
             .max_by(|a, b| a.0.cmp(&b.0))
-            .ok_or(format!("invalid input: {:?}", galaxies))?
+            .context("no galaxies")?
             .0;
-        let height = galaxies
-            .last()
-            .ok_or(format!("invalid input: {:?}", galaxies))?
-            .1;
+        let height = galaxies.last().context("no galaxies")?.1;
 
         let empty_columns = (0..=width)
             .filter(|x| !galaxies.iter().any(|(gx, _)| gx == x))
@@ -75,13 +76,13 @@ impl GiantImage {
     }
 }
 
-pub fn get_part_one(input: &str) -> Result<usize, String> {
+pub fn get_part_one(input: &str) -> Result<usize> {
     let image = GiantImage::from_str(input)?;
 
     Ok(image.get_lengths_sum(2))
 }
 
-pub fn get_part_two(input: &str, expansion: usize) -> Result<usize, String> {
+pub fn get_part_two(input: &str, expansion: usize) -> Result<usize> {
     let image = GiantImage::from_str(input)?;
 
     Ok(image.get_lengths_sum(expansion))
@@ -1,7 +1,7 @@
 use std::fs;
 use std::hint::black_box;
 
-use day12::*;
+use aoc_2023_12::*;
 
 use criterion::{criterion_group, criterion_main, Criterion};
 
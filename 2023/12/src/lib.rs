@@ -1,7 +1,8 @@
-use rayon::iter::ParallelIterator;
-use rayon::str::ParallelString;
 use std::collections::BTreeMap;
 
+use aoc_error::{parse_lines, Context, Error, Location, Result};
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+
 fn count_the_ways(
     cache: &mut BTreeMap<(usize, usize, usize), usize>,
     springs: &[u8],
@@ -65,57 +66,54 @@ fn count_the_ways(
     ways
 }
 
-pub fn get_part_one(input: &str) -> Result<usize, String> {
-    let mut cache = BTreeMap::new();
+fn parse_record(line: &str) -> Result<(&str, Vec<usize>)> {
+    let (springs, sizes) = line.split_once(' ').context("expected springs and sizes")?;
+    if let Some((i, c)) = springs
+        .char_indices()
+        .find(|(_, c)| !matches!(c, '.' | '#' | '?'))
+    {
+        return Err(
+            Error::invalid_input(format!("unexpected {:?}", c)).at(Location::line_column(1, i + 1))
+        );
+    }
+    let sizes = sizes
+        .split(',')
+        .map(|size| size.parse::<usize>())
+        .collect::<Result<Vec<_>, _>>()?;
 
-    let ways = input
-        .trim()
-        .lines()
-        .map(|line| {
-            let (springs, sizes) = line
-                .split_once(' ')
-                .ok_or(format!("bad input: {}", input))?;
-            let sizes = sizes
-                .split(',')
-                .map(|size| size.parse::<usize>())
-                .collect::<Result<Vec<_>, _>>()
-                .map_err(|e| e.to_string())?;
-            let springs = springs.as_bytes();
+    Ok((springs, sizes))
+}
+
+pub fn get_part_one(input: &str) -> Result<usize> {
+    let mut cache = BTreeMap::new();
 
+    let ways = parse_lines(input.trim(), parse_record)?
+        .iter()
+        .map(|(springs, sizes)| {
             cache.clear();
 
-            Ok::<usize, String>(count_the_ways(&mut cache, springs, None, &sizes))
+            count_the_ways(&mut cache, springs.as_bytes(), None, sizes)
         })
-        .collect::<Result<Vec<_>, _>>()?;
+        .sum();
 
-    Ok(ways.iter().sum())
+    Ok(ways)
 }
 
-pub fn get_part_two(input: &str) -> Result<usize, String> {
-    let ways = input
-        .trim()
-        .par_lines()
-        .map(|line| {
-            let (springs, sizes) = line
-                .split_once(' ')
-                .ok_or(format!("bad input: {}", input))?;
-            let sizes = sizes
-                .split(',')
-                .map(|size| size.parse::<usize>())
-                .collect::<Result<Vec<_>, _>>()
-                .map_err(|e| e.to_string())?;
-
-            let springs = (0..5).map(|_| springs).collect::<Vec<_>>().join("?");
+pub fn get_part_two(input: &str) -> Result<usize> {
+    let ways = parse_lines(input.trim(), parse_record)?
+        .par_iter()
+        .map(|(springs, sizes)| {
+            let springs = (0..5).map(|_| *springs).collect::<Vec<_>>().join("?");
             let springs = springs.as_bytes();
-            let sizes = (0..5).flat_map(|_| &sizes).copied().collect::<Vec<_>>();
+            let sizes = (0..5).flat_map(|_| sizes).copied().collect::<Vec<_>>();
 
             let mut cache = BTreeMap::new();
 
-            Ok::<usize, String>(count_the_ways(&mut cache, springs, None, &sizes))
+            count_the_ways(&mut cache, springs, None, &sizes)
         })
-        .collect::<Result<Vec<_>, _>>()?;
+        .sum();
 
-    Ok(ways.iter().sum())
+    Ok(ways)
 }
 
 #[cfg(test)]
@@ -1,3 +1,7 @@
+use aoc_error::{Error, Result};
+
+type Pattern<'a> = Vec<&'a [u8]>;
+
 fn find_column_reflection(pattern: &[&[u8]], limit: usize) -> Option<usize> {
     (0..pattern[0].len() - 1).find(|&column| {
         let smudged = (0..=column.min(pattern[0].len() - column - 2))
@@ -30,46 +34,53 @@ fn find_row_reflection(pattern: &[&[u8]], limit: usize) -> Option<usize> {
     })
 }
 
-fn get_summary(patterns: Vec<Vec<&[u8]>>, limit: usize) -> Result<usize, String> {
-    let notes = patterns
-        .iter()
+/// Each pattern along with the line of the input it starts on.
+fn get_patterns(input: &str) -> Result<Vec<(usize, Pattern<'_>)>> {
+    let mut line = 1;
+
+    input
+        .trim()
+        .split("\n\n")
         .map(|pattern| {
+            let start = line;
+            line += pattern.lines().count() + 1;
+
+            let rows = pattern.lines().map(str::as_bytes).collect::<Vec<_>>();
+            if rows.is_empty() {
+                return Err(Error::invalid_input("empty pattern").on_line(start));
+            }
+
+            match rows.iter().position(|row| row.len() != rows[0].len()) {
+                Some(i) => Err(
+                    Error::invalid_input(format!("expected {} columns", rows[0].len()))
+                        .on_line(start + i),
+                ),
+                None => Ok((start, rows)),
+            }
+        })
+        .collect()
+}
+
+fn get_summary(input: &str, limit: usize) -> Result<usize> {
+    let notes = get_patterns(input)?
+        .iter()
+        .map(|(line, pattern)| {
             find_row_reflection(pattern, limit)
                 .map(|row| (row + 1) * 100)
                 .or_else(|| find_column_reflection(pattern, limit).map(|column| column + 1))
-                .ok_or("could not find reflection".to_owned())
+                .ok_or_else(|| Error::no_solution("could not find reflection").on_line(*line))
         })
-        .collect::<Result<Vec<_>, String>>()?;
+        .collect::<Result<Vec<_>>>()?;
 
     Ok(notes.iter().sum())
 }
 
-pub fn get_part_one(input: &str) -> Result<usize, String> {
-    let patterns = input
-        .split("\n\n")
-        .map(|pattern| {
-            pattern
-                .lines()
-                .map(|line| line.as_bytes())
-                .collect::<Vec<_>>()
-        })
-        .collect::<Vec<_>>();
-
-    get_summary(patterns, 0)
+pub fn get_part_one(input: &str) -> Result<usize> {
+    get_summary(input, 0)
 }
 
-pub fn get_part_two(input: &str) -> Result<usize, String> {
-    let patterns = input
-        .split("\n\n")
-        .map(|pattern| {
-            pattern
-                .lines()
-                .map(|line| line.as_bytes())
-                .collect::<Vec<_>>()
-        })
-        .collect::<Vec<_>>();
-
-    get_summary(patterns, 1)
+pub fn get_part_two(input: &str) -> Result<usize> {
+    get_summary(input, 1)
 }
 
 #[cfg(test)]
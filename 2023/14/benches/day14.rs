@@ -1,7 +1,7 @@
 use std::fs;
 use std::hint::black_box;
 
-use day14::*;
+use aoc_2023_14::*;
 
 use criterion::{criterion_group, criterion_main, Criterion};
 
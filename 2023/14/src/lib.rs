@@ -1,90 +1,62 @@
 use std::collections::BTreeMap;
-use std::mem;
 
-fn tilt_north(positions: &mut Vec<Vec<u8>>) {
-    (0..(positions.len() - 1)).for_each(|y| {
-        (0..positions[0].len()).for_each(|x| {
-            if positions[y][x] == b'.'
-                && (y == 0 || (positions[y - 1][x] == b'#' || positions[y - 1][x] == b'O'))
-            {
-                if let Some((ty, b'O')) =
-                    ((y + 1)..positions.len()).find_map(|ty| match positions[ty][x] {
-                        b'#' => Some((ty, b'#')),
-                        b'O' => Some((ty, b'O')),
-                        _ => None,
-                    })
-                {
-                    let (a, b) = positions.split_at_mut(ty);
-                    mem::swap(&mut a[y][x], &mut b[0][x]);
+use aoc_error::Result;
+use aoc_grid::Grid;
+
+fn tilt_north(platform: &mut Grid<u8>) {
+    for x in 0..platform.width() {
+        let mut free = 0;
+        for y in 0..platform.height() {
+            match platform[(x, y)] {
+                b'#' => free = y + 1,
+                b'O' => {
+                    platform[(x, y)] = b'.';
+                    platform[(x, free)] = b'O';
+                    free += 1;
                 }
+                _ => {}
             }
-        });
-    });
-}
-
-fn rotate_clockwise(positions: Vec<Vec<u8>>) -> Vec<Vec<u8>> {
-    (0..positions[0].len())
-        .map(|x| positions.iter().rev().map(|row| row[x]).collect::<Vec<_>>())
-        .collect::<Vec<_>>()
+        }
+    }
 }
 
-#[allow(unused)]
-fn display(positions: &[Vec<u8>]) -> String {
-    positions
+fn total_load(platform: &Grid<u8>) -> usize {
+    platform
         .iter()
-        .map(|row| row.iter().map(|b| *b as char).collect::<String>())
-        .collect::<Vec<_>>()
-        .join("\n")
+        .filter(|(_, rock)| **rock == b'O')
+        .map(|((_, y), _)| platform.height() - y)
+        .sum()
 }
 
-fn total_load(positions: Vec<Vec<u8>>) -> usize {
-    let height = positions.len();
-
-    positions
-        .iter()
-        .enumerate()
-        .flat_map(|(y, row)| {
-            row.iter().filter_map(move |&column| match column {
-                b'O' => Some(height - y),
-                _ => None,
-            })
-        })
-        .sum()
+fn get_platform(input: &str) -> Result<Grid<u8>> {
+    input.trim().parse()
 }
 
-pub fn get_part_one(input: &str) -> Result<usize, String> {
-    let mut positions = input
-        .trim()
-        .lines()
-        .map(|line| line.as_bytes().to_vec())
-        .collect::<Vec<_>>();
+pub fn get_part_one(input: &str) -> Result<usize> {
+    let mut platform = get_platform(input)?;
 
-    tilt_north(&mut positions);
+    tilt_north(&mut platform);
 
-    Ok(total_load(positions))
+    Ok(total_load(&platform))
 }
 
-pub fn get_part_two(input: &str) -> Result<usize, String> {
-    let mut positions = input
-        .trim()
-        .lines()
-        .map(|line| line.as_bytes().to_vec())
-        .collect::<Vec<_>>();
+pub fn get_part_two(input: &str) -> Result<usize> {
+    let mut platform = get_platform(input)?;
 
     let mut seen = BTreeMap::new();
     for cycle in 1.. {
         for _ in 0..4 {
-            tilt_north(&mut positions);
-            positions = rotate_clockwise(positions);
+            tilt_north(&mut platform);
+            platform = platform.rotate_clockwise();
         }
-        if let Some(seen_cycle) = seen.insert(positions.clone(), cycle) {
+        if let Some(seen_cycle) = seen.insert(platform.clone(), cycle) {
             if (1000000000 - cycle) % (cycle - seen_cycle) == 0 {
                 break;
             }
         }
     }
 
-    Ok(total_load(positions))
+    Ok(total_load(&platform))
 }
 
 #[cfg(test)]
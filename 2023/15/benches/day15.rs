@@ -1,7 +1,7 @@
 use std::fs;
 use std::hint::black_box;
 
-use day15::*;
+use aoc_2023_15::*;
 
 use criterion::{criterion_group, criterion_main, Criterion};
 
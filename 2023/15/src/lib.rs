@@ -1,3 +1,5 @@
+use aoc_error::{Context, Error, Result};
+
 enum Operation {
     Remove,
     Insert(usize),
@@ -59,21 +61,21 @@ fn hash(string: &str) -> usize {
         .fold(0, |acc, c| ((acc + *c as usize) * 17) % 256)
 }
 
-pub fn get_part_one(input: &str) -> Result<usize, String> {
+pub fn get_part_one(input: &str) -> Result<usize> {
     let sum = input.trim().split(',').map(hash).sum();
 
     Ok(sum)
 }
 
-pub fn get_part_two(input: &str) -> Result<usize, String> {
-    let steps = manual::steps(input.trim()).map_err(|e| e.to_string())?;
+pub fn get_part_two(input: &str) -> Result<usize> {
+    let steps = manual::steps(input.trim())?;
 
     let boxes = steps
         .iter()
         .try_fold(vec![vec![]; 256], |mut boxes, step| {
             let box_: &mut Vec<&Step> = boxes
                 .get_mut(step.hash)
-                .ok_or(format!("no boxes at {}", step.hash))?;
+                .with_context(|| format!("no boxes at {}", step.hash))?;
 
             match step.operation {
                 Operation::Remove => {
@@ -90,7 +92,7 @@ pub fn get_part_two(input: &str) -> Result<usize, String> {
                 },
             };
 
-            Ok::<Vec<Vec<_>>, String>(boxes)
+            Ok::<Vec<Vec<_>>, Error>(boxes)
         })?;
 
     let focusing_power = boxes
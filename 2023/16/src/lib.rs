@@ -1,6 +1,8 @@
-use rayon::prelude::*;
 use std::collections::VecDeque;
 
+use aoc_error::{parse_lines, Context, Error, Location, Result};
+use rayon::prelude::*;
+
 #[derive(Clone, Copy)]
 enum Direction {
     Left,
@@ -109,12 +111,34 @@ fn get_energized_tiles(contraption: &[Vec<char>], start: Beam) -> usize {
         .count()
 }
 
-pub fn get_part_one(input: &str) -> Result<usize, String> {
-    let contraption = input
-        .trim()
-        .lines()
-        .map(|line| line.chars().collect::<Vec<_>>())
-        .collect::<Vec<_>>();
+/// The beams assume the contraption is square.
+fn get_contraption(input: &str) -> Result<Vec<Vec<char>>> {
+    let contraption = parse_lines(input.trim(), |line| {
+        line.char_indices()
+            .map(|(x, c)| match c {
+                '.' | '/' | '\\' | '|' | '-' => Ok(c),
+                _ => Err(Error::invalid_input(format!("unexpected {:?}", c))
+                    .at(Location::line_column(1, x + 1))),
+            })
+            .collect::<Result<Vec<_>>>()
+    })?;
+    if contraption.is_empty() {
+        return Err(Error::invalid_input("empty contraption"));
+    }
+
+    match contraption
+        .iter()
+        .position(|row| row.len() != contraption.len())
+    {
+        Some(y) => Err(
+            Error::invalid_input(format!("expected {} columns", contraption.len())).on_line(y + 1),
+        ),
+        None => Ok(contraption),
+    }
+}
+
+pub fn get_part_one(input: &str) -> Result<usize> {
+    let contraption = get_contraption(input)?;
 
     Ok(get_energized_tiles(
         &contraption,
@@ -126,12 +150,8 @@ pub fn get_part_one(input: &str) -> Result<usize, String> {
     ))
 }
 
-pub fn get_part_two(input: &str) -> Result<usize, String> {
-    let contraption = input
-        .trim()
-        .lines()
-        .map(|line| line.chars().collect::<Vec<_>>())
-        .collect::<Vec<_>>();
+pub fn get_part_two(input: &str) -> Result<usize> {
+    let contraption = get_contraption(input)?;
 
     let energized_tiles = (0..contraption.len())
         .flat_map(|y| {
@@ -149,7 +169,7 @@ pub fn get_part_two(input: &str) -> Result<usize, String> {
         .par_bridge()
         .map(|start| get_energized_tiles(&contraption, start))
         .max()
-        .ok_or(format!("couldn't cound energized tiles: {}", input))?;
+        .context("empty contraption")?;
 
     Ok(energized_tiles)
 }
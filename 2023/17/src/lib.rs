@@ -1,15 +1,18 @@
 use std::cmp::Reverse;
 use std::collections::{BinaryHeap, HashMap};
 
-fn dfs(map: Vec<&[u8]>, min_blocks: isize, max_blocks: isize) -> isize {
+use aoc_error::{Error, Result};
+use aoc_grid::Grid;
+
+fn dfs(map: &Grid<isize>, min_blocks: isize, max_blocks: isize) -> Result<isize> {
     let mut stack = BinaryHeap::new();
     let mut costs = HashMap::new();
 
     stack.push((Reverse(0), (0, 0, (0, 0))));
 
     while let Some((Reverse(heat_loss), (y, x, direction))) = stack.pop() {
-        if (y, x) == (map.len() - 1, map[0].len() - 1) {
-            return heat_loss;
+        if (y + 1, x + 1) == (map.height(), map.width()) {
+            return Ok(heat_loss);
         }
 
         if costs
@@ -27,10 +30,10 @@ fn dfs(map: Vec<&[u8]>, min_blocks: isize, max_blocks: isize) -> isize {
             for block in 1..=max_blocks {
                 let ny = (y as isize + dy * block) as usize;
                 let nx = (x as isize + dx * block) as usize;
-                if ny >= map.len() || nx >= map[0].len() {
+                if !map.contains((nx, ny)) {
                     continue;
                 }
-                next_cost += (map[ny][nx] - b'0') as isize;
+                next_cost += map[(nx, ny)];
                 if block < min_blocks {
                     continue;
                 }
@@ -43,19 +46,23 @@ fn dfs(map: Vec<&[u8]>, min_blocks: isize, max_blocks: isize) -> isize {
         }
     }
 
-    panic!("failed to determine minimum heat loss")
+    Err(Error::no_solution("failed to determine minimum heat loss"))
 }
 
-pub fn get_part_one(input: &str) -> Result<isize, String> {
-    let map = input.trim().lines().map(str::as_bytes).collect::<Vec<_>>();
-
-    Ok(dfs(map, 1, 3))
+fn get_map(input: &str) -> Result<Grid<isize>> {
+    Grid::parse(input.trim(), |c| {
+        c.to_digit(10)
+            .map(|digit| digit as isize)
+            .ok_or_else(|| Error::invalid_input(format!("invalid digit {:?}", c)))
+    })
 }
 
-pub fn get_part_two(input: &str) -> Result<isize, String> {
-    let map = input.trim().lines().map(str::as_bytes).collect::<Vec<_>>();
+pub fn get_part_one(input: &str) -> Result<isize> {
+    dfs(&get_map(input)?, 1, 3)
+}
 
-    Ok(dfs(map, 4, 10))
+pub fn get_part_two(input: &str) -> Result<isize> {
+    dfs(&get_map(input)?, 4, 10)
 }
 
 #[cfg(test)]
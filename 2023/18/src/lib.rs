@@ -1,19 +1,22 @@
+use aoc_error::{parse_lines, Context, Error, Result};
 use itertools::Itertools;
 
-pub fn get_part_one(input: &str) -> Result<isize, String> {
-    let dig_plan = input
-        .trim()
-        .lines()
-        .map(|line| {
-            let (direction, suffix) = line.split_once(' ').ok_or(format!("bad line: {}", line))?;
-            let (metres, _) = suffix
-                .split_once(' ')
-                .ok_or(format!("bad line: {}", line))?;
-            let metres = metres.parse::<isize>().map_err(|e| e.to_string())?;
-
-            Ok::<(_, _), String>((direction, metres))
-        })
-        .collect::<Result<Vec<_>, _>>()?;
+pub fn get_part_one(input: &str) -> Result<isize> {
+    let dig_plan = parse_lines(input.trim(), |line| {
+        let (direction, suffix) = line.split_once(' ').context("expected a direction")?;
+        let (metres, _) = suffix
+            .split_once(' ')
+            .context("expected metres and a colour")?;
+        if !matches!(direction, "U" | "D" | "L" | "R") {
+            return Err(Error::invalid_input(format!(
+                "invalid direction {:?}",
+                direction
+            )));
+        }
+        let metres = metres.parse::<isize>()?;
+
+        Ok((direction, metres))
+    })?;
 
     let positions = dig_plan
         .iter()
@@ -49,17 +52,23 @@ pub fn get_part_one(input: &str) -> Result<isize, String> {
     Ok(((area / 2) - (perimeter / 2) + 1) + perimeter)
 }
 
-pub fn get_part_two(input: &str) -> Result<isize, String> {
-    let dig_plan = input
-        .trim()
-        .lines()
-        .map(|line| {
-            let (_, hex) = line.split_once('#').ok_or(format!("bad line: {}", line))?;
-            let metres = isize::from_str_radix(&hex[..5], 16).map_err(|e| e.to_string())?;
-
-            Ok::<(_, _), String>((&hex[5..=5], metres))
-        })
-        .collect::<Result<Vec<_>, _>>()?;
+pub fn get_part_two(input: &str) -> Result<isize> {
+    let dig_plan = parse_lines(input.trim(), |line| {
+        let (_, hex) = line.split_once('#').context("expected a colour")?;
+        let (metres, direction) = hex
+            .get(..5)
+            .zip(hex.get(5..6))
+            .context("expected six hex digits")?;
+        if !matches!(direction, "0" | "1" | "2" | "3") {
+            return Err(Error::invalid_input(format!(
+                "invalid direction {:?}",
+                direction
+            )));
+        }
+        let metres = isize::from_str_radix(metres, 16)?;
+
+        Ok((direction, metres))
+    })?;
 
     let positions = dig_plan
         .iter()
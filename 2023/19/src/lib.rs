@@ -1,5 +1,7 @@
 use std::collections::HashMap;
 
+use aoc_error::{Context, Result};
+
 #[derive(Debug)]
 enum Operation {
     LessThan,
@@ -119,7 +121,7 @@ peg::parser! {
 
         rule part() -> Part
             = "{"
-              values:rating() ++ ","
+              values:rating() **<4> ","
               "}"
               {
                 values
@@ -136,21 +138,23 @@ peg::parser! {
     }
 }
 
-pub fn get_part_one(input: &str) -> Result<usize, String> {
-    let (workflows, parts) = ratings::input(input.trim()).map_err(|e| e.to_string())?;
-
-    let ratings = parts
-        .iter()
-        .filter(|part| {
-            // TODO: there must be a nicer way to do this…?!
-            let mut label = "in";
-            while label != "A" && label != "R" {
-                label = workflows.get(&label).unwrap().perform(part);
-            }
-            label == "A"
-        })
-        .map(|part| part.iter().sum::<usize>())
-        .sum();
+pub fn get_part_one(input: &str) -> Result<usize> {
+    let (workflows, parts) = ratings::input(input.trim())?;
+
+    let mut ratings = 0;
+    for part in &parts {
+        // TODO: there must be a nicer way to do this…?!
+        let mut label = "in";
+        while label != "A" && label != "R" {
+            label = workflows
+                .get(&label)
+                .with_context(|| format!("no workflow {:?}", label))?
+                .perform(part);
+        }
+        if label == "A" {
+            ratings += part.iter().sum::<usize>();
+        }
+    }
 
     Ok(ratings)
 }
@@ -159,7 +163,7 @@ fn get_combinations(
     workflows: &HashMap<&str, Workflow>,
     label: &str,
     mut part_ranges: Vec<Vec<usize>>,
-) -> Result<usize, String> {
+) -> Result<usize> {
     if label == "A" {
         return Ok(part_ranges
             .iter()
@@ -173,7 +177,7 @@ fn get_combinations(
 
     let workflow = workflows
         .get(label)
-        .ok_or(format!("bad label: {}", label))?;
+        .with_context(|| format!("no workflow {:?}", label))?;
 
     let combinations = workflow
         .rules
@@ -196,8 +200,8 @@ fn get_combinations(
     Ok(combinations + get_combinations(workflows, workflow.otherwise, part_ranges)?)
 }
 
-pub fn get_part_two(input: &str) -> Result<usize, String> {
-    let (workflows, _) = ratings::input(input.trim()).map_err(|e| e.to_string())?;
+pub fn get_part_two(input: &str) -> Result<usize> {
+    let (workflows, _) = ratings::input(input.trim())?;
 
     let part_ranges = (0..4)
         .map(|_| (1..=4000).collect::<Vec<_>>())
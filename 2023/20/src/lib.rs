@@ -1,5 +1,7 @@
 use std::collections::{HashMap, VecDeque};
 
+use aoc_error::{Context, Error, Result};
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 enum Pulse {
     High,
@@ -16,37 +18,43 @@ struct Module<'a> {
     destinations: Vec<&'a str>,
 }
 
-fn from_str(input: &str) -> Result<(Vec<&str>, HashMap<&str, Module>), String> {
+fn parse_module(line: &str) -> Result<(&str, Module)> {
+    let kind = match line.chars().next() {
+        Some('%') => Type::FlipFlop { off: true },
+        Some('&') => Type::Conjunction {
+            last_pulses: vec![],
+        },
+        _ => return Err(Error::invalid_input(format!("invalid module {:?}", line))),
+    };
+    let (name, outputs) = line[1..]
+        .split_once(" -> ")
+        .context("expected a name and destinations")?;
+    let outputs = outputs.split(", ").collect::<Vec<_>>();
+
+    let module = Module {
+        r#type: kind,
+        destinations: outputs,
+    };
+
+    Ok((name, module))
+}
+
+fn from_str(input: &str) -> Result<(Vec<&str>, HashMap<&str, Module>)> {
     let broadcaster_targets = input
         .lines()
         .find_map(|line| {
             line.strip_prefix("broadcaster -> ")
                 .map(|targets| targets.split(", ").collect::<Vec<_>>())
         })
-        .ok_or(format!("couldn't find broadcaster: {}", input))?;
+        .context("couldn't find broadcaster")?;
 
     let mut rules = input
+        .trim_end()
         .lines()
-        .filter(|line| !line.starts_with("broadcaster"))
-        .map(|line| {
-            let (name, outputs) = line[1..].split_once(" -> ").ok_or(format!("bad line: {}", line))?;
-            let outputs = outputs.split(", ").collect::<Vec<_>>();
-
-            let kind = match line.chars().next() {
-                Some('%') => Type::FlipFlop { off: true },
-                Some('&') => Type::Conjunction {
-                    last_pulses: vec![],
-                },
-                _ => unreachable!("invalid module kind"),
-            };
-
-            let module = Module {
-                r#type: kind,
-                destinations: outputs,
-            };
-            Ok((name, module))
-        })
-        .collect::<Result<HashMap<_, _>, String>>()?;
+        .enumerate()
+        .filter(|(_, line)| !line.starts_with("broadcaster"))
+        .map(|(i, line)| parse_module(line).map_err(|error| error.on_line(i + 1)))
+        .collect::<Result<HashMap<_, _>>>()?;
 
     let input_to_outputs = rules
         .iter()
@@ -76,7 +84,7 @@ fn pulsify<'a>(
     name: &'a str,
     sender: &'a str,
     pulse: Pulse,
-) -> Result<(), String> {
+) -> Result<()> {
     let Some(module) = rules.get_mut(name) else {
         return Ok(());
     };
@@ -93,7 +101,7 @@ fn pulsify<'a>(
             last_pulses
                 .iter_mut()
                 .find(|(input, _pulse)| input == &sender)
-                .ok_or("cannot find conjunction".to_owned())?
+                .context("cannot find conjunction")?
                 .1 = pulse;
 
             let all_high = last_pulses.iter().all(|(_, pulse)| *pulse == Pulse::High);
@@ -114,7 +122,7 @@ fn pulsify<'a>(
     Ok(())
 }
 
-pub fn get_part_one(input: &str) -> Result<usize, String> {
+pub fn get_part_one(input: &str) -> Result<usize> {
     let (broadcaster_targets, mut modules) = from_str(input)?;
     let mut low_pulses = 1000;
     let mut high_pulses = 0;
@@ -139,18 +147,21 @@ pub fn get_part_one(input: &str) -> Result<usize, String> {
     Ok(low_pulses * high_pulses)
 }
 
-pub fn get_part_two(input: &str) -> Result<usize, String> {
+pub fn get_part_two(input: &str) -> Result<usize> {
     let (broadcaster_targets, mut rules) = from_str(input)?;
 
     let rx_sender = rules
         .iter()
         .find_map(|(name, module)| module.destinations.contains(&"rx").then_some(*name))
-        .ok_or("couldn't find senders to rx".to_owned())?;
+        .context("couldn't find senders to rx")?;
 
     let rx_sender_senders = {
         let module = &rules[rx_sender];
         let Type::Conjunction { last_pulses } = &module.r#type else {
-            unreachable!();
+            return Err(Error::invalid_input(format!(
+                "{} sends to rx but isn't a conjunction",
+                rx_sender
+            )));
         };
         last_pulses
             .iter()
@@ -175,7 +186,7 @@ pub fn get_part_two(input: &str) -> Result<usize, String> {
                 let sender = rx_sender_senders
                     .iter()
                     .position(|input| input == &parent_name)
-                    .ok_or("couldn't find rx sender".to_owned())?;
+                    .context("couldn't find rx sender")?;
 
                 if button_pushes[sender].is_none() && pulse == Pulse::High {
                     button_pushes[sender] = Some(pushes);
@@ -186,11 +197,14 @@ pub fn get_part_two(input: &str) -> Result<usize, String> {
         pushes += 1;
     }
 
+    let button_pushes = button_pushes.into_iter().flatten().collect::<Vec<_>>();
+    if button_pushes.is_empty() {
+        return Err(Error::no_solution("couldn't find fewest"));
+    }
     let fewest = button_pushes
         .into_iter()
-        .flatten()
-        .reduce(num_integer::lcm)
-        .ok_or("couldn't find fewest".to_owned())?;
+        .try_fold(1, aoc_math::lcm)
+        .context("fewest overflowed")?;
 
     Ok(fewest)
 }
@@ -1,5 +1,9 @@
 use std::collections::{HashMap, HashSet, VecDeque};
 
+use aoc_error::{Context, Error, Location, Result};
+
+type Garden = Vec<Vec<u8>>;
+
 #[allow(dead_code)]
 fn to_string(garden: &[Vec<u8>]) -> String {
     garden
@@ -83,50 +87,51 @@ fn distances(garden: &[Vec<u8>], start: (isize, isize)) -> HashMap<(isize, isize
     distances
 }
 
-pub fn get_part_one(input: &str, target: isize) -> Result<usize, String> {
-    let mut start = (0, 0);
-    let garden = input
-        .trim()
-        .lines()
-        .enumerate()
-        .map(|(y, line)| {
-            line.as_bytes()
-                .iter()
-                .enumerate()
-                .map(|(x, position)| match position {
-                    b'S' => {
-                        start = (x as isize, y as isize);
-                        b'.'
-                    }
-                    p => *p,
-                })
-                .collect::<Vec<_>>()
-        })
-        .collect::<Vec<_>>();
+/// The garden, with its `S` replaced by a plot, and where that `S` was.
+fn get_garden(input: &str) -> Result<(Garden, (isize, isize))> {
+    let mut start = None;
+    let mut garden = Vec::new();
+
+    for (y, line) in input.trim().lines().enumerate() {
+        let row = line
+            .bytes()
+            .enumerate()
+            .map(|(x, position)| match position {
+                b'S' => {
+                    start = Some((x as isize, y as isize));
+                    Ok(b'.')
+                }
+                b'.' | b'#' => Ok(position),
+                _ => Err(
+                    Error::invalid_input(format!("unexpected {:?}", position as char))
+                        .at(Location::line_column(y + 1, x + 1)),
+                ),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        if garden
+            .first()
+            .is_some_and(|first: &Vec<u8>| first.len() != row.len())
+        {
+            return Err(
+                Error::invalid_input(format!("expected {} columns", garden[0].len()))
+                    .on_line(y + 1),
+            );
+        }
+
+        garden.push(row);
+    }
+
+    Ok((garden, start.context("no S marking the start")?))
+}
+
+pub fn get_part_one(input: &str, target: isize) -> Result<usize> {
+    let (garden, start) = get_garden(input)?;
 
     Ok(bfs(&garden, start, target).len())
 }
 
-pub fn get_part_two(input: &str, target: isize) -> Result<isize, String> {
-    let mut start = (0, 0);
-    let garden = input
-        .trim()
-        .lines()
-        .enumerate()
-        .map(|(y, line)| {
-            line.as_bytes()
-                .iter()
-                .enumerate()
-                .map(|(x, position)| match position {
-                    b'S' => {
-                        start = (x as isize, y as isize);
-                        b'.'
-                    }
-                    p => *p,
-                })
-                .collect::<Vec<_>>()
-        })
-        .collect::<Vec<_>>();
+pub fn get_part_two(input: &str, target: isize) -> Result<isize> {
+    let (garden, start) = get_garden(input)?;
 
     let distances = distances(&garden, start);
 
@@ -1,7 +1,7 @@
 use std::fs;
 use std::hint::black_box;
 
-use day22::*;
+use aoc_2023_22::*;
 
 use criterion::{criterion_group, criterion_main, Criterion};
 
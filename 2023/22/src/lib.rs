@@ -3,6 +3,8 @@ use std::{
     str::FromStr,
 };
 
+use aoc_error::{parse_lines, Error, Result};
+
 struct Brick {
     x: (usize, usize),
     y: (usize, usize),
@@ -10,22 +12,22 @@ struct Brick {
 }
 
 impl FromStr for Brick {
-    type Err = String;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let numbers = s
             .splitn(6, &[',', '~'])
             .map(|n| n.parse::<usize>())
-            .collect::<Result<Vec<_>, _>>()
-            .map_err(|e| e.to_string())?;
+            .collect::<Result<Vec<_>, _>>()?;
 
         match &numbers[..] {
-            [ax, ay, az, bx, by, bz] => Ok(Self {
+            [ax, ay, az, bx, by, bz] if ax <= bx && ay <= by && az <= bz => Ok(Self {
                 x: (*ax, *bx),
                 y: (*ay, *by),
                 z: (*az, *bz),
             }),
-            _ => Err(format!("invalid line: {}", s)),
+            [_, _, _, _, _, _] => Err(Error::invalid_input("expected the lower end first")),
+            _ => Err(Error::invalid_input("expected two ends")),
         }
     }
 }
@@ -45,12 +47,8 @@ impl Brick {
     }
 }
 
-pub fn get_part_one(input: &str) -> Result<usize, String> {
-    let mut bricks = input
-        .trim()
-        .lines()
-        .map(Brick::from_str)
-        .collect::<Result<Vec<_>, _>>()?;
+pub fn get_part_one(input: &str) -> Result<usize> {
+    let mut bricks = parse_lines(input.trim(), Brick::from_str)?;
 
     bricks.sort_unstable_by_key(|brick| brick.z.0);
 
@@ -101,12 +99,8 @@ pub fn get_part_one(input: &str) -> Result<usize, String> {
     Ok(bricks.len() - unsafe_.len())
 }
 
-pub fn get_part_two(input: &str) -> Result<usize, String> {
-    let mut bricks = input
-        .trim()
-        .lines()
-        .map(Brick::from_str)
-        .collect::<Result<Vec<_>, _>>()?;
+pub fn get_part_two(input: &str) -> Result<usize> {
+    let mut bricks = parse_lines(input.trim(), Brick::from_str)?;
 
     bricks.sort_unstable_by_key(|brick| brick.z.0);
 
@@ -1,7 +1,7 @@
 use std::fs;
 use std::hint::black_box;
 
-use day23::*;
+use aoc_2023_23::*;
 
 use criterion::{criterion_group, criterion_main, Criterion};
 
@@ -1,7 +1,9 @@
 // TODO: ditch pathfinding; more trouble than its worth.
-use pathfinding::prelude::{bfs, Grid, Matrix};
 use std::collections::{HashMap, HashSet};
 
+use aoc_error::{parse_lines, Context, Error, Location, Result};
+use pathfinding::prelude::{bfs, Grid, Matrix};
+
 // TODO: this is horrid.
 type PathDistances = HashMap<(usize, usize), HashSet<((usize, usize), usize)>>;
 
@@ -66,24 +68,37 @@ fn go_hiking_again(
     distance
 }
 
-pub fn get_part_one(input: &str) -> Result<usize, String> {
-    let trails = input
-        .trim()
-        .lines()
-        .map(str::as_bytes)
-        .collect::<Matrix<_>>();
+fn get_trails(input: &str) -> Result<Matrix<&u8>> {
+    let rows = parse_lines(input.trim(), |line| {
+        match line
+            .char_indices()
+            .find(|(_, c)| !matches!(c, '#' | '.' | '>' | '<' | 'v' | '^'))
+        {
+            Some((x, c)) => Err(Error::invalid_input(format!("unexpected {:?}", c))
+                .at(Location::line_column(1, x + 1))),
+            None => Ok(line.as_bytes()),
+        }
+    })?;
+
+    match rows.iter().position(|row| row.len() != rows[0].len()) {
+        Some(y) => {
+            Err(Error::invalid_input(format!("expected {} columns", rows[0].len())).on_line(y + 1))
+        }
+        None if rows.is_empty() => Err(Error::invalid_input("no trails")),
+        None => Matrix::from_rows(rows).map_err(|error| Error::invalid_input(error.to_string())),
+    }
+}
+
+pub fn get_part_one(input: &str) -> Result<usize> {
+    let trails = get_trails(input)?;
 
     let hike = go_hiking(&trails, (0, 1), 0, &mut HashSet::new());
 
     Ok(hike)
 }
 
-pub fn get_part_two(input: &str) -> Result<usize, String> {
-    let trails = input
-        .trim()
-        .lines()
-        .map(str::as_bytes)
-        .collect::<Matrix<_>>();
+pub fn get_part_two(input: &str) -> Result<usize> {
+    let trails = get_trails(input)?;
     let trails: Grid = trails.map(|b| *b != b'#').into();
 
     let junctions = trails
@@ -115,7 +130,7 @@ pub fn get_part_two(input: &str) -> Result<usize, String> {
             ) {
                 let end = path
                     .last()
-                    .ok_or(format!("couldn't find end of path: {:?}", path))?;
+                    .with_context(|| format!("couldn't find end of path: {:?}", path))?;
                 let distance = path.len() - 1;
                 neighbours.entry(next).or_default().insert((*end, distance));
             }
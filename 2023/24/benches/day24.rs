@@ -1,7 +1,7 @@
 use std::fs;
 use std::hint::black_box;
 
-use day24::*;
+use aoc_2023_24::*;
 
 use criterion::{criterion_group, criterion_main, Criterion};
 
@@ -1,3 +1,4 @@
+use aoc_error::{Error, Result};
 use ndarray::prelude::*;
 use ndarray_linalg::Solve;
 
@@ -60,8 +61,8 @@ fn intersects(
     }
 }
 
-pub fn get_part_one(input: &str, min: f64, max: f64) -> Result<usize, String> {
-    let hailstones = trajectories::hailstones(input.trim()).map_err(|e| e.to_string())?;
+pub fn get_part_one(input: &str, min: f64, max: f64) -> Result<usize> {
+    let hailstones = trajectories::hailstones(input.trim())?;
 
     let intersections = hailstones
         .iter()
@@ -104,8 +105,11 @@ fn possible(dimension: Vec<(isize, isize)>) -> Option<isize> {
     Some(possible.into_iter().position(|s| s)? as isize + min)
 }
 
-pub fn get_part_two(input: &str) -> Result<usize, String> {
-    let hailstones = trajectories::hailstones(input.trim()).map_err(|e| e.to_string())?;
+pub fn get_part_two(input: &str) -> Result<usize> {
+    let hailstones = trajectories::hailstones(input.trim())?;
+    let [first, second, ..] = hailstones[..] else {
+        return Err(Error::invalid_input("expected at least two hailstones"));
+    };
 
     let x = possible(
         hailstones
@@ -113,38 +117,40 @@ pub fn get_part_two(input: &str) -> Result<usize, String> {
             .map(|((x, _, _), (dx, _, _))| (*x, *dx))
             .collect(),
     )
-    .ok_or("could not determine x".to_owned())? as f64;
+    .ok_or_else(|| Error::no_solution("could not determine x"))? as f64;
     let y = possible(
         hailstones
             .iter()
             .map(|((_, y, _), (_, dy, _))| (*y, *dy))
             .collect(),
     )
-    .ok_or("could not determine y".to_owned())? as f64;
+    .ok_or_else(|| Error::no_solution("could not determine y"))? as f64;
     let z = possible(
         hailstones
             .iter()
             .map(|((_, _, z), (_, _, dz))| (*z, *dz))
             .collect(),
     )
-    .ok_or("could not determine z".to_owned())? as f64;
+    .ok_or_else(|| Error::no_solution("could not determine z"))? as f64;
 
     let a: Array2<f64> = array![
-        [1.0, 0.0, 0.0, x - hailstones[0].1 .0 as f64, 0.0],
-        [0.0, 1.0, 0.0, y - hailstones[0].1 .1 as f64, 0.0],
-        [0.0, 0.0, 1.0, z - hailstones[0].1 .2 as f64, 0.0],
-        [1.0, 0.0, 0.0, 0.0, x - hailstones[1].1 .0 as f64],
-        [0.0, 1.0, 0.0, 0.0, y - hailstones[1].1 .1 as f64]
+        [1.0, 0.0, 0.0, x - first.1 .0 as f64, 0.0],
+        [0.0, 1.0, 0.0, y - first.1 .1 as f64, 0.0],
+        [0.0, 0.0, 1.0, z - first.1 .2 as f64, 0.0],
+        [1.0, 0.0, 0.0, 0.0, x - second.1 .0 as f64],
+        [0.0, 1.0, 0.0, 0.0, y - second.1 .1 as f64]
     ];
 
     let b: Array1<f64> = array![
-        hailstones[0].0 .0 as f64,
-        hailstones[0].0 .1 as f64,
-        hailstones[0].0 .2 as f64,
-        hailstones[1].0 .0 as f64,
-        hailstones[1].0 .1 as f64
+        first.0 .0 as f64,
+        first.0 .1 as f64,
+        first.0 .2 as f64,
+        second.0 .0 as f64,
+        second.0 .1 as f64
     ];
-    let solution = a.solve_into(b).map_err(|e| e.to_string())?;
+    let solution = a
+        .solve_into(b)
+        .map_err(|error| Error::no_solution(error.to_string()))?;
 
     Ok((solution[0] + solution[1] + solution[2]).round() as usize)
 }
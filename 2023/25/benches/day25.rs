@@ -1,7 +1,7 @@
 use std::fs;
 use std::hint::black_box;
 
-use day25::*;
+use aoc_2023_25::*;
 
 use criterion::{criterion_group, criterion_main, Criterion};
 
@@ -11,10 +11,6 @@ fn criterion_benchmark(c: &mut Criterion) {
     c.bench_function("part_one input", |b| {
         b.iter(|| get_part_one(black_box(&input)))
     });
-
-    c.bench_function("part_two input", |b| {
-        b.iter(|| get_part_two(black_box(&input)))
-    });
 }
 
 criterion_group!(benches, criterion_benchmark);
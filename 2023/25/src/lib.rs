@@ -1,34 +1,35 @@
+use aoc_error::{parse_lines, Context, Error, Result};
 use graphrs::{algorithms::community::louvain, Edge, Graph, GraphSpecs};
 
-pub fn get_part_one(input: &str) -> Result<usize, String> {
+pub fn get_part_one(input: &str) -> Result<usize> {
     let mut graph = Graph::<&str, &str>::new(GraphSpecs::undirected_create_missing());
 
-    let edges = input
-        .trim()
-        .lines()
-        .map(|line| {
-            let (source, suffix) = line.split_once(':').ok_or(format!("bad line: {}", line))?;
+    let edges = parse_lines(input.trim(), |line| {
+        let (source, suffix) = line
+            .split_once(':')
+            .context("expected a component and its connections")?;
 
-            let edges = suffix[1..]
-                .split_whitespace()
-                .map(|target| Edge::<&str, &str>::new(source, target))
-                .collect::<Vec<_>>();
+        let edges = suffix
+            .split_whitespace()
+            .map(|target| Edge::<&str, &str>::new(source, target))
+            .collect::<Vec<_>>();
 
-            Ok::<Vec<_>, String>(edges)
-        })
-        .collect::<Result<Vec<_>, _>>()?
-        .into_iter()
-        .flatten()
-        .collect::<Vec<_>>();
+        Ok::<Vec<_>, Error>(edges)
+    })?
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>();
 
-    graph.add_edges(edges).map_err(|e| e.to_string())?;
+    graph
+        .add_edges(edges)
+        .map_err(|error| Error::invalid_input(error.to_string()))?;
 
     let best = louvain::louvain_partitions(&graph, false, Some(0f64), Some(4f64), None)
-        .map_err(|e| e.to_string())?;
+        .map_err(|error| Error::no_solution(error.to_string()))?;
 
     Ok(best
         .first()
-        .ok_or("bad louvain".to_owned())?
+        .ok_or_else(|| Error::no_solution("no partitions"))?
         .iter()
         .map(|partitions| partitions.len())
         .product())
@@ -1,5 +1,7 @@
 use std::str::FromStr;
 
+use aoc_error::{parse_lines, Context, Error, Result};
+
 #[derive(Debug)]
 enum Rotation {
     Left(isize),
@@ -7,29 +9,24 @@ enum Rotation {
 }
 
 impl FromStr for Rotation {
-    type Err = String;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let s = s.trim();
 
-        let dir = s.chars().next().ok_or_else(|| "empty line".to_string())?;
-        let value: isize = s[1..]
-            .parse()
-            .map_err(|e| format!("invalid distance: {}", e))?;
+        let dir = s.chars().next().context("empty line")?;
+        let value: isize = s[dir.len_utf8()..].parse().context("invalid distance")?;
 
         match dir {
             'L' => Ok(Rotation::Left(value)),
             'R' => Ok(Rotation::Right(value)),
-            _ => Err(format!("invalid direction: {}", dir)),
+            _ => Err(Error::invalid_input(format!("invalid direction {:?}", dir))),
         }
     }
 }
 
-pub fn get_part_one(input: &str) -> Result<usize, String> {
-    let rotations = input
-        .lines()
-        .map(Rotation::from_str)
-        .collect::<Result<Vec<Rotation>, _>>()?;
+pub fn get_part_one(input: &str) -> Result<usize> {
+    let rotations = parse_lines(input, Rotation::from_str)?;
 
     let count = rotations
         .iter()
@@ -46,11 +43,8 @@ pub fn get_part_one(input: &str) -> Result<usize, String> {
     Ok(count)
 }
 
-pub fn get_part_two(input: &str) -> Result<isize, String> {
-    let rotations = input
-        .lines()
-        .map(Rotation::from_str)
-        .collect::<Result<Vec<Rotation>, _>>()?;
+pub fn get_part_two(input: &str) -> Result<isize> {
+    let rotations = parse_lines(input, Rotation::from_str)?;
 
     let count = rotations
         .iter()
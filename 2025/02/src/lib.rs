@@ -1,31 +1,33 @@
 use std::ops::RangeInclusive;
 
+use aoc_error::{Context, Error, Location, Result};
 use itoa::Buffer;
 use rayon::prelude::*;
 
-fn get_ranges(input: &str) -> Result<Vec<RangeInclusive<usize>>, String> {
+fn get_range(range: &str) -> Result<RangeInclusive<usize>> {
+    let (start, end) = range.split_once('-').context("invalid range")?;
+    let s = start.parse::<usize>().context("invalid start")?;
+    let e = end.parse::<usize>().context("invalid end")?;
+
+    Ok(s..=e)
+}
+
+fn get_ranges(input: &str) -> Result<Vec<RangeInclusive<usize>>> {
+    let mut offset = input.len() - input.trim_start().len();
+
     input
         .trim()
         .split(',')
         .map(|range| {
-            range
-                .split_once('-')
-                .ok_or_else(|| "invalid range".to_string())
-                .and_then(|(start, end)| {
-                    let s = start
-                        .parse::<usize>()
-                        .map_err(|_| format!("invalid start: {start}"))?;
-                    let e = end
-                        .parse::<usize>()
-                        .map_err(|_| format!("invalid end: {end}"))?;
-
-                    Ok(s..=e)
-                })
+            let location = Location::from_offset(input, offset);
+            offset += range.len() + 1;
+
+            get_range(range).map_err(|error: Error| error.at(location))
         })
-        .collect::<Result<Vec<RangeInclusive<usize>>, _>>()
+        .collect()
 }
 
-pub fn get_part_one(input: &str) -> Result<usize, String> {
+pub fn get_part_one(input: &str) -> Result<usize> {
     let total = get_ranges(input)?
         .into_par_iter()
         .map(|range| {
@@ -52,7 +54,7 @@ pub fn get_part_one(input: &str) -> Result<usize, String> {
     Ok(total)
 }
 
-pub fn get_part_two(input: &str) -> Result<usize, String> {
+pub fn get_part_two(input: &str) -> Result<usize> {
     let total = get_ranges(input)?
         .into_par_iter()
         .map(|range| {
@@ -1,7 +1,7 @@
 use std::fs;
 use std::hint::black_box;
 
-use day03::*;
+use aoc_2025_03::*;
 
 use criterion::{criterion_group, criterion_main, Criterion};
 
@@ -1,4 +1,16 @@
-fn get_joltage(bank: &[u8], digits: usize) -> Result<Vec<&u8>, String> {
+use aoc_error::{parse_lines, Error, Location, Result};
+
+fn get_joltage(bank: &[u8], digits: usize) -> Result<Vec<&u8>> {
+    if let Some(i) = bank.iter().position(|b| !b.is_ascii_digit()) {
+        return Err(Error::invalid_input("expected a digit").at(Location::line_column(1, i + 1)));
+    }
+    if bank.len() < digits {
+        return Err(Error::invalid_input(format!(
+            "expected at least {} batteries",
+            digits
+        )));
+    }
+
     let batteries = (0..digits)
         .scan((0, digits), |state, _| {
             let (i, digit) = bank[state.0..bank.len() - state.1 + 1]
@@ -16,36 +28,32 @@ fn get_joltage(bank: &[u8], digits: usize) -> Result<Vec<&u8>, String> {
     Ok(batteries)
 }
 
-pub fn get_part_one(input: &str) -> Result<usize, String> {
-    let total_output_joltage = input
-        .trim()
-        .lines()
-        .map(|line| {
-            let joltage = get_joltage(line.as_bytes(), 2)?;
-            Ok(joltage
+pub fn get_part_one(input: &str) -> Result<usize> {
+    let total_output_joltage = parse_lines(input.trim(), |line| {
+        let joltage = get_joltage(line.as_bytes(), 2)?;
+        Ok::<_, Error>(
+            joltage
                 .iter()
-                .fold(0, |acc, &b| acc * 10 + (b - b'0') as usize))
-        })
-        .collect::<Result<Vec<_>, String>>()?
-        .iter()
-        .sum();
+                .fold(0, |acc, &b| acc * 10 + (b - b'0') as usize),
+        )
+    })?
+    .iter()
+    .sum();
 
     Ok(total_output_joltage)
 }
 
-pub fn get_part_two(input: &str) -> Result<usize, String> {
-    let total_output_joltage = input
-        .trim()
-        .lines()
-        .map(|line| {
-            let joltage = get_joltage(line.as_bytes(), 12)?;
-            Ok(joltage
+pub fn get_part_two(input: &str) -> Result<usize> {
+    let total_output_joltage = parse_lines(input.trim(), |line| {
+        let joltage = get_joltage(line.as_bytes(), 12)?;
+        Ok::<_, Error>(
+            joltage
                 .iter()
-                .fold(0, |acc, &b| acc * 10 + (b - b'0') as usize))
-        })
-        .collect::<Result<Vec<_>, String>>()?
-        .iter()
-        .sum();
+                .fold(0, |acc, &b| acc * 10 + (b - b'0') as usize),
+        )
+    })?
+    .iter()
+    .sum();
 
     Ok(total_output_joltage)
 }
@@ -1,8 +1,10 @@
 use std::fs;
 
-use ::day03::*;
+use aoc_error::Error;
 
-fn main() -> Result<(), String> {
+use ::aoc_2025_03::*;
+
+fn main() -> Result<(), Error> {
     let input = fs::read_to_string("input.txt").expect("Error reading input.txt");
 
     println!(
@@ -1,7 +1,7 @@
 use std::fs;
 use std::hint::black_box;
 
-use day05::*;
+use aoc_2025_05::*;
 
 use criterion::{criterion_group, criterion_main, Criterion};
 
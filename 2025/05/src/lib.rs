@@ -1,5 +1,7 @@
 use std::ops::RangeInclusive;
 
+use aoc_error::Result;
+
 struct Database {
     ranges: Vec<RangeInclusive<usize>>,
     ids: Vec<usize>,
@@ -36,8 +38,8 @@ peg::parser! {
     }
 }
 
-pub fn get_part_one(input: &str) -> Result<usize, String> {
-    let database = database::database(input.trim()).map_err(|e| e.to_string())?;
+pub fn get_part_one(input: &str) -> Result<usize> {
+    let database = database::database(input.trim())?;
 
     let fresh = database
         .ids
@@ -48,8 +50,8 @@ pub fn get_part_one(input: &str) -> Result<usize, String> {
     Ok(fresh)
 }
 
-pub fn get_part_two(input: &str) -> Result<usize, String> {
-    let mut database = database::database(input.trim()).map_err(|e| e.to_string())?;
+pub fn get_part_two(input: &str) -> Result<usize> {
+    let mut database = database::database(input.trim())?;
 
     database.ranges.sort_unstable_by_key(|range| *range.start());
 
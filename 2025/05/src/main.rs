@@ -1,8 +1,10 @@
 use std::fs;
 
-use ::day05::{get_part_one, get_part_two};
+use aoc_error::Error;
 
-fn main() -> Result<(), String> {
+use ::aoc_2025_05::{get_part_one, get_part_two};
+
+fn main() -> Result<(), Error> {
     let input = fs::read_to_string("input.txt").expect("Error reading input.txt");
 
     println!(
@@ -1,7 +1,7 @@
 use std::fs;
 use std::hint::black_box;
 
-use day06::*;
+use aoc_2025_06::*;
 
 use criterion::{criterion_group, criterion_main, Criterion};
 
@@ -1,38 +1,53 @@
-pub fn get_part_one(input: &str) -> Result<usize, String> {
+use aoc_error::{Context, Error, Result};
+
+pub fn get_part_one(input: &str) -> Result<usize> {
     let count = input.lines().clone().count();
 
     let problems = input
         .lines()
         .take(count.saturating_sub(1))
-        .map(|line| {
+        .enumerate()
+        .map(|(i, line)| {
             line.split_ascii_whitespace()
                 .map(|digits| digits.parse::<usize>())
                 .collect::<Result<Vec<usize>, _>>()
+                .map_err(|error| Error::from(error).on_line(i + 1))
         })
-        .collect::<Result<Vec<Vec<usize>>, _>>()
-        .map_err(|e| e.to_string())?;
+        .collect::<Result<Vec<Vec<usize>>>>()?;
 
-    let grand_total = input
+    let operations = input
         .lines()
         .last()
-        .ok_or("invalid input")?
+        .context("expected operations")?
         .split_ascii_whitespace()
+        .collect::<Vec<_>>();
+    if let Some(i) = problems
+        .iter()
+        .position(|row| row.len() != operations.len())
+    {
+        return Err(
+            Error::invalid_input(format!("expected {} numbers", operations.len())).on_line(i + 1),
+        );
+    }
+
+    let grand_total = operations
+        .iter()
         .enumerate()
-        .map(|(position, op)| match op {
+        .map(|(position, op)| match *op {
             "+" => Ok(problems.iter().map(|row| row[position]).sum::<usize>()),
             "*" => Ok(problems.iter().map(|row| row[position]).product::<usize>()),
-            _ => Err("invalid operation"),
+            _ => Err(Error::invalid_input(format!("invalid operation {:?}", op)).on_line(count)),
         })
-        .collect::<Result<Vec<usize>, _>>()?
+        .collect::<Result<Vec<usize>>>()?
         .iter()
         .sum();
 
     Ok(grand_total)
 }
 
-pub fn get_part_two(input: &str) -> Result<usize, String> {
+pub fn get_part_two(input: &str) -> Result<usize> {
     let columns = input.lines().clone().count();
-    let rows = input.lines().next().ok_or("invalid input")?.len();
+    let rows = input.lines().next().context("expected problems")?.len();
 
     let problems = (0..rows)
         .rev()
@@ -42,7 +57,7 @@ pub fn get_part_two(input: &str) -> Result<usize, String> {
                 .collect::<Option<Vec<&u8>>>()
         })
         .collect::<Option<Vec<Vec<&u8>>>>()
-        .ok_or("invalid input")?;
+        .context("expected every line to be as long as the first")?;
 
     let grand_total = problems
         .iter()
@@ -1,13 +1,15 @@
 use std::collections::{HashMap, HashSet};
 
-pub fn get_part_one(input: &str) -> Result<usize, String> {
+use aoc_error::{Context, Result};
+
+pub fn get_part_one(input: &str) -> Result<usize> {
     let mut lines = input.lines();
 
     let start = lines
         .next()
-        .ok_or("invalid input")?
+        .context("expected a manifold")?
         .find('S')
-        .ok_or("no start found")?;
+        .context("no S marking the start")?;
 
     let mut splits = 0;
     let mut beams = HashSet::from([start]);
@@ -30,14 +32,14 @@ pub fn get_part_one(input: &str) -> Result<usize, String> {
     Ok(splits)
 }
 
-pub fn get_part_two(input: &str) -> Result<usize, String> {
+pub fn get_part_two(input: &str) -> Result<usize> {
     let mut lines = input.lines();
 
     let start = lines
         .next()
-        .ok_or("invalid input")?
+        .context("expected a manifold")?
         .find('S')
-        .ok_or("no start found")?;
+        .context("no S marking the start")?;
 
     let mut timelines = HashMap::from([(start, 1)]);
 
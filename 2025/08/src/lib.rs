@@ -3,6 +3,7 @@ use std::collections::{HashMap, HashSet};
 use std::ops::Sub;
 use std::str::FromStr;
 
+use aoc_error::{parse_lines, Context, Error, Result};
 use itertools::Itertools;
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
@@ -40,16 +41,16 @@ impl JunctionBox {
 }
 
 impl FromStr for JunctionBox {
-    type Err = String;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let [x, y, z] = s
             .trim()
             .splitn(3, ',')
-            .map(|p| p.parse::<isize>().map_err(|e| e.to_string()))
+            .map(|p| p.parse::<isize>())
             .collect::<Result<Vec<_>, _>>()?
             .try_into()
-            .map_err(|_| "invalid input".to_string())?;
+            .map_err(|_| Error::invalid_input("expected three coordinates"))?;
 
         Ok(Self { x, y, z })
     }
@@ -77,11 +78,8 @@ impl DisjointSetUnion {
     }
 }
 
-pub fn get_part_one(input: &str, pairs: usize) -> Result<usize, String> {
-    let junction_boxes = input
-        .lines()
-        .map(|line| line.parse::<JunctionBox>())
-        .collect::<Result<Vec<_>, _>>()?;
+pub fn get_part_one(input: &str, pairs: usize) -> Result<usize> {
+    let junction_boxes = parse_lines(input, JunctionBox::from_str)?;
 
     let distances = {
         let mut distances = junction_boxes
@@ -114,14 +112,15 @@ pub fn get_part_one(input: &str, pairs: usize) -> Result<usize, String> {
     largest.sort_unstable();
     largest.reverse();
 
-    Ok(largest[..3].iter().product())
+    Ok(largest
+        .get(..3)
+        .context("expected at least three circuits")?
+        .iter()
+        .product())
 }
 
-pub fn get_part_two(input: &str) -> Result<usize, String> {
-    let junction_boxes = input
-        .lines()
-        .map(|line| line.parse::<JunctionBox>())
-        .collect::<Result<Vec<_>, _>>()?;
+pub fn get_part_two(input: &str) -> Result<usize> {
+    let junction_boxes = parse_lines(input, JunctionBox::from_str)?;
 
     let distances = {
         let mut distances = junction_boxes
@@ -146,7 +145,7 @@ pub fn get_part_two(input: &str) -> Result<usize, String> {
 
     match last_pair {
         Some((a, b, _)) => Ok((a.x * b.x) as usize),
-        None => Err("could not find last pair".to_owned()),
+        None => Err(Error::no_solution("could not find last pair")),
     }
 }
 
@@ -1,21 +1,20 @@
 use std::cmp::Reverse;
 
+use aoc_error::{parse_lines, Context, Error, Result};
+
 struct Tile {
     x: usize,
     y: usize,
 }
 
-fn get_tiles(input: &str) -> Result<Vec<Tile>, String> {
-    input
-        .lines()
-        .map(|line| {
-            let (x, y) = line.split_once(',').ok_or("invalid line")?;
-            let x = x.parse::<usize>().map_err(|e| e.to_string())?;
-            let y = y.parse::<usize>().map_err(|e| e.to_string())?;
+fn get_tiles(input: &str) -> Result<Vec<Tile>> {
+    parse_lines(input, |line| {
+        let (x, y) = line.split_once(',').context("invalid line")?;
+        let x = x.parse::<usize>()?;
+        let y = y.parse::<usize>()?;
 
-            Ok(Tile { x, y })
-        })
-        .collect::<Result<Vec<_>, String>>()
+        Ok::<_, Error>(Tile { x, y })
+    })
 }
 
 impl Tile {
@@ -33,7 +32,7 @@ impl Tile {
     }
 }
 
-pub fn get_part_one(input: &str) -> Result<usize, String> {
+pub fn get_part_one(input: &str) -> Result<usize> {
     let tiles = get_tiles(input)?;
 
     let max = tiles
@@ -46,13 +45,22 @@ pub fn get_part_one(input: &str) -> Result<usize, String> {
                 .map(move |b| (a.x.abs_diff(b.x) + 1) * (a.y.abs_diff(b.y) + 1))
         })
         .max()
-        .ok_or("invalid input")?;
+        .context("expected at least two tiles")?;
 
     Ok(max)
 }
 
-pub fn get_part_two(input: &str) -> Result<usize, String> {
+pub fn get_part_two(input: &str) -> Result<usize> {
     let tiles = get_tiles(input)?;
+    if let Some(i) = (0..tiles.len()).find(|&i| {
+        let (a, b) = (&tiles[i], &tiles[(i + 1) % tiles.len()]);
+
+        a.x != b.x && a.y != b.y
+    }) {
+        return Err(
+            Error::invalid_input("edges should be axis-aligned").on_line((i + 1) % tiles.len() + 1)
+        );
+    }
 
     let rectangles = {
         let mut rectangles = tiles
@@ -109,7 +117,7 @@ pub fn get_part_two(input: &str) -> Result<usize, String> {
 
             !crosses_edge
         })
-        .ok_or("cannot find largest area".to_string())?;
+        .ok_or_else(|| Error::no_solution("cannot find largest area"))?;
 
     Ok(*area)
 }
@@ -1,5 +1,6 @@
 use std::collections::{HashSet, VecDeque};
 
+use aoc_error::{parse_lines, Error, Result};
 use z3::{ast::Int, Optimize, SatResult};
 
 #[derive(Debug)]
@@ -118,36 +119,35 @@ fn fewest_presses_lights(factory_machine: &FactoryMachine) -> Option<usize> {
     None
 }
 
-pub fn get_part_one(input: &str) -> Result<usize, String> {
-    let factory_machines = input
-        .lines()
-        .map(factory_machine::machine)
-        .collect::<Result<Vec<_>, _>>()
-        .map_err(|e| e.to_string())?;
+pub fn get_part_one(input: &str) -> Result<usize> {
+    let factory_machines = parse_lines(input, factory_machine::machine)?;
 
     let fewest_presses = factory_machines
         .iter()
-        .map(fewest_presses_lights)
-        .collect::<Option<Vec<_>>>()
-        .ok_or("invalid manual")?
+        .enumerate()
+        .map(|(i, factory_machine)| {
+            fewest_presses_lights(factory_machine)
+                .ok_or_else(|| Error::no_solution("the lights can't be matched").on_line(i + 1))
+        })
+        .collect::<Result<Vec<_>>>()?
         .iter()
         .sum();
 
     Ok(fewest_presses)
 }
 
-pub fn get_part_two(input: &str) -> Result<u64, String> {
-    let factory_machines = input
-        .lines()
-        .map(factory_machine::machine)
-        .collect::<Result<Vec<_>, _>>()
-        .map_err(|e| e.to_string())?;
+pub fn get_part_two(input: &str) -> Result<u64> {
+    let factory_machines = parse_lines(input, factory_machine::machine)?;
 
     let fewest_presses = factory_machines
         .iter()
-        .map(fewest_presses_joltage)
-        .collect::<Option<Vec<_>>>()
-        .ok_or("invalid manual")?
+        .enumerate()
+        .map(|(i, factory_machine)| {
+            fewest_presses_joltage(factory_machine).ok_or_else(|| {
+                Error::no_solution("the joltage requirements can't be met").on_line(i + 1)
+            })
+        })
+        .collect::<Result<Vec<_>>>()?
         .iter()
         .sum();
 
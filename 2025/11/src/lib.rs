@@ -1,5 +1,7 @@
 use std::collections::HashMap;
 
+use aoc_error::{parse_lines, Context, Error, Result};
+
 fn find_paths(
     start: &str,
     end: &str,
@@ -31,30 +33,25 @@ fn find_paths(
     paths
 }
 
-pub fn get_part_one(input: &str) -> Result<usize, String> {
-    let devices = input
-        .lines()
-        .map(|line| {
-            let (device, outputs) = line.split_once(": ").ok_or("invalid line")?;
-            let outputs = outputs.split_whitespace().collect();
+fn get_devices(input: &str) -> Result<HashMap<&str, Vec<&str>>> {
+    let devices = parse_lines(input, |line| {
+        let (device, outputs) = line.split_once(": ").context("invalid line")?;
+        let outputs = outputs.split_whitespace().collect();
 
-            Ok((device, outputs))
-        })
-        .collect::<Result<HashMap<_, Vec<_>>, String>>()?;
+        Ok::<_, Error>((device, outputs))
+    })?;
 
-    Ok(find_paths("you", "out", &devices, &mut HashMap::new()))
+    Ok(devices.into_iter().collect())
 }
 
-pub fn get_part_two(input: &str) -> Result<usize, String> {
-    let devices = input
-        .lines()
-        .map(|line| {
-            let (device, outputs) = line.split_once(": ").ok_or("invalid line")?;
-            let outputs = outputs.split_whitespace().collect();
+pub fn get_part_one(input: &str) -> Result<usize> {
+    let devices = get_devices(input)?;
+
+    Ok(find_paths("you", "out", &devices, &mut HashMap::new()))
+}
 
-            Ok((device, outputs))
-        })
-        .collect::<Result<HashMap<_, Vec<_>>, String>>()?;
+pub fn get_part_two(input: &str) -> Result<usize> {
+    let devices = get_devices(input)?;
 
     let count = {
         let mut memo = HashMap::new();
@@ -1,3 +1,5 @@
+use aoc_error::Result;
+
 #[derive(Debug)]
 struct Region {
     width: usize,
@@ -51,8 +53,8 @@ peg::parser! {
     }
 }
 
-pub fn get_part_one(input: &str) -> Result<usize, String> {
-    let summary = summary::summary(input).map_err(|e| e.to_string())?;
+pub fn get_part_one(input: &str) -> Result<usize> {
+    let summary = summary::summary(input)?;
 
     let regions = summary
         .regions
@@ -1,8 +1,10 @@
 use std::fs;
 
-use ::day12::get_part_one;
+use aoc_error::Error;
 
-fn main() -> Result<(), String> {
+use ::aoc_2025_12::get_part_one;
+
+fn main() -> Result<(), Error> {
     let input = fs::read_to_string("input.txt").expect("Error reading input.txt");
 
     println!(
@@ -0,0 +1,345 @@
+#![deny(clippy::expect_used, clippy::unwrap_used)]
+
+use std::fmt;
+use std::num::ParseIntError;
+
+use peg::error::ParseError;
+use peg::str::LineCol;
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// A 1-indexed position in the puzzle input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub column: Option<usize>,
+}
+
+impl Location {
+    pub fn line(line: usize) -> Self {
+        Location { line, column: None }
+    }
+
+    pub fn line_column(line: usize, column: usize) -> Self {
+        Location {
+            line,
+            column: Some(column),
+        }
+    }
+
+    /// The line and column of the byte at `offset` within `input`. An
+    /// offset inside a multi-byte character is taken as that character's.
+    pub fn from_offset(input: &str, offset: usize) -> Self {
+        let mut offset = offset.min(input.len());
+        while !input.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        let before = &input[..offset];
+        let line = before.matches('\n').count() + 1;
+        let column = before
+            .rfind('\n')
+            .map_or(before, |newline| &before[newline + 1..])
+            .chars()
+            .count()
+            + 1;
+
+        Location::line_column(line, column)
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.column {
+            Some(column) => write!(f, "line {}, column {}", self.line, column),
+            None => write!(f, "line {}", self.line),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+    ParseIntError(ParseIntError),
+    /// The input didn't match a grammar; holds what was expected instead.
+    ParseError(String),
+    /// The input parsed but doesn't make sense for the puzzle.
+    InvalidInputError(String),
+    /// The input is fine but the puzzle has no answer for it.
+    NoSolutionError(String),
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::ParseIntError(error) => write!(f, "{}", error),
+            ErrorKind::ParseError(expected) => write!(f, "expected {}", expected),
+            ErrorKind::InvalidInputError(message) => write!(f, "{}", message),
+            ErrorKind::NoSolutionError(message) => write!(f, "no solution: {}", message),
+        }
+    }
+}
+
+/// An error that knows where in the puzzle input it happened and what was
+/// being attempted at the time.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Error {
+    kind: ErrorKind,
+    location: Option<Location>,
+    context: Vec<String>,
+}
+
+impl Error {
+    pub fn new(kind: ErrorKind) -> Self {
+        Error {
+            kind,
+            location: None,
+            context: Vec::new(),
+        }
+    }
+
+    pub fn invalid_input(message: impl Into<String>) -> Self {
+        Error::new(ErrorKind::InvalidInputError(message.into()))
+    }
+
+    pub fn no_solution(message: impl Into<String>) -> Self {
+        Error::new(ErrorKind::NoSolutionError(message.into()))
+    }
+
+    pub fn kind(&self) -> &ErrorKind {
+        &self.kind
+    }
+
+    pub fn location(&self) -> Option<Location> {
+        self.location
+    }
+
+    /// Context messages, innermost first.
+    pub fn context(&self) -> impl Iterator<Item = &str> {
+        self.context.iter().map(String::as_str)
+    }
+
+    pub fn at(mut self, location: Location) -> Self {
+        self.location = Some(location);
+        self
+    }
+
+    /// Places the error on `line` of the whole input. An error that already
+    /// has a location is assumed to be relative to that line alone, so its
+    /// column is kept.
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.location = Some(match self.location {
+            Some(location) => Location {
+                line: line + location.line - 1,
+                ..location
+            },
+            None => Location::line(line),
+        });
+        self
+    }
+
+    pub fn with_context(mut self, context: impl Into<String>) -> Self {
+        self.context.push(context.into());
+        self
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for context in self.context.iter().rev() {
+            write!(f, "{}: ", context)?;
+        }
+        if let Some(location) = self.location {
+            write!(f, "{}: ", location)?;
+        }
+
+        write!(f, "{}", self.kind)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ErrorKind> for Error {
+    fn from(kind: ErrorKind) -> Self {
+        Error::new(kind)
+    }
+}
+
+impl From<ParseIntError> for Error {
+    fn from(error: ParseIntError) -> Self {
+        Error::new(ErrorKind::ParseIntError(error))
+    }
+}
+
+impl From<ParseError<LineCol>> for Error {
+    fn from(error: ParseError<LineCol>) -> Self {
+        Error::new(ErrorKind::ParseError(error.expected.to_string())).at(Location::line_column(
+            error.location.line,
+            error.location.column,
+        ))
+    }
+}
+
+impl From<String> for Error {
+    fn from(message: String) -> Self {
+        Error::invalid_input(message)
+    }
+}
+
+impl From<&str> for Error {
+    fn from(message: &str) -> Self {
+        Error::invalid_input(message)
+    }
+}
+
+/// Attaches context to a failing `Result`, or turns a `None` into an
+/// invalid input error.
+pub trait Context<T> {
+    fn context(self, context: impl Into<String>) -> Result<T>;
+
+    fn with_context<C: Into<String>>(self, f: impl FnOnce() -> C) -> Result<T>;
+}
+
+impl<T, E: Into<Error>> Context<T> for std::result::Result<T, E> {
+    fn context(self, context: impl Into<String>) -> Result<T> {
+        self.map_err(|error| error.into().with_context(context))
+    }
+
+    fn with_context<C: Into<String>>(self, f: impl FnOnce() -> C) -> Result<T> {
+        self.map_err(|error| error.into().with_context(f()))
+    }
+}
+
+impl<T> Context<T> for Option<T> {
+    fn context(self, context: impl Into<String>) -> Result<T> {
+        self.ok_or_else(|| Error::invalid_input(context))
+    }
+
+    fn with_context<C: Into<String>>(self, f: impl FnOnce() -> C) -> Result<T> {
+        self.ok_or_else(|| Error::invalid_input(f()))
+    }
+}
+
+/// Parses each line of `input` with `parse`, reporting the line number of
+/// the first one that fails.
+pub fn parse_lines<'a, T, E: Into<Error>>(
+    input: &'a str,
+    mut parse: impl FnMut(&'a str) -> std::result::Result<T, E>,
+) -> Result<Vec<T>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse(line).map_err(|error| error.into().on_line(i + 1)))
+        .collect()
+}
+
+/// Parses each `separator`-delimited field of `input` with `parse`,
+/// reporting the line and column at which the first one that fails starts.
+pub fn parse_fields<'a, T, E: Into<Error>>(
+    input: &'a str,
+    separator: char,
+    mut parse: impl FnMut(&'a str) -> std::result::Result<T, E>,
+) -> Result<Vec<T>> {
+    let mut offset = 0;
+
+    input
+        .split(separator)
+        .map(|field| {
+            let start = offset;
+            offset += field.len() + separator.len_utf8();
+
+            parse(field).map_err(|error| error.into().at(Location::from_offset(input, start)))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    peg::parser! {
+        grammar pairs() for str {
+            rule number() -> usize
+                = n:$(['0'..='9']+) {? n.parse().or(Err("number")) }
+
+            pub rule pair() -> (usize, usize)
+                = a:number() "," b:number() { (a, b) }
+        }
+    }
+
+    #[test]
+    fn test_location_from_offset() {
+        let input = "abc\ndef\nghi";
+
+        assert_eq!(Location::line_column(1, 1), Location::from_offset(input, 0));
+        assert_eq!(Location::line_column(2, 3), Location::from_offset(input, 6));
+        assert_eq!(
+            Location::line_column(3, 4),
+            Location::from_offset(input, 11)
+        );
+    }
+
+    #[test]
+    fn test_location_from_offset_within_char() {
+        let input = "a
+…b";
+
+        assert_eq!(Location::line_column(2, 1), Location::from_offset(input, 3));
+        assert_eq!(Location::line_column(2, 1), Location::from_offset(input, 4));
+        assert_eq!(Location::line_column(2, 2), Location::from_offset(input, 5));
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let error = parse_lines("1\n2\nthree\n4", str::parse::<usize>);
+
+        assert_eq!(
+            Err(Some(Location::line(3))),
+            error.as_ref().map_err(Error::location),
+        );
+        assert_eq!(
+            Ok(vec![1, 2, 3]),
+            parse_lines("1\n2\n3", str::parse::<usize>),
+        );
+    }
+
+    #[test]
+    fn test_parse_fields() {
+        let error = parse_fields("1,2,3,\n4,x,6", ',', |field| field.trim().parse::<usize>());
+
+        assert_eq!(
+            Err(Some(Location::line_column(2, 3))),
+            error.as_ref().map_err(Error::location),
+        );
+        assert_eq!(
+            Ok(vec![1, 2, 3]),
+            parse_fields("1,2,3", ',', str::parse::<usize>),
+        );
+    }
+
+    #[test]
+    fn test_peg_location() {
+        let error = parse_lines("1,2\n3,4\n5;6", pairs::pair).map(|_| ());
+
+        assert_eq!(
+            Err("line 3, column 2: expected one of \",\", ['0'..='9']".to_owned()),
+            error.map_err(|error| error.to_string()),
+        );
+    }
+
+    #[test]
+    fn test_context() {
+        let error = "x"
+            .parse::<usize>()
+            .context("reading the crate count")
+            .context("parsing moves")
+            .map_err(|error| error.to_string());
+
+        assert_eq!(
+            Err("parsing moves: reading the crate count: invalid digit found in string".to_owned()),
+            error,
+        );
+        assert_eq!(
+            Err(Error::invalid_input("stack 2 is empty")),
+            None::<char>.with_context(|| format!("stack {} is empty", 2)),
+        );
+    }
+}
@@ -0,0 +1,144 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::{Direction, Grid, ORTHOGONAL, SURROUNDING};
+
+/// An unbounded grid in which every cell not explicitly set holds the
+/// `background`, for puzzles whose state spreads beyond its input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InfiniteGrid<T> {
+    cells: HashMap<Direction, T>,
+    background: T,
+}
+
+impl<T: Clone + PartialEq> InfiniteGrid<T> {
+    pub fn new(background: T) -> Self {
+        InfiniteGrid {
+            cells: HashMap::new(),
+            background,
+        }
+    }
+
+    pub fn background(&self) -> &T {
+        &self.background
+    }
+
+    /// Changes what every unset cell holds; cells already set keep their
+    /// value, even if it's the old background.
+    pub fn set_background(&mut self, background: T) {
+        self.background = background;
+    }
+
+    pub fn get(&self, point: Direction) -> &T {
+        self.cells.get(&point).unwrap_or(&self.background)
+    }
+
+    /// Setting a cell to the background forgets it.
+    pub fn set(&mut self, point: Direction, value: T) {
+        if value == self.background {
+            self.cells.remove(&point);
+        } else {
+            self.cells.insert(point, value);
+        }
+    }
+
+    /// Every cell that isn't the background, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Direction, &T)> {
+        self.cells.iter().map(|(point, value)| (*point, value))
+    }
+
+    /// The smallest rectangle, as its top-left and bottom-right corners,
+    /// holding every cell that isn't the background.
+    pub fn bounds(&self) -> Option<(Direction, Direction)> {
+        let xs = self.cells.keys().map(|(x, _)| *x);
+        let ys = self.cells.keys().map(|(_, y)| *y);
+
+        Some((
+            (xs.clone().min()?, ys.clone().min()?),
+            (xs.max()?, ys.max()?),
+        ))
+    }
+
+    pub fn neighbours4((x, y): Direction) -> impl Iterator<Item = Direction> {
+        ORTHOGONAL.iter().map(move |(dx, dy)| (x + dx, y + dy))
+    }
+
+    pub fn neighbours8((x, y): Direction) -> impl Iterator<Item = Direction> {
+        SURROUNDING.iter().map(move |(dx, dy)| (x + dx, y + dy))
+    }
+
+    /// Copies the part of the grid within `bounds` (inclusive) into a
+    /// `Grid`, whose `(0, 0)` is the top-left corner.
+    pub fn to_grid(&self, ((min_x, min_y), (max_x, max_y)): (Direction, Direction)) -> Grid<T> {
+        let width = (max_x - min_x + 1).max(0) as usize;
+        let height = (max_y - min_y + 1).max(0) as usize;
+        let mut grid = Grid::new(width, height, self.background.clone());
+        for ((x, y), value) in self.iter() {
+            if (min_x..=max_x).contains(&x) && (min_y..=max_y).contains(&y) {
+                grid[((x - min_x) as usize, (y - min_y) as usize)] = value.clone();
+            }
+        }
+
+        grid
+    }
+}
+
+impl<T: Clone + PartialEq> From<Grid<T>> for InfiniteGrid<T>
+where
+    T: Default,
+{
+    /// Places the grid's top-left corner at `(0, 0)`, with a default
+    /// background.
+    fn from(grid: Grid<T>) -> Self {
+        let mut infinite = InfiniteGrid::new(T::default());
+        for ((x, y), value) in grid.iter() {
+            infinite.set((x as isize, y as isize), value.clone());
+        }
+
+        infinite
+    }
+}
+
+impl<T: Clone + PartialEq + fmt::Display> fmt::Display for InfiniteGrid<T> {
+    /// Draws the smallest rectangle holding every non-background cell.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.bounds() {
+            Some(bounds) => write!(f, "{}", self.to_grid(bounds)),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_infinite() {
+        let mut grid = InfiniteGrid::new('.');
+        grid.set((-2, 1), '#');
+        grid.set((1, -1), '#');
+        grid.set((0, 0), '#');
+        grid.set((0, 0), '.');
+
+        assert_eq!(&'#', grid.get((-2, 1)));
+        assert_eq!(&'.', grid.get((100, 100)));
+        assert_eq!(Some(((-2, -1), (1, 1))), grid.bounds());
+        assert_eq!("...#\n....\n#...", grid.to_string());
+        assert_eq!(
+            vec![(0, -1), (-1, 0), (1, 0), (0, 1)],
+            InfiniteGrid::<char>::neighbours4((0, 0)).collect::<Vec<_>>(),
+        );
+    }
+
+    #[test]
+    fn test_from_grid() {
+        let grid = Grid::from_rows(vec![vec![0, 1], vec![2, 0]]).map(InfiniteGrid::from);
+
+        assert_eq!(
+            Ok(Some(((0, 0), (1, 1)))),
+            grid.as_ref().map(InfiniteGrid::bounds)
+        );
+        assert_eq!(Ok(&2), grid.as_ref().map(|grid| grid.get((0, 1))));
+    }
+}
@@ -0,0 +1,454 @@
+#![deny(clippy::expect_used, clippy::unwrap_used)]
+
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+use aoc_error::{Error, Location, Result};
+
+mod infinite;
+
+pub use infinite::InfiniteGrid;
+
+/// An `(x, y)` position, with `(0, 0)` at the top left.
+pub type Point = (usize, usize);
+
+/// An `(x, y)` step, with negative `y` heading up.
+pub type Direction = (isize, isize);
+
+/// Up, left, right and down: the four neighbours in reading order.
+pub const ORTHOGONAL: [Direction; 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+/// All eight neighbours, in reading order.
+pub const SURROUNDING: [Direction; 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A rectangular grid, stored row by row.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Fails if the rows aren't all the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self> {
+        let width = rows.first().map_or(0, Vec::len);
+        if let Some(y) = rows.iter().position(|row| row.len() != width) {
+            return Err(Error::invalid_input(format!(
+                "expected {} cells but found {}",
+                width,
+                rows[y].len()
+            ))
+            .at(Location::line(y + 1)));
+        }
+
+        Ok(Grid {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Parses puzzle text a character at a time, ignoring surrounding
+    /// blank lines.
+    pub fn parse<E: Into<Error>>(
+        input: &str,
+        mut parse: impl FnMut(char) -> std::result::Result<T, E>,
+    ) -> Result<Self> {
+        let trimmed = input.trim_start_matches('\n');
+        let skipped = input.len() - trimmed.len();
+
+        let rows = trimmed
+            .trim_end_matches('\n')
+            .lines()
+            .enumerate()
+            .map(|(y, line)| {
+                line.trim_end_matches('\r')
+                    .chars()
+                    .enumerate()
+                    .map(|(x, c)| {
+                        parse(c)
+                            .map_err(|error| error.into().at(Location::line_column(y + 1, x + 1)))
+                    })
+                    .collect()
+            })
+            .collect::<Result<_>>()
+            .and_then(Grid::from_rows);
+
+        rows.map_err(|error| error.on_line(skipped + 1))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Point) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        match self.contains(point) {
+            true => Some(&self.cells[point.1 * self.width + point.0]),
+            false => None,
+        }
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        match self.contains(point) {
+            true => Some(&mut self.cells[point.1 * self.width + point.0]),
+            false => None,
+        }
+    }
+
+    /// Every point, in reading order.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell alongside its point, in reading order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// The first point, in reading order, whose cell matches.
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<Point> {
+        self.iter()
+            .find(|(_, value)| predicate(value))
+            .map(|(point, _)| point)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1)).take(self.height)
+    }
+
+    /// The cells in column `x`, top to bottom; none if `x` is off the grid.
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        let cells = match x < self.width {
+            true => &self.cells[x..],
+            false => &[],
+        };
+
+        cells.iter().step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// The point one `direction` away, if it's still on the grid.
+    pub fn step(&self, (x, y): Point, (dx, dy): Direction) -> Option<Point> {
+        let point = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+
+        self.contains(point).then_some(point)
+    }
+
+    /// Every point from `point` (exclusive) to the edge in `direction`.
+    pub fn ray(&self, point: Point, direction: Direction) -> impl Iterator<Item = Point> + '_ {
+        let mut current = point;
+
+        std::iter::from_fn(move || {
+            current = self.step(current, direction)?;
+            Some(current)
+        })
+    }
+
+    fn neighbours<'a>(
+        &'a self,
+        point: Point,
+        directions: &'a [Direction],
+    ) -> impl Iterator<Item = Point> + 'a {
+        directions
+            .iter()
+            .filter_map(move |direction| self.step(point, *direction))
+    }
+
+    /// The up-to-four orthogonal neighbours that are on the grid.
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbours(point, &ORTHOGONAL)
+    }
+
+    /// The up-to-eight surrounding neighbours that are on the grid.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbours(point, &SURROUNDING)
+    }
+
+    /// Treats the grid as repeating forever in every direction.
+    ///
+    /// # Panics
+    ///
+    /// If the grid is empty, as there's nothing to wrap onto.
+    pub fn wrap(&self, (x, y): Direction) -> Point {
+        (
+            x.rem_euclid(self.width as isize) as usize,
+            y.rem_euclid(self.height as isize) as usize,
+        )
+    }
+
+    pub fn get_wrapped(&self, point: Direction) -> &T {
+        &self[self.wrap(point)]
+    }
+
+    /// The orthogonal neighbours, wrapping around the edges.
+    pub fn neighbours4_wrapped(&self, (x, y): Point) -> impl Iterator<Item = Point> + '_ {
+        ORTHOGONAL
+            .iter()
+            .map(move |(dx, dy)| self.wrap((x as isize + dx, y as isize + dy)))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    fn rebuild(&self, width: usize, height: usize, source: impl Fn(Point) -> Point) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|point| self[source(point)].clone())
+            .collect();
+
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Swaps rows and columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rebuild(self.height, self.width, |(x, y)| (y, x))
+    }
+
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height;
+
+        self.rebuild(self.height, self.width, |(x, y)| (y, height - 1 - x))
+    }
+
+    pub fn rotate_anticlockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+
+        self.rebuild(self.height, self.width, |(x, y)| (width - 1 - y, x))
+    }
+
+    /// Mirrors left to right.
+    pub fn flip_horizontal(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+
+        self.rebuild(self.width, self.height, |(x, y)| (width - 1 - x, y))
+    }
+
+    /// Mirrors top to bottom.
+    pub fn flip_vertical(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height;
+
+        self.rebuild(self.width, self.height, |(x, y)| (x, height - 1 - y))
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): Point) -> &Self::Output {
+        assert!(self.contains((x, y)), "({}, {}) is off the grid", x, y);
+
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, (x, y): Point) -> &mut Self::Output {
+        assert!(self.contains((x, y)), "({}, {}) is off the grid", x, y);
+
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+/// Parses any cell type that can be made from a single character, such as
+/// `char`, `u8` or a puzzle's own enum.
+impl<T: TryFrom<char>> FromStr for Grid<T>
+where
+    T::Error: fmt::Display,
+{
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::parse(s, |c| {
+            T::try_from(c).map_err(|error| Error::invalid_input(error.to_string()))
+        })
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = r#"
+abc
+def
+"#;
+
+    fn get_grid() -> Result<Grid<char>> {
+        INPUT.parse()
+    }
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        let grid = get_grid()?;
+
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(Some(&'f'), grid.get((2, 1)));
+        assert_eq!(None, grid.get((3, 1)));
+        assert_eq!(INPUT.trim(), grid.to_string());
+
+        assert_eq!(
+            Err("line 3: expected 2 cells but found 3".to_owned()),
+            "\nab\ncde".parse::<Grid<char>>().map_err(|e| e.to_string()),
+        );
+        assert_eq!(
+            Err("line 1, column 2: invalid digit 'x'".to_owned()),
+            Grid::parse("1x", |c| c.to_digit(10).ok_or_else(
+                || Error::invalid_input(format!("invalid digit {:?}", c))
+            ))
+            .map_err(|e| e.to_string()),
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_neighbours() -> Result<()> {
+        let grid = get_grid()?;
+
+        assert_eq!(
+            vec![(0, 0), (2, 0), (1, 1)],
+            grid.neighbours4((1, 0)).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![(0, 0), (1, 0), (2, 0), (0, 1), (2, 1)],
+            grid.neighbours8((1, 1)).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![(0, 1), (2, 0), (1, 0), (0, 1)],
+            grid.neighbours4_wrapped((0, 0)).collect::<Vec<_>>()
+        );
+        assert_eq!(&'f', grid.get_wrapped((-1, 3)));
+        assert_eq!(
+            vec![(1, 0), (2, 0)],
+            grid.ray((0, 0), (1, 0)).collect::<Vec<_>>()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_views() -> Result<()> {
+        let grid = get_grid()?;
+
+        assert_eq!(
+            vec![&['a', 'b', 'c'][..], &['d', 'e', 'f']],
+            grid.rows().collect::<Vec<_>>()
+        );
+        assert_eq!(vec![&'b', &'e'], grid.column(1).collect::<Vec<_>>());
+        assert_eq!(None, grid.column(3).next());
+        assert_eq!(
+            vec!["ad", "be", "cf"],
+            grid.columns()
+                .map(|column| column.collect::<String>())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(Some((1, 1)), grid.position(|c| *c == 'e'));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_transformations() -> Result<()> {
+        let grid = get_grid()?;
+
+        assert_eq!("ad\nbe\ncf", grid.transpose().to_string());
+        assert_eq!("da\neb\nfc", grid.rotate_clockwise().to_string());
+        assert_eq!("cf\nbe\nad", grid.rotate_anticlockwise().to_string());
+        assert_eq!("cba\nfed", grid.flip_horizontal().to_string());
+        assert_eq!("def\nabc", grid.flip_vertical().to_string());
+        assert_eq!(
+            grid,
+            grid.rotate_clockwise()
+                .rotate_clockwise()
+                .rotate_clockwise()
+                .rotate_clockwise()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,284 @@
+#![deny(clippy::expect_used, clippy::unwrap_used)]
+
+//! Number theory. Everything modular is taken in `i128`, multiplying
+//! without overflow for any modulus below 2¹²⁶.
+
+use std::collections::HashMap;
+use std::ops::{Div, Rem};
+
+/// The primitive integers, for [`gcd`] and [`lcm`].
+pub trait Integer: Copy + Eq + Div<Output = Self> + Rem<Output = Self> {
+    const ZERO: Self;
+
+    fn checked_abs(self) -> Option<Self>;
+
+    fn checked_mul(self, other: Self) -> Option<Self>;
+
+    fn wrapping_rem(self, other: Self) -> Self;
+}
+
+macro_rules! integer {
+    (signed: $($t:ty)*; unsigned: $($u:ty)*) => {
+        $(
+            impl Integer for $t {
+                const ZERO: Self = 0;
+
+                fn checked_abs(self) -> Option<Self> {
+                    <$t>::checked_abs(self)
+                }
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, other)
+                }
+
+                fn wrapping_rem(self, other: Self) -> Self {
+                    <$t>::wrapping_rem(self, other)
+                }
+            }
+        )*
+        $(
+            impl Integer for $u {
+                const ZERO: Self = 0;
+
+                fn checked_abs(self) -> Option<Self> {
+                    Some(self)
+                }
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$u>::checked_mul(self, other)
+                }
+
+                fn wrapping_rem(self, other: Self) -> Self {
+                    <$u>::wrapping_rem(self, other)
+                }
+            }
+        )*
+    };
+}
+
+integer!(signed: i8 i16 i32 i64 i128 isize; unsigned: u8 u16 u32 u64 u128 usize);
+
+/// The greatest common divisor, always non-negative; `None` if it doesn't
+/// fit in `T`, as with `gcd(i64::MIN, 0)`.
+///
+/// The remainders keep the sign of the dividend but still shrink, so the
+/// signs are only dropped at the end; the one remainder that overflows,
+/// `MIN % -1`, wraps to the right answer of zero.
+pub fn gcd<T: Integer>(a: T, b: T) -> Option<T> {
+    let (mut a, mut b) = (a, b);
+    while b != T::ZERO {
+        (a, b) = (b, a.wrapping_rem(b));
+    }
+
+    a.checked_abs()
+}
+
+/// The least common multiple, always non-negative; `None` if it doesn't
+/// fit in `T`.
+pub fn lcm<T: Integer>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+
+    (a / gcd(a, b)?).checked_mul(b)?.checked_abs()
+}
+
+/// The `(g, x, y)` where `g` is the greatest common divisor of `a` and
+/// `b`, and `a·x + b·y = g`; `None` if any of them overflows.
+pub fn extended_gcd(a: i128, b: i128) -> Option<(i128, i128, i128)> {
+    let step =
+        |value: i128, quotient: i128, next: i128| value.checked_sub(quotient.checked_mul(next)?);
+
+    let (mut r, mut next_r) = (a, b);
+    let (mut x, mut next_x) = (1_i128, 0);
+    let (mut y, mut next_y) = (0_i128, 1);
+    while next_r != 0 {
+        let quotient = r.checked_div(next_r)?;
+        (r, next_r) = (next_r, step(r, quotient, next_r)?);
+        (x, next_x) = (next_x, step(x, quotient, next_x)?);
+        (y, next_y) = (next_y, step(y, quotient, next_y)?);
+    }
+
+    match r < 0 {
+        true => Some((r.checked_neg()?, x.checked_neg()?, y.checked_neg()?)),
+        false => Some((r, x, y)),
+    }
+}
+
+/// `a·b mod m`, in `[0, m)`, falling back on doubling and adding should
+/// the product overflow.
+pub fn mul_mod(a: i128, b: i128, m: i128) -> i128 {
+    let (mut a, mut b) = (a.rem_euclid(m), b.rem_euclid(m));
+    if let Some(product) = a.checked_mul(b) {
+        return product % m;
+    }
+
+    let mut product = 0;
+    while b > 0 {
+        if b & 1 == 1 {
+            product = (product + a) % m;
+        }
+        a = (a + a) % m;
+        b >>= 1;
+    }
+
+    product
+}
+
+/// `base` to the power of `exponent`, modulo `m`, by repeated squaring.
+pub fn mod_pow(base: i128, mut exponent: u128, m: i128) -> i128 {
+    let mut result = 1 % m;
+    let mut square = base.rem_euclid(m);
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mul_mod(result, square, m);
+        }
+        square = mul_mod(square, square, m);
+        exponent >>= 1;
+    }
+
+    result
+}
+
+/// The `x` in `[0, m)` with `a·x ≡ 1 (mod m)`, if `a` and `m` are
+/// coprime.
+pub fn mod_inverse(a: i128, m: i128) -> Option<i128> {
+    match extended_gcd(a.rem_euclid(m), m)? {
+        (1, x, _) => Some(x.rem_euclid(m)),
+        _ => None,
+    }
+}
+
+/// The smallest non-negative `x` satisfying `x ≡ r (mod m)` for every
+/// `(r, m)`, along with the modulus it repeats with (the lcm of them
+/// all).
+///
+/// The moduli needn't be coprime; `None` if the congruences contradict
+/// one another, a modulus isn't positive or the answer overflows.
+pub fn crt(congruences: &[(i128, i128)]) -> Option<(i128, i128)> {
+    congruences
+        .iter()
+        .try_fold((0, 1), |(x, modulus), &(r, m)| {
+            if m <= 0 {
+                return None;
+            }
+
+            let (g, p, _) = extended_gcd(modulus, m)?;
+            let difference = r.checked_sub(x)?;
+            if difference % g != 0 {
+                return None;
+            }
+
+            // x + modulus·k ≡ r (mod m) when k ≡ p·(r - x)/g (mod m/g).
+            let lcm = lcm(modulus, m)?;
+            let k = mul_mod(p, difference / g, m / g);
+            let x = x.checked_add(mul_mod(modulus, k, lcm))?.rem_euclid(lcm);
+
+            Some((x, lcm))
+        })
+}
+
+/// The smallest non-negative `x` with `base^x ≡ target (mod m)`, by
+/// baby-step giant-step; `base` must be coprime to `m`.
+pub fn discrete_log(base: i128, target: i128, m: i128) -> Option<u128> {
+    if m <= 0 {
+        return None;
+    }
+
+    // The ceiling of √m, so that steps² ≥ m.
+    let root = m.isqrt();
+    let steps = match root * root == m {
+        true => root,
+        false => root + 1,
+    };
+
+    let mut baby = HashMap::new();
+    let mut power = 1 % m;
+    for j in 0..steps {
+        baby.entry(power).or_insert(j);
+        power = mul_mod(power, base, m);
+    }
+
+    let giant = mod_inverse(power, m)?;
+    let mut gamma = target.rem_euclid(m);
+    for i in 0..steps {
+        if let Some(j) = baby.get(&gamma) {
+            return u128::try_from(i * steps + j).ok();
+        }
+        gamma = mul_mod(gamma, giant, m);
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(Some(6), gcd(54_usize, 24));
+        assert_eq!(Some(6), gcd(-54_i64, 24));
+        assert_eq!(Some(5), gcd(0_u8, 5));
+        assert_eq!(Some(1), gcd(i32::MIN, -1));
+        assert_eq!(Some(1 << 30), gcd(i32::MIN, 1 << 30));
+        assert_eq!(None, gcd(i32::MIN, 0));
+        assert_eq!(
+            Some(4_686_774_924),
+            lcm(2028_usize, lcm(5898, 4702).unwrap_or(0))
+        );
+        assert_eq!(Some(0), lcm(0_i32, 7));
+        assert_eq!(None, lcm(u64::MAX, u64::MAX - 1));
+        assert_eq!(None, lcm(i8::MIN, 1));
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        let (g, x, y) = extended_gcd(240, 46).unwrap_or_default();
+
+        assert_eq!(2, g);
+        assert_eq!(g, 240 * x + 46 * y);
+        assert_eq!(None, extended_gcd(i128::MIN, 0));
+        assert_eq!(None, extended_gcd(i128::MIN, -1));
+    }
+
+    #[test]
+    fn test_modular() {
+        assert_eq!(Some(4), mod_inverse(3, 11));
+        assert_eq!(None, mod_inverse(4, 10));
+        assert_eq!(445, mod_pow(4, 13, 497));
+        assert_eq!(1, mod_pow(2, 0, 7));
+
+        let m = (1 << 100) + 277;
+        assert_eq!(mul_mod(m - 1, m - 1, m), 1);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(
+            Some((1_068_781, 3_162_341)),
+            crt(&[(0, 7), (-1, 13), (-4, 59), (-6, 31), (-7, 19)])
+        );
+        assert_eq!(Some((7, 12)), crt(&[(1, 6), (3, 4)]));
+        assert_eq!(None, crt(&[(1, 6), (2, 4)]));
+        assert_eq!(Some((0, 1)), crt(&[]));
+    }
+
+    #[test]
+    fn test_crt_invalid() {
+        assert_eq!(None, crt(&[(1, 6), (0, 0)]));
+        assert_eq!(None, crt(&[(1, -6)]));
+        assert_eq!(None, crt(&[(1, 2), (i128::MIN, 3)]));
+        assert_eq!(None, crt(&[(i128::MAX, 2), (i128::MAX - 1, i128::MAX)]));
+    }
+
+    #[test]
+    fn test_discrete_log() {
+        assert_eq!(Some(8), discrete_log(7, 5_764_801, 20_201_227));
+        assert_eq!(Some(11), discrete_log(7, 17_807_724, 20_201_227));
+        assert_eq!(Some(0), discrete_log(3, 1, 7));
+        assert_eq!(None, discrete_log(2, 3, 7));
+        assert_eq!(Some(0), discrete_log(5, 0, 1));
+        assert_eq!(None, discrete_log(5, 1, 0));
+    }
+}
@@ -0,0 +1,365 @@
+/// The 4x6 font used by most screen-drawing puzzles (2016/08, 2019/08,
+/// 2019/11, 2021/13, 2022/10). `Y` is the odd one out at five wide.
+pub const SMALL: &[(char, &str)] = &[
+    (
+        'A',
+        ".##.\n\
+         #..#\n\
+         #..#\n\
+         ####\n\
+         #..#\n\
+         #..#",
+    ),
+    (
+        'B',
+        "###.\n\
+         #..#\n\
+         ###.\n\
+         #..#\n\
+         #..#\n\
+         ###.",
+    ),
+    (
+        'C',
+        ".##.\n\
+         #..#\n\
+         #...\n\
+         #...\n\
+         #..#\n\
+         .##.",
+    ),
+    (
+        'E',
+        "####\n\
+         #...\n\
+         ###.\n\
+         #...\n\
+         #...\n\
+         ####",
+    ),
+    (
+        'F',
+        "####\n\
+         #...\n\
+         ###.\n\
+         #...\n\
+         #...\n\
+         #...",
+    ),
+    (
+        'G',
+        ".##.\n\
+         #..#\n\
+         #...\n\
+         #.##\n\
+         #..#\n\
+         .###",
+    ),
+    (
+        'H',
+        "#..#\n\
+         #..#\n\
+         ####\n\
+         #..#\n\
+         #..#\n\
+         #..#",
+    ),
+    (
+        'I',
+        ".###\n\
+         ..#.\n\
+         ..#.\n\
+         ..#.\n\
+         ..#.\n\
+         .###",
+    ),
+    (
+        'J',
+        "..##\n\
+         ...#\n\
+         ...#\n\
+         ...#\n\
+         #..#\n\
+         .##.",
+    ),
+    (
+        'K',
+        "#..#\n\
+         #.#.\n\
+         ##..\n\
+         #.#.\n\
+         #.#.\n\
+         #..#",
+    ),
+    (
+        'L',
+        "#...\n\
+         #...\n\
+         #...\n\
+         #...\n\
+         #...\n\
+         ####",
+    ),
+    (
+        'O',
+        ".##.\n\
+         #..#\n\
+         #..#\n\
+         #..#\n\
+         #..#\n\
+         .##.",
+    ),
+    (
+        'P',
+        "###.\n\
+         #..#\n\
+         #..#\n\
+         ###.\n\
+         #...\n\
+         #...",
+    ),
+    (
+        'R',
+        "###.\n\
+         #..#\n\
+         #..#\n\
+         ###.\n\
+         #.#.\n\
+         #..#",
+    ),
+    (
+        'S',
+        ".###\n\
+         #...\n\
+         #...\n\
+         .##.\n\
+         ...#\n\
+         ###.",
+    ),
+    (
+        'U',
+        "#..#\n\
+         #..#\n\
+         #..#\n\
+         #..#\n\
+         #..#\n\
+         .##.",
+    ),
+    (
+        'Y',
+        "#...#\n\
+         #...#\n\
+         .#.#.\n\
+         ..#..\n\
+         ..#..\n\
+         ..#..",
+    ),
+    (
+        'Z',
+        "####\n\
+         ...#\n\
+         ..#.\n\
+         .#..\n\
+         #...\n\
+         ####",
+    ),
+];
+
+/// The 6x10 font used by 2018/10's points of light.
+pub const LARGE: &[(char, &str)] = &[
+    (
+        'A',
+        "..##..\n\
+         .#..#.\n\
+         #....#\n\
+         #....#\n\
+         #....#\n\
+         ######\n\
+         #....#\n\
+         #....#\n\
+         #....#\n\
+         #....#",
+    ),
+    (
+        'B',
+        "#####.\n\
+         #....#\n\
+         #....#\n\
+         #....#\n\
+         #####.\n\
+         #....#\n\
+         #....#\n\
+         #....#\n\
+         #....#\n\
+         #####.",
+    ),
+    (
+        'C',
+        ".####.\n\
+         #....#\n\
+         #.....\n\
+         #.....\n\
+         #.....\n\
+         #.....\n\
+         #.....\n\
+         #.....\n\
+         #....#\n\
+         .####.",
+    ),
+    (
+        'E',
+        "######\n\
+         #.....\n\
+         #.....\n\
+         #.....\n\
+         #####.\n\
+         #.....\n\
+         #.....\n\
+         #.....\n\
+         #.....\n\
+         ######",
+    ),
+    (
+        'F',
+        "######\n\
+         #.....\n\
+         #.....\n\
+         #.....\n\
+         #####.\n\
+         #.....\n\
+         #.....\n\
+         #.....\n\
+         #.....\n\
+         #.....",
+    ),
+    (
+        'G',
+        ".####.\n\
+         #....#\n\
+         #.....\n\
+         #.....\n\
+         #.....\n\
+         #..###\n\
+         #....#\n\
+         #....#\n\
+         #...##\n\
+         .###.#",
+    ),
+    (
+        'H',
+        "#....#\n\
+         #....#\n\
+         #....#\n\
+         #....#\n\
+         ######\n\
+         #....#\n\
+         #....#\n\
+         #....#\n\
+         #....#\n\
+         #....#",
+    ),
+    (
+        'J',
+        "...###\n\
+         ....#.\n\
+         ....#.\n\
+         ....#.\n\
+         ....#.\n\
+         ....#.\n\
+         ....#.\n\
+         #...#.\n\
+         #...#.\n\
+         .###..",
+    ),
+    (
+        'K',
+        "#....#\n\
+         #...#.\n\
+         #..#..\n\
+         #.#...\n\
+         ##....\n\
+         ##....\n\
+         #.#...\n\
+         #..#..\n\
+         #...#.\n\
+         #....#",
+    ),
+    (
+        'L',
+        "#.....\n\
+         #.....\n\
+         #.....\n\
+         #.....\n\
+         #.....\n\
+         #.....\n\
+         #.....\n\
+         #.....\n\
+         #.....\n\
+         ######",
+    ),
+    (
+        'N',
+        "#....#\n\
+         ##...#\n\
+         ##...#\n\
+         #.#..#\n\
+         #.#..#\n\
+         #..#.#\n\
+         #..#.#\n\
+         #...##\n\
+         #...##\n\
+         #....#",
+    ),
+    (
+        'P',
+        "#####.\n\
+         #....#\n\
+         #....#\n\
+         #....#\n\
+         #####.\n\
+         #.....\n\
+         #.....\n\
+         #.....\n\
+         #.....\n\
+         #.....",
+    ),
+    (
+        'R',
+        "#####.\n\
+         #....#\n\
+         #....#\n\
+         #....#\n\
+         #####.\n\
+         #..#..\n\
+         #...#.\n\
+         #...#.\n\
+         #....#\n\
+         #....#",
+    ),
+    (
+        'X',
+        "#....#\n\
+         #....#\n\
+         .#..#.\n\
+         .#..#.\n\
+         ..##..\n\
+         ..##..\n\
+         .#..#.\n\
+         .#..#.\n\
+         #....#\n\
+         #....#",
+    ),
+    (
+        'Z',
+        "######\n\
+         .....#\n\
+         .....#\n\
+         ....#.\n\
+         ...#..\n\
+         ..#...\n\
+         .#....\n\
+         #.....\n\
+         #.....\n\
+         ######",
+    ),
+];
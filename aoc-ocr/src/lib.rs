@@ -0,0 +1,233 @@
+#![deny(clippy::expect_used, clippy::unwrap_used)]
+
+use std::cmp::Reverse;
+
+use aoc_error::{Error, Result};
+
+mod fonts;
+
+/// Characters drawn as a lit pixel by the various solutions.
+const LIT: [char; 3] = ['#', '█', '⬛'];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Font {
+    /// 4x6 letters, one blank column apart.
+    Small,
+    /// 6x10 letters, two blank columns apart.
+    Large,
+}
+
+impl Font {
+    fn from_height(height: usize) -> Result<Self> {
+        match height {
+            6 => Ok(Font::Small),
+            10 => Ok(Font::Large),
+            _ => Err(Error::invalid_input(format!(
+                "no font is {} pixels high",
+                height
+            ))),
+        }
+    }
+
+    fn glyphs(&self) -> Vec<(char, Vec<u16>)> {
+        let font = match self {
+            Font::Small => fonts::SMALL,
+            Font::Large => fonts::LARGE,
+        };
+
+        let mut glyphs = font
+            .iter()
+            .map(|(letter, glyph)| {
+                let rows = glyph
+                    .lines()
+                    .map(|row| row.chars().map(|c| LIT.contains(&c)).collect())
+                    .collect::<Vec<_>>();
+
+                (*letter, trim(get_columns(&rows)))
+            })
+            .collect::<Vec<_>>();
+        // Widest first, so that a narrow glyph never claims the start of a
+        // wider one.
+        glyphs.sort_by_key(|(_, columns)| Reverse(columns.len()));
+
+        glyphs
+    }
+}
+
+/// Each column as a bitmask, with the top row in the lowest bit.
+fn get_columns(rows: &[Vec<bool>]) -> Vec<u16> {
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+
+    (0..width)
+        .map(|x| {
+            rows.iter()
+                .enumerate()
+                .filter(|(_, row)| row.get(x).copied().unwrap_or(false))
+                .fold(0, |column, (y, _)| column | (1 << y))
+        })
+        .collect()
+}
+
+fn trim(columns: Vec<u16>) -> Vec<u16> {
+    let start = columns.iter().position(|column| *column != 0);
+    let end = columns.iter().rposition(|column| *column != 0);
+
+    match (start, end) {
+        (Some(start), Some(end)) => columns[start..=end].to_vec(),
+        _ => Vec::new(),
+    }
+}
+
+/// Reads the letters drawn in `image`, where each line is a row of pixels
+/// and `#`, `█` or `⬛` are lit; anything else is dark.
+pub fn recognise(image: &str) -> Result<String> {
+    let pixels = image
+        .lines()
+        .map(|line| line.chars().map(|c| LIT.contains(&c)).collect())
+        .collect::<Vec<_>>();
+
+    recognise_pixels(&pixels)
+}
+
+/// Reads the letters drawn by the lit points, wherever they are.
+pub fn recognise_points<I: IntoIterator<Item = (isize, isize)>>(points: I) -> Result<String> {
+    let points = points.into_iter().collect::<Vec<_>>();
+    let min_x = points.iter().map(|(x, _)| *x).min().unwrap_or(0);
+    let min_y = points.iter().map(|(_, y)| *y).min().unwrap_or(0);
+    let max_x = points.iter().map(|(x, _)| *x).max().unwrap_or(-1);
+    let max_y = points.iter().map(|(_, y)| *y).max().unwrap_or(-1);
+
+    let mut pixels = vec![vec![false; (max_x - min_x + 1) as usize]; (max_y - min_y + 1) as usize];
+    for (x, y) in points {
+        pixels[(y - min_y) as usize][(x - min_x) as usize] = true;
+    }
+
+    recognise_pixels(&pixels)
+}
+
+/// Reads the letters drawn in a grid of pixels, ignoring any blank border.
+pub fn recognise_pixels(pixels: &[Vec<bool>]) -> Result<String> {
+    let start = pixels.iter().position(|row| row.contains(&true));
+    let end = pixels.iter().rposition(|row| row.contains(&true));
+    let rows = match (start, end) {
+        (Some(start), Some(end)) => &pixels[start..=end],
+        _ => return Err(Error::invalid_input("nothing is drawn")),
+    };
+
+    let font = Font::from_height(rows.len())?;
+    let glyphs = font.glyphs();
+    let columns = get_columns(rows);
+
+    let mut letters = String::new();
+    let mut x = 0;
+    while x < columns.len() {
+        if columns[x] == 0 {
+            x += 1;
+            continue;
+        }
+
+        let (letter, width) = glyphs
+            .iter()
+            .find(|(_, glyph)| columns[x..].starts_with(glyph))
+            .map(|(letter, glyph)| (*letter, glyph.len()))
+            .ok_or_else(|| {
+                Error::invalid_input(format!("unrecognised letter at column {}", x + 1))
+            })?;
+
+        letters.push(letter);
+        x += width;
+    }
+
+    Ok(letters)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn draw(font: &[(char, &str)], letters: &str, spacing: usize) -> String {
+        let glyphs = letters
+            .chars()
+            .filter_map(|letter| font.iter().find(|(c, _)| *c == letter))
+            .map(|(_, glyph)| glyph.lines().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        (0..glyphs[0].len())
+            .map(|y| {
+                glyphs
+                    .iter()
+                    .map(|glyph| format!("{}{}", glyph[y], ".".repeat(spacing)))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_small_font() {
+        let letters = fonts::SMALL.iter().map(|(c, _)| *c).collect::<String>();
+
+        assert_eq!(
+            Ok(letters.clone()),
+            recognise(&draw(fonts::SMALL, &letters, 1))
+        );
+    }
+
+    #[test]
+    fn test_large_font() {
+        let letters = fonts::LARGE.iter().map(|(c, _)| *c).collect::<String>();
+
+        assert_eq!(
+            Ok(letters.clone()),
+            recognise(&draw(fonts::LARGE, &letters, 2))
+        );
+    }
+
+    #[test]
+    fn test_touching_letters() {
+        assert_eq!(
+            Ok("YEJIY".to_owned()),
+            recognise(&draw(fonts::SMALL, "YEJIY", 0))
+        );
+    }
+
+    #[test]
+    fn test_recognise_squares() {
+        let image = draw(fonts::SMALL, "HI", 1)
+            .replace('.', "⬜")
+            .replace('#', "⬛");
+
+        assert_eq!(Ok("HI".to_owned()), recognise(&image));
+    }
+
+    #[test]
+    fn test_recognise_points() {
+        let points = draw(fonts::SMALL, "OK", 1)
+            .lines()
+            .enumerate()
+            .flat_map(|(y, row)| {
+                row.chars()
+                    .enumerate()
+                    .filter(|(_, c)| *c == '#')
+                    .map(move |(x, _)| (x as isize - 40, y as isize + 7))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(Ok("OK".to_owned()), recognise_points(points));
+    }
+
+    #[test]
+    fn test_unrecognised() {
+        let image = "#..#\n#..#\n#..#\n#..#\n#..#\n####\n";
+
+        assert_eq!(
+            Err("unrecognised letter at column 1".to_owned()),
+            recognise(image).map_err(|error| error.to_string()),
+        );
+        assert_eq!(
+            Err("no font is 2 pixels high".to_owned()),
+            recognise("#\n#").map_err(|error| error.to_string()),
+        );
+    }
+}
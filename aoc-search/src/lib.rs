@@ -0,0 +1,415 @@
+#![deny(clippy::expect_used, clippy::unwrap_used)]
+
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+/// Something that can be searched from, such as a position on a map or a
+/// whole game state.
+///
+/// The `Context` is whatever the states are explored within (the map, the
+/// rules); use `()` for states that carry everything they need.
+pub trait State: Clone + Eq + Hash {
+    type Context: ?Sized;
+
+    /// Every state reachable in one move, with the cost of that move.
+    fn successors(&self, context: &Self::Context) -> Vec<(Self, usize)>;
+
+    fn is_goal(&self, context: &Self::Context) -> bool;
+
+    /// A lower bound on the remaining cost to a goal; only used by
+    /// [`astar`], where it must never overestimate.
+    fn heuristic(&self, _context: &Self::Context) -> usize {
+        0
+    }
+}
+
+/// How much work a search did.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Statistics {
+    /// States whose successors were generated.
+    pub expanded: usize,
+    /// Distinct states ever seen.
+    pub discovered: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Path<S> {
+    /// From the start to the goal, inclusive.
+    pub states: Vec<S>,
+    pub cost: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Search<S> {
+    pub path: Option<Path<S>>,
+    pub statistics: Statistics,
+}
+
+impl<S> Search<S> {
+    pub fn cost(&self) -> Option<usize> {
+        self.path.as_ref().map(|path| path.cost)
+    }
+
+    pub fn goal(&self) -> Option<&S> {
+        self.path.as_ref().and_then(|path| path.states.last())
+    }
+}
+
+/// Every state seen so far, with the best known way to reach it.
+struct Explored<S> {
+    states: Vec<S>,
+    indices: HashMap<S, usize>,
+    parents: Vec<Option<usize>>,
+    costs: Vec<usize>,
+}
+
+impl<S: State> Explored<S> {
+    fn new(start: S) -> Self {
+        Explored {
+            indices: HashMap::from([(start.clone(), 0)]),
+            states: vec![start],
+            parents: vec![None],
+            costs: vec![0],
+        }
+    }
+
+    /// Records `state` as reached from `parent` at `cost`, unless it was
+    /// already reached at least as cheaply. Returns its index if recorded.
+    fn relax(&mut self, state: S, parent: usize, cost: usize) -> Option<usize> {
+        match self.indices.entry(state) {
+            Entry::Occupied(entry) => {
+                let index = *entry.get();
+                if cost >= self.costs[index] {
+                    return None;
+                }
+                self.parents[index] = Some(parent);
+                self.costs[index] = cost;
+
+                Some(index)
+            }
+            Entry::Vacant(entry) => {
+                let index = self.states.len();
+                self.states.push(entry.key().clone());
+                entry.insert(index);
+                self.parents.push(Some(parent));
+                self.costs.push(cost);
+
+                Some(index)
+            }
+        }
+    }
+
+    /// The states from the start to `index`, inclusive.
+    fn path_to(&self, index: usize) -> Vec<S> {
+        let mut indices = vec![index];
+        let mut current = index;
+        while let Some(parent) = self.parents[current] {
+            indices.push(parent);
+            current = parent;
+        }
+
+        indices
+            .into_iter()
+            .rev()
+            .map(|index| self.states[index].clone())
+            .collect()
+    }
+
+    fn into_search(self, goal: Option<usize>, expanded: usize) -> Search<S> {
+        Search {
+            path: goal.map(|index| Path {
+                states: self.path_to(index),
+                cost: self.costs[index],
+            }),
+            statistics: Statistics {
+                expanded,
+                discovered: self.states.len(),
+            },
+        }
+    }
+}
+
+/// Breadth-first search for the goal with the fewest moves, ignoring their
+/// costs; the path's cost is its number of moves.
+pub fn bfs<S: State>(start: S, context: &S::Context) -> Search<S> {
+    let mut explored = Explored::new(start);
+    let mut queue = VecDeque::from([0]);
+    let mut expanded = 0;
+
+    while let Some(index) = queue.pop_front() {
+        if explored.states[index].is_goal(context) {
+            return explored.into_search(Some(index), expanded);
+        }
+
+        expanded += 1;
+        let steps = explored.costs[index] + 1;
+        for (successor, _) in explored.states[index].successors(context) {
+            if !explored.indices.contains_key(&successor) {
+                if let Some(successor) = explored.relax(successor, index, steps) {
+                    queue.push_back(successor);
+                }
+            }
+        }
+    }
+
+    explored.into_search(None, expanded)
+}
+
+fn best_first<S: State>(
+    start: S,
+    context: &S::Context,
+    heuristic: impl Fn(&S) -> usize,
+) -> Search<S> {
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), 0))]);
+    let mut explored = Explored::new(start);
+    let mut expanded = 0;
+
+    while let Some(Reverse((priority, index))) = heap.pop() {
+        let cost = explored.costs[index];
+        if priority > cost + heuristic(&explored.states[index]) {
+            // Superseded by a cheaper route found since it was queued.
+            continue;
+        }
+        if explored.states[index].is_goal(context) {
+            return explored.into_search(Some(index), expanded);
+        }
+
+        expanded += 1;
+        for (successor, step) in explored.states[index].successors(context) {
+            let priority = cost + step + heuristic(&successor);
+            if let Some(successor) = explored.relax(successor, index, cost + step) {
+                heap.push(Reverse((priority, successor)));
+            }
+        }
+    }
+
+    explored.into_search(None, expanded)
+}
+
+/// The cheapest path to a goal.
+pub fn dijkstra<S: State>(start: S, context: &S::Context) -> Search<S> {
+    best_first(start, context, |_| 0)
+}
+
+/// The cheapest path to a goal, guided by [`State::heuristic`].
+pub fn astar<S: State>(start: S, context: &S::Context) -> Search<S> {
+    best_first(start, context, |state| state.heuristic(context))
+}
+
+/// The cheapest cost to every state reachable from `start`, ignoring
+/// goals; handy for building a smaller graph between points of interest.
+pub fn distances<S: State>(start: S, context: &S::Context) -> HashMap<S, usize> {
+    let mut heap = BinaryHeap::from([Reverse((0, 0))]);
+    let mut explored = Explored::new(start);
+
+    while let Some(Reverse((cost, index))) = heap.pop() {
+        if cost > explored.costs[index] {
+            continue;
+        }
+        for (successor, step) in explored.states[index].successors(context) {
+            if let Some(successor) = explored.relax(successor, index, cost + step) {
+                heap.push(Reverse((cost + step, successor)));
+            }
+        }
+    }
+
+    explored.states.into_iter().zip(explored.costs).collect()
+}
+
+/// Breadth-first search from both `start` and `goal` at once, meeting in
+/// the middle; moves must be reversible, so that `successors` also gives
+/// the predecessors. `is_goal` is ignored in favour of `goal`.
+pub fn bidirectional_bfs<S: State>(start: S, goal: S, context: &S::Context) -> Search<S> {
+    let mut forwards = Explored::new(start);
+    let mut backwards = Explored::new(goal);
+    let mut frontiers = (vec![0], vec![0]);
+    let mut expanded = 0;
+
+    let meeting = loop {
+        if let Some(index) = backwards.indices.get(&forwards.states[0]) {
+            break Some((0, *index));
+        }
+        if frontiers.0.is_empty() || frontiers.1.is_empty() {
+            break None;
+        }
+
+        // Grow whichever side has the smaller frontier.
+        let forwards_first = frontiers.0.len() <= frontiers.1.len();
+        let (explored, other, frontier) = match forwards_first {
+            true => (&mut forwards, &backwards, &mut frontiers.0),
+            false => (&mut backwards, &forwards, &mut frontiers.1),
+        };
+
+        let mut next = Vec::new();
+        let mut met = None;
+        for index in frontier.drain(..) {
+            expanded += 1;
+            let steps = explored.costs[index] + 1;
+            for (successor, _) in explored.states[index].successors(context) {
+                if explored.indices.contains_key(&successor) {
+                    continue;
+                }
+                let Some(successor) = explored.relax(successor, index, steps) else {
+                    continue;
+                };
+                if let Some(other_index) = other.indices.get(&explored.states[successor]) {
+                    let total = steps + other.costs[*other_index];
+                    if met.is_none_or(|(_, _, best)| total < best) {
+                        met = Some((successor, *other_index, total));
+                    }
+                }
+                next.push(successor);
+            }
+        }
+        *frontier = next;
+
+        if let Some((index, other_index, _)) = met {
+            break Some(match forwards_first {
+                true => (index, other_index),
+                false => (other_index, index),
+            });
+        }
+    };
+
+    let statistics = Statistics {
+        expanded,
+        discovered: forwards.states.len() + backwards.states.len(),
+    };
+    let path = meeting.map(|(forwards_index, backwards_index)| {
+        let mut states = forwards.path_to(forwards_index);
+        let mut rest = backwards.path_to(backwards_index);
+        rest.pop();
+        states.extend(rest.into_iter().rev());
+
+        Path {
+            cost: forwards.costs[forwards_index] + backwards.costs[backwards_index],
+            states,
+        }
+    });
+
+    Search { path, statistics }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A maze where `#` is a wall and `.` costs 1 to enter, digits cost
+    /// their value.
+    const MAZE: &str = "\
+S..#....
+.#.#.##.
+.#...#..
+.####.#.
+......#E";
+
+    struct Maze {
+        cells: Vec<Vec<u8>>,
+        goal: (usize, usize),
+    }
+
+    impl Maze {
+        fn new(input: &str) -> (Self, Position) {
+            let cells = input
+                .lines()
+                .map(|line| line.bytes().collect::<Vec<_>>())
+                .collect::<Vec<_>>();
+            let find = |target| {
+                cells
+                    .iter()
+                    .enumerate()
+                    .find_map(|(y, row)| row.iter().position(|c| *c == target).map(|x| (x, y)))
+                    .unwrap_or_default()
+            };
+            let (start, goal) = (find(b'S'), find(b'E'));
+
+            (Maze { cells, goal }, Position(start))
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Position((usize, usize));
+
+    impl State for Position {
+        type Context = Maze;
+
+        fn successors(&self, maze: &Maze) -> Vec<(Self, usize)> {
+            let (x, y) = self.0;
+            [(0, -1), (-1, 0), (1, 0), (0, 1)]
+                .into_iter()
+                .filter_map(|(dx, dy)| {
+                    let (x, y) = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+                    let cost = match maze.cells.get(y)?.get(x)? {
+                        b'#' => return None,
+                        digit @ b'1'..=b'9' => usize::from(digit - b'0'),
+                        _ => 1,
+                    };
+
+                    Some((Position((x, y)), cost))
+                })
+                .collect()
+        }
+
+        fn is_goal(&self, maze: &Maze) -> bool {
+            self.0 == maze.goal
+        }
+
+        fn heuristic(&self, maze: &Maze) -> usize {
+            self.0 .0.abs_diff(maze.goal.0) + self.0 .1.abs_diff(maze.goal.1)
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let (maze, start) = Maze::new(MAZE);
+        let search = bfs(start, &maze);
+
+        assert_eq!(Some(15), search.cost());
+        assert_eq!(Some(16), search.path.map(|path| path.states.len()));
+        assert!(search.statistics.expanded <= search.statistics.discovered);
+    }
+
+    #[test]
+    fn test_weighted() {
+        let (maze, start) = Maze::new("S.9.E\n.###.\n.....");
+
+        assert_eq!(Some(4), bfs(start.clone(), &maze).cost());
+        assert_eq!(Some(8), dijkstra(start.clone(), &maze).cost());
+        assert_eq!(Some(8), astar(start.clone(), &maze).cost());
+        assert!(
+            astar(start.clone(), &maze).statistics.expanded
+                <= dijkstra(start, &maze).statistics.expanded
+        );
+    }
+
+    #[test]
+    fn test_bidirectional() {
+        let (maze, start) = Maze::new(MAZE);
+        let goal = Position(maze.goal);
+        let search = bidirectional_bfs(start.clone(), goal.clone(), &maze);
+
+        assert_eq!(Some(15), search.cost());
+        let states = search.path.map(|path| path.states).unwrap_or_default();
+        assert_eq!(Some(&start), states.first());
+        assert_eq!(Some(&goal), states.last());
+        assert!(states
+            .windows(2)
+            .all(|pair| pair[0].successors(&maze).iter().any(|(s, _)| *s == pair[1])));
+
+        assert_eq!(
+            Some(0),
+            bidirectional_bfs(start.clone(), start, &maze).cost()
+        );
+    }
+
+    #[test]
+    fn test_unreachable() {
+        let (maze, start) = Maze::new(&MAZE.replace(".####.#.", ".####.##"));
+
+        assert_eq!(None, bfs(start.clone(), &maze).path);
+        assert_eq!(None, dijkstra(start.clone(), &maze).path);
+        assert_eq!(25, distances(start, &maze).len());
+    }
+}
@@ -0,0 +1,172 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::Part;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct Recorded {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part_one: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part_two: Option<String>,
+}
+
+/// The known answers for a single year, stored as `answers/<year>.toml` at
+/// the root of the repository with a table per (zero-padded) day:
+///
+/// ```toml
+/// [10]
+/// part_one = "13140"
+/// part_two = "EHZFZHCZ"
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Answers {
+    days: BTreeMap<String, Recorded>,
+}
+
+/// How an answer compares with the one on record.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Incorrect { expected: String },
+    Unrecorded,
+    Failed(String),
+}
+
+impl Answers {
+    fn key(day: u8) -> String {
+        format!("{:02}", day)
+    }
+
+    pub fn path(year: u16) -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join("answers")
+            .join(format!("{}.toml", year))
+    }
+
+    /// The answers recorded for `year`; none at all if there's no file yet.
+    pub fn load(year: u16) -> Result<Self, String> {
+        let path = Answers::path(year);
+
+        match fs::read_to_string(&path) {
+            Ok(contents) => contents.parse(),
+            Err(error) if error.kind() == ErrorKind::NotFound => Ok(Answers::default()),
+            Err(error) => Err(format!("Error reading {}: {}", path.display(), error)),
+        }
+    }
+
+    pub fn save(&self, year: u16) -> Result<(), String> {
+        let path = Answers::path(year);
+        let contents = toml::to_string(self).map_err(|error| error.to_string())?;
+
+        if let Some(directory) = path.parent() {
+            fs::create_dir_all(directory)
+                .map_err(|error| format!("Error creating {}: {}", directory.display(), error))?;
+        }
+        fs::write(&path, contents)
+            .map_err(|error| format!("Error writing {}: {}", path.display(), error))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.days.is_empty()
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        let recorded = self.days.get(&Answers::key(day))?;
+
+        match part {
+            Part::One => recorded.part_one.as_deref(),
+            Part::Two => recorded.part_two.as_deref(),
+        }
+    }
+
+    pub fn set(&mut self, day: u8, part: Part, answer: String) {
+        let recorded = self.days.entry(Answers::key(day)).or_default();
+
+        match part {
+            Part::One => recorded.part_one = Some(answer),
+            Part::Two => recorded.part_two = Some(answer),
+        }
+    }
+
+    pub fn check(&self, day: u8, part: Part, answer: &Result<String, String>) -> Verdict {
+        match (answer, self.get(day, part)) {
+            (Err(error), _) => Verdict::Failed(error.clone()),
+            (Ok(_), None) => Verdict::Unrecorded,
+            (Ok(answer), Some(expected)) if answer == expected => Verdict::Correct,
+            (Ok(_), Some(expected)) => Verdict::Incorrect {
+                expected: expected.to_owned(),
+            },
+        }
+    }
+}
+
+impl std::str::FromStr for Answers {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        toml::from_str(s).map_err(|error| error.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &str = r#"[01]
+part_one = "24000"
+part_two = "45000"
+
+[10]
+part_one = "13140"
+"#;
+
+    #[test]
+    fn test_round_trip() {
+        let answers = ANSWERS.parse::<Answers>();
+
+        assert_eq!(
+            Ok(Some("45000")),
+            answers.as_ref().map(|answers| answers.get(1, Part::Two)),
+        );
+        assert_eq!(
+            Ok(None),
+            answers.as_ref().map(|answers| answers.get(10, Part::Two)),
+        );
+        assert_eq!(
+            Ok(ANSWERS.to_owned()),
+            answers.and_then(|answers| toml::to_string(&answers).map_err(|e| e.to_string())),
+        );
+    }
+
+    #[test]
+    fn test_check() {
+        let mut answers = Answers::default();
+        answers.set(20, Part::One, "20899048083289".to_owned());
+
+        assert_eq!(
+            Verdict::Correct,
+            answers.check(20, Part::One, &Ok("20899048083289".to_owned())),
+        );
+        assert_eq!(
+            Verdict::Incorrect {
+                expected: "20899048083289".to_owned()
+            },
+            answers.check(20, Part::One, &Ok("42".to_owned())),
+        );
+        assert_eq!(
+            Verdict::Unrecorded,
+            answers.check(20, Part::Two, &Ok("273".to_owned())),
+        );
+        assert_eq!(
+            Verdict::Failed("Invalid input".to_owned()),
+            answers.check(20, Part::One, &Err("Invalid input".to_owned())),
+        );
+    }
+}
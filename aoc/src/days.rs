@@ -0,0 +1,365 @@
+use crate::{Answer, Day};
+
+macro_rules! day {
+    ($year:literal, $day:literal, $directory:literal, $krate:ident $(,)?) => {
+        day!(
+            $year,
+            $day,
+            $directory,
+            $krate::get_part_one,
+            $krate::get_part_two
+        )
+    };
+    ($year:literal, $day:literal, $directory:literal, $part_one:expr $(,)?) => {
+        Day {
+            year: $year,
+            day: $day,
+            directory: $directory,
+            part_one: |input| $part_one(input).into_answer(),
+            part_two: None,
+        }
+    };
+    ($year:literal, $day:literal, $directory:literal, $part_one:expr, $part_two:expr $(,)?) => {
+        Day {
+            year: $year,
+            day: $day,
+            directory: $directory,
+            part_one: |input| $part_one(input).into_answer(),
+            part_two: Some(|input| $part_two(input).into_answer()),
+        }
+    };
+}
+
+/// Every day whose crate exposes library entry points, in order.
+pub fn get_days() -> Vec<Day> {
+    vec![
+        day!(2015, 1, "2015/01", aoc_2015_01),
+        day!(2015, 2, "2015/02", aoc_2015_02),
+        day!(2015, 3, "2015/03", aoc_2015_03),
+        day!(2015, 4, "2015/04", aoc_2015_04),
+        day!(2015, 5, "2015/05", aoc_2015_05),
+        day!(2015, 6, "2015/06", aoc_2015_06),
+        day!(2015, 7, "2015/07", aoc_2015_07),
+        day!(2015, 8, "2015/08", aoc_2015_08),
+        day!(2015, 9, "2015/09", aoc_2015_09),
+        day!(2015, 10, "2015/10", aoc_2015_10),
+        day!(2015, 11, "2015/11", aoc_2015_11),
+        day!(2015, 12, "2015/12", aoc_2015_12),
+        day!(2015, 13, "2015/13", aoc_2015_13),
+        day!(2015, 14, "2015/14", aoc_2015_14),
+        day!(2015, 15, "2015/15", aoc_2015_15),
+        day!(2015, 16, "2015/16", aoc_2015_16),
+        day!(2015, 17, "2015/17", aoc_2015_17),
+        day!(2015, 18, "2015/18", aoc_2015_18),
+        day!(2015, 19, "2015/19", aoc_2015_19),
+        day!(2015, 20, "2015/20", aoc_2015_20),
+        day!(2015, 21, "2015/21", aoc_2015_21),
+        day!(2015, 22, "2015/22", aoc_2015_22),
+        day!(2015, 23, "2015/23", aoc_2015_23),
+        day!(2015, 24, "2015/24", aoc_2015_24),
+        day!(2015, 25, "2015/25", aoc_2015_25::get_part_one),
+        day!(2016, 1, "2016/01", aoc_2016_01),
+        day!(2016, 2, "2016/02", aoc_2016_02),
+        day!(2016, 3, "2016/03", aoc_2016_03),
+        day!(2016, 4, "2016/04", aoc_2016_04),
+        day!(2016, 5, "2016/05", aoc_2016_05),
+        day!(2016, 6, "2016/06", aoc_2016_06),
+        day!(2016, 7, "2016/07", aoc_2016_07),
+        day!(2016, 8, "2016/08", aoc_2016_08),
+        day!(2016, 9, "2016/09", aoc_2016_09),
+        day!(2016, 10, "2016/10", aoc_2016_10),
+        day!(2016, 11, "2016/11", aoc_2016_11),
+        day!(2016, 12, "2016/12", aoc_2016_12),
+        day!(2016, 13, "2016/13", aoc_2016_13),
+        day!(2016, 14, "2016/14", aoc_2016_14),
+        day!(2016, 15, "2016/15", aoc_2016_15),
+        day!(2016, 16, "2016/16", aoc_2016_16),
+        day!(2016, 17, "2016/17", aoc_2016_17),
+        day!(2016, 18, "2016/18", aoc_2016_18),
+        day!(2016, 19, "2016/19", aoc_2016_19),
+        day!(2016, 20, "2016/20", aoc_2016_20),
+        day!(2016, 21, "2016/21", aoc_2016_21),
+        day!(2016, 22, "2016/22", aoc_2016_22::get_part_one),
+        day!(2016, 23, "2016/23", aoc_2016_23),
+        day!(2016, 24, "2016/24", aoc_2016_24),
+        day!(2016, 25, "2016/25", aoc_2016_25::get_part_one),
+        day!(
+            2017,
+            1,
+            "2017/01",
+            aoc_2017_01::get_sum_of_identical_digits,
+            aoc_2017_01::get_sum_of_halfway_digits
+        ),
+        day!(
+            2017,
+            2,
+            "2017/02",
+            aoc_2017_02::get_checksum,
+            aoc_2017_02::get_evenly_divisible_values
+        ),
+        day!(2017, 3, "2017/03", aoc_2017_03),
+        day!(
+            2017,
+            4,
+            "2017/04",
+            aoc_2017_04::get_valid_passphrase_count,
+            aoc_2017_04::get_valid_non_anagram_passphrase_count
+        ),
+        day!(2017, 5, "2017/05", aoc_2017_05),
+        day!(
+            2017,
+            6,
+            "2017/06",
+            aoc_2017_06::get_steps_to_repeat,
+            aoc_2017_06::get_infinite_loop_cycles
+        ),
+        day!(2017, 7, "2017/07", aoc_2017_07),
+        day!(2017, 8, "2017/08", aoc_2017_08),
+        day!(2017, 9, "2017/09", aoc_2017_09),
+        day!(2017, 10, "2017/10", aoc_2017_10),
+        day!(2017, 11, "2017/11", aoc_2017_11),
+        day!(2017, 12, "2017/12", aoc_2017_12),
+        day!(
+            2017,
+            13,
+            "2017/13",
+            aoc_2017_13::get_severity,
+            aoc_2017_13::get_delay
+        ),
+        day!(2017, 14, "2017/14", aoc_2017_14),
+        day!(2017, 15, "2017/15", aoc_2017_15),
+        day!(2017, 16, "2017/16", aoc_2017_16),
+        day!(2017, 17, "2017/17", aoc_2017_17),
+        day!(2017, 18, "2017/18", aoc_2017_18),
+        day!(2017, 19, "2017/19", aoc_2017_19),
+        day!(2017, 20, "2017/20", aoc_2017_20),
+        day!(2017, 21, "2017/21", aoc_2017_21),
+        day!(2017, 22, "2017/22", aoc_2017_22),
+        day!(2017, 23, "2017/23", aoc_2017_23),
+        day!(2017, 24, "2017/24", aoc_2017_24),
+        day!(2017, 25, "2017/25", aoc_2017_25::get_part_one),
+        day!(
+            2018,
+            1,
+            "2018/rs-01",
+            aoc_2018_rs_01::get_resulting_frequency,
+            aoc_2018_rs_01::get_first_duplicated_frequency,
+        ),
+        day!(
+            2018,
+            2,
+            "2018/rs-02",
+            aoc_2018_rs_02::get_checksum,
+            aoc_2018_rs_02::get_common_letters,
+        ),
+        day!(
+            2018,
+            3,
+            "2018/rs-03",
+            aoc_2018_rs_03::get_overlapping_inches_count,
+            aoc_2018_rs_03::get_non_overlapping_claim_id,
+        ),
+        day!(
+            2018,
+            4,
+            "2018/rs-04",
+            aoc_2018_rs_04::get_strategy_one,
+            aoc_2018_rs_04::get_strategy_two,
+        ),
+        day!(2018, 5, "2018/rs-05", aoc_2018_rs_05),
+        day!(
+            2018,
+            6,
+            "2018/rs-06",
+            aoc_2018_rs_06::get_part_one,
+            |input| aoc_2018_rs_06::get_part_two(input, 10_000),
+        ),
+        day!(2018, 7, "2018/07", aoc_2018_07),
+        day!(2018, 8, "2018/rs-08", aoc_2018_rs_08),
+        day!(2018, 9, "2018/rs-09", aoc_2018_rs_09),
+        day!(2018, 10, "2018/rs-10", aoc_2018_rs_10),
+        day!(2018, 11, "2018/rs-11", aoc_2018_rs_11),
+        day!(2018, 15, "2018/15", aoc_2018_15),
+        day!(2018, 16, "2018/16", aoc_2018_16),
+        day!(2018, 17, "2018/17", aoc_2018_17),
+        day!(2018, 18, "2018/18", aoc_2018_18),
+        day!(2018, 19, "2018/19", aoc_2018_19),
+        day!(2018, 20, "2018/20", aoc_2018_20),
+        day!(2018, 21, "2018/21", aoc_2018_21),
+        day!(2018, 22, "2018/22", aoc_2018_22),
+        day!(2018, 23, "2018/23", aoc_2018_23),
+        day!(2018, 24, "2018/24", aoc_2018_24),
+        day!(2018, 25, "2018/25", aoc_2018_25::get_constellation_count),
+        day!(2019, 1, "2019/01", aoc_2019_01),
+        day!(2019, 2, "2019/02", aoc_2019_02),
+        day!(2019, 3, "2019/03", aoc_2019_03),
+        day!(2019, 4, "2019/04", aoc_2019_04),
+        day!(2019, 5, "2019/05", aoc_2019_05),
+        day!(2019, 6, "2019/06", aoc_2019_06),
+        day!(2019, 7, "2019/07", aoc_2019_07),
+        day!(2019, 8, "2019/08", aoc_2019_08),
+        day!(2019, 9, "2019/09", aoc_2019_09),
+        day!(2019, 10, "2019/10", aoc_2019_10),
+        day!(2019, 11, "2019/11", aoc_2019_11),
+        day!(2019, 12, "2019/12", aoc_2019_12),
+        day!(2019, 13, "2019/13", aoc_2019_13),
+        day!(2019, 14, "2019/14", aoc_2019_14),
+        day!(2019, 15, "2019/15", aoc_2019_15),
+        day!(2019, 16, "2019/16", aoc_2019_16),
+        day!(2019, 17, "2019/17", aoc_2019_17),
+        day!(2019, 18, "2019/18", aoc_2019_18),
+        day!(2019, 19, "2019/19", aoc_2019_19),
+        day!(2019, 20, "2019/20", aoc_2019_20),
+        day!(2019, 21, "2019/21", aoc_2019_21),
+        day!(2019, 22, "2019/22", aoc_2019_22),
+        day!(2019, 23, "2019/23", aoc_2019_23),
+        day!(2019, 24, "2019/24", aoc_2019_24),
+        day!(2019, 25, "2019/25", aoc_2019_25::get_part_one),
+        day!(2020, 1, "2020/01", aoc_2020_01),
+        day!(2020, 2, "2020/02", aoc_2020_02),
+        day!(2020, 3, "2020/03", aoc_2020_03),
+        day!(2020, 4, "2020/04", aoc_2020_04),
+        day!(2020, 5, "2020/05", aoc_2020_05),
+        day!(2020, 6, "2020/06", aoc_2020_06),
+        day!(2020, 7, "2020/07", aoc_2020_07),
+        day!(2020, 8, "2020/08", aoc_2020_08),
+        day!(2020, 9, "2020/09", aoc_2020_09),
+        day!(2020, 10, "2020/10", aoc_2020_10),
+        day!(2020, 11, "2020/11", aoc_2020_11),
+        day!(2020, 12, "2020/12", aoc_2020_12),
+        day!(2020, 13, "2020/13", aoc_2020_13),
+        day!(2020, 14, "2020/14", aoc_2020_14),
+        day!(2020, 15, "2020/15", aoc_2020_15),
+        day!(2020, 16, "2020/16", aoc_2020_16),
+        day!(2020, 17, "2020/17", aoc_2020_17),
+        day!(2020, 18, "2020/18", aoc_2020_18),
+        day!(2020, 19, "2020/19", aoc_2020_19),
+        day!(2020, 20, "2020/20", aoc_2020_20),
+        day!(2020, 21, "2020/21", aoc_2020_21),
+        day!(2020, 22, "2020/22", aoc_2020_22),
+        day!(2020, 23, "2020/23", aoc_2020_23),
+        day!(2020, 24, "2020/24", aoc_2020_24),
+        day!(2020, 25, "2020/25", aoc_2020_25::get_part_one),
+        day!(
+            2021,
+            1,
+            "2021/01",
+            aoc_2021_01::get_larger_measurements,
+            aoc_2021_01::get_larger_sums,
+        ),
+        day!(2021, 2, "2021/02", aoc_2021_02),
+        day!(2021, 3, "2021/03", aoc_2021_03),
+        day!(2021, 4, "2021/04", aoc_2021_04),
+        day!(2021, 5, "2021/05", aoc_2021_05),
+        day!(2021, 6, "2021/06", aoc_2021_06),
+        day!(2021, 7, "2021/07", aoc_2021_07),
+        day!(2021, 8, "2021/08", aoc_2021_08),
+        day!(2021, 9, "2021/09", aoc_2021_09),
+        day!(2021, 10, "2021/10", aoc_2021_10),
+        day!(2021, 11, "2021/11", aoc_2021_11),
+        day!(2021, 12, "2021/12", aoc_2021_12),
+        day!(2021, 13, "2021/13", aoc_2021_13),
+        day!(2021, 14, "2021/14", aoc_2021_14),
+        day!(2021, 15, "2021/15", aoc_2021_15),
+        day!(2021, 16, "2021/16", aoc_2021_16),
+        day!(2021, 17, "2021/17", aoc_2021_17),
+        day!(2021, 18, "2021/18", aoc_2021_18),
+        day!(2021, 19, "2021/19", aoc_2021_19),
+        day!(2021, 20, "2021/20", aoc_2021_20),
+        day!(2021, 21, "2021/21", aoc_2021_21),
+        day!(2021, 22, "2021/22", aoc_2021_22),
+        day!(2021, 23, "2021/23", aoc_2021_23),
+        day!(2021, 24, "2021/24", aoc_2021_24),
+        day!(2021, 25, "2021/25", aoc_2021_25::get_part_one),
+        day!(2022, 1, "2022/01", aoc_2022_01),
+        day!(2022, 2, "2022/02", aoc_2022_02),
+        day!(2022, 3, "2022/03", aoc_2022_03),
+        day!(2022, 4, "2022/04", aoc_2022_04),
+        day!(2022, 5, "2022/05", aoc_2022_05),
+        day!(2022, 6, "2022/06", aoc_2022_06),
+        day!(2022, 7, "2022/07", aoc_2022_07),
+        day!(2022, 8, "2022/08", aoc_2022_08),
+        day!(2022, 9, "2022/09", aoc_2022_09),
+        day!(2022, 10, "2022/10", aoc_2022_10),
+        day!(2022, 11, "2022/11", aoc_2022_11),
+        day!(2022, 12, "2022/12", aoc_2022_12),
+        day!(2022, 13, "2022/13", aoc_2022_13),
+        day!(2022, 14, "2022/14", aoc_2022_14),
+        day!(
+            2022,
+            15,
+            "2022/15",
+            |input| aoc_2022_15::get_part_one(input, 2_000_000),
+            aoc_2022_15::get_part_two,
+        ),
+        day!(2022, 16, "2022/16", aoc_2022_16),
+        day!(2022, 17, "2022/17", aoc_2022_17),
+        day!(2022, 18, "2022/18", aoc_2022_18),
+        day!(2022, 19, "2022/19", aoc_2022_19),
+        day!(2022, 20, "2022/20", aoc_2022_20),
+        day!(2022, 21, "2022/21", aoc_2022_21),
+        day!(2022, 22, "2022/22", aoc_2022_22),
+        day!(2022, 23, "2022/23", aoc_2022_23),
+        day!(2022, 24, "2022/24", aoc_2022_24),
+        day!(2022, 25, "2022/25", aoc_2022_25::get_part_one),
+        day!(2023, 1, "2023/01", aoc_2023_01),
+        day!(2023, 2, "2023/02", aoc_2023_02),
+        day!(2023, 3, "2023/03", aoc_2023_03),
+        day!(2023, 4, "2023/04", aoc_2023_04),
+        day!(2023, 5, "2023/05", aoc_2023_05),
+        day!(2023, 6, "2023/06", aoc_2023_06),
+        day!(2023, 7, "2023/07", aoc_2023_07),
+        day!(2023, 8, "2023/08", aoc_2023_08),
+        day!(2023, 9, "2023/09", aoc_2023_09),
+        day!(2023, 10, "2023/10", aoc_2023_10),
+        day!(2023, 11, "2023/11", aoc_2023_11::get_part_one, |input| {
+            aoc_2023_11::get_part_two(input, 1_000_000)
+        },),
+        day!(2023, 12, "2023/12", aoc_2023_12),
+        day!(2023, 13, "2023/13", aoc_2023_13),
+        day!(2023, 14, "2023/14", aoc_2023_14),
+        day!(2023, 15, "2023/15", aoc_2023_15),
+        day!(2023, 16, "2023/16", aoc_2023_16),
+        day!(2023, 17, "2023/17", aoc_2023_17),
+        day!(2023, 18, "2023/18", aoc_2023_18),
+        day!(2023, 19, "2023/19", aoc_2023_19),
+        day!(2023, 20, "2023/20", aoc_2023_20),
+        day!(
+            2023,
+            21,
+            "2023/21",
+            |input| aoc_2023_21::get_part_one(input, 64),
+            |input| aoc_2023_21::get_part_two(input, 26_501_365),
+        ),
+        day!(2023, 22, "2023/22", aoc_2023_22),
+        day!(2023, 23, "2023/23", aoc_2023_23),
+        #[cfg(feature = "lapack")]
+        day!(
+            2023,
+            24,
+            "2023/24",
+            |input| aoc_2023_24::get_part_one(input, 200_000_000_000_000.0, 400_000_000_000_000.0),
+            aoc_2023_24::get_part_two,
+        ),
+        day!(2023, 25, "2023/25", aoc_2023_25::get_part_one),
+        day!(2025, 1, "2025/01", aoc_2025_01),
+        day!(2025, 2, "2025/02", aoc_2025_02),
+        day!(2025, 3, "2025/03", aoc_2025_03),
+        day!(2025, 4, "2025/04", aoc_2025_04),
+        day!(2025, 5, "2025/05", aoc_2025_05),
+        day!(2025, 6, "2025/06", aoc_2025_06),
+        day!(2025, 7, "2025/07", aoc_2025_07),
+        day!(
+            2025,
+            8,
+            "2025/08",
+            |input| aoc_2025_08::get_part_one(input, 1_000),
+            aoc_2025_08::get_part_two,
+        ),
+        day!(2025, 9, "2025/09", aoc_2025_09),
+        #[cfg(feature = "z3")]
+        day!(2025, 10, "2025/10", aoc_2025_10),
+        day!(2025, 11, "2025/11", aoc_2025_11),
+        day!(2025, 12, "2025/12", aoc_2025_12::get_part_one),
+    ]
+}
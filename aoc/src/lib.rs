@@ -0,0 +1,182 @@
+#![deny(clippy::expect_used, clippy::unwrap_used)]
+
+use std::any::Any;
+use std::fmt::Display;
+use std::panic;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+mod answers;
+mod days;
+
+pub use answers::{Answers, Verdict};
+pub use days::get_days;
+
+/// Converts whatever a day's entry point returns into a printable answer.
+pub trait Answer {
+    fn into_answer(self) -> Result<String, String>;
+}
+
+macro_rules! impl_answer {
+    ($($t:ty),*) => {
+        $(
+            impl Answer for $t {
+                fn into_answer(self) -> Result<String, String> {
+                    Ok(self.to_string())
+                }
+            }
+        )*
+    };
+}
+
+impl_answer!(u16, u32, u64, usize, i16, i32, i64, i128, isize, String);
+
+impl<T: Answer, E: Display> Answer for Result<T, E> {
+    fn into_answer(self) -> Result<String, String> {
+        self.map_err(|error| error.to_string())
+            .and_then(Answer::into_answer)
+    }
+}
+
+pub type Solver = fn(&str) -> Result<String, String>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl TryFrom<u8> for Part {
+    type Error = String;
+
+    fn try_from(part: u8) -> Result<Self, Self::Error> {
+        match part {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err(format!("There is no part {}", part)),
+        }
+    }
+}
+
+pub struct Solution {
+    pub answer: Result<String, String>,
+    pub elapsed: Duration,
+}
+
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub directory: &'static str,
+    pub part_one: Solver,
+    pub part_two: Option<Solver>,
+}
+
+impl Day {
+    /// The `input.txt` alongside the day's crate.
+    pub fn default_input(&self) -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join(self.directory)
+            .join("input.txt")
+    }
+
+    /// Times a single part against `input`, turning any panic into an
+    /// error so that one broken day doesn't stop a whole year.
+    pub fn solve(&self, part: Part, input: &str) -> Option<Solution> {
+        let solver = match part {
+            Part::One => self.part_one,
+            Part::Two => self.part_two?,
+        };
+
+        let start = Instant::now();
+        let answer = panic::catch_unwind(|| solver(input))
+            .unwrap_or_else(|payload| Err(get_panic_message(payload)));
+
+        Some(Solution {
+            answer,
+            elapsed: start.elapsed(),
+        })
+    }
+}
+
+fn get_panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => "Panicked".to_owned(),
+        },
+    }
+}
+
+/// Every registered day for `year` (or every year), optionally narrowed
+/// down to a single `day`.
+pub fn get_selected_days(year: Option<u16>, day: Option<u8>) -> Vec<Day> {
+    get_days()
+        .into_iter()
+        .filter(|d| year.is_none_or(|year| d.year == year))
+        .filter(|d| day.is_none_or(|day| d.day == day))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answer() {
+        assert_eq!(Ok("42".to_owned()), 42_usize.into_answer());
+        assert_eq!(Ok("-1".to_owned()), Ok::<isize, String>(-1).into_answer());
+        assert_eq!(
+            Err("Invalid input".to_owned()),
+            Err::<usize, &str>("Invalid input").into_answer(),
+        );
+    }
+
+    #[test]
+    fn test_selected_days() {
+        assert_eq!(25, get_selected_days(Some(2015), None).len());
+        assert_eq!(25, get_selected_days(Some(2016), None).len());
+        assert_eq!(25, get_selected_days(Some(2017), None).len());
+        assert_eq!(22, get_selected_days(Some(2018), None).len());
+        assert_eq!(25, get_selected_days(Some(2019), None).len());
+        assert_eq!(25, get_selected_days(Some(2020), None).len());
+        assert_eq!(25, get_selected_days(Some(2021), None).len());
+        assert_eq!(25, get_selected_days(Some(2022), None).len());
+        assert_eq!(
+            24 + usize::from(cfg!(feature = "lapack")),
+            get_selected_days(Some(2023), None).len(),
+        );
+        assert_eq!(
+            11 + usize::from(cfg!(feature = "z3")),
+            get_selected_days(Some(2025), None).len(),
+        );
+        assert_eq!(
+            vec![(2022, 10)],
+            get_selected_days(Some(2022), Some(10))
+                .iter()
+                .map(|day| (day.year, day.day))
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    #[test]
+    fn test_solve() {
+        let day = Day {
+            year: 2015,
+            day: 1,
+            directory: "2015/01",
+            part_one: |input| Ok(input.len().to_string()),
+            part_two: Some(|_| panic!("Oops!")),
+        };
+
+        assert_eq!(
+            Some(Ok("3".to_owned())),
+            day.solve(Part::One, "abc").map(|solution| solution.answer),
+        );
+        assert_eq!(
+            Some(Err("Oops!".to_owned())),
+            day.solve(Part::Two, "abc").map(|solution| solution.answer),
+        );
+    }
+}
@@ -0,0 +1,170 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use argh::FromArgs;
+
+use ::aoc::*;
+
+#[derive(FromArgs)]
+#[argh(description = "Advent of Code")]
+struct Args {
+    #[argh(subcommand)]
+    command: Command,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand)]
+enum Command {
+    Run(Run),
+}
+
+#[derive(FromArgs)]
+#[argh(
+    subcommand,
+    name = "run",
+    description = "run a single day, a whole year or every year"
+)]
+struct Run {
+    #[argh(
+        positional,
+        arg_name = "year [day]",
+        description = "year and day to run; every year if omitted, the whole year if no day"
+    )]
+    selection: Vec<u16>,
+    #[argh(option, short = 'p', description = "only run this part (1 or 2)")]
+    part: Option<u8>,
+    #[argh(
+        option,
+        short = 'i',
+        description = "path to the puzzle input; defaults to the day's input.txt"
+    )]
+    input: Option<PathBuf>,
+    #[argh(
+        switch,
+        short = 'c',
+        description = "compare each answer against the one recorded in answers/"
+    )]
+    check: bool,
+    #[argh(
+        switch,
+        short = 'r',
+        description = "record each answer in answers/, replacing any already there"
+    )]
+    record: bool,
+}
+
+fn run(args: Run) -> Result<bool, String> {
+    let parts = match args.part {
+        Some(part) => vec![Part::try_from(part)?],
+        None => vec![Part::One, Part::Two],
+    };
+
+    let (year, day) = match args.selection[..] {
+        [] => (None, None),
+        [year] => (Some(year), None),
+        [year, day] => (
+            Some(year),
+            Some(u8::try_from(day).map_err(|_| format!("There is no day {}", day))?),
+        ),
+        _ => return Err("Expected at most a year and a day".to_owned()),
+    };
+
+    let days = get_selected_days(year, day);
+    if days.is_empty() {
+        return Err("No matching days found".to_owned());
+    }
+    if args.input.is_some() && days.len() > 1 {
+        return Err("--input can only be used when running a single day".to_owned());
+    }
+    if args.check && args.record {
+        return Err("--check and --record can't be used together".to_owned());
+    }
+
+    let mut answers = BTreeMap::new();
+    let mut succeeded = true;
+    for day in days {
+        println!("{} day {}", day.year, day.day);
+
+        if (args.check || args.record) && !answers.contains_key(&day.year) {
+            answers.insert(day.year, Answers::load(day.year)?);
+        }
+
+        let path = args.input.clone().unwrap_or_else(|| day.default_input());
+        let input = match fs::read_to_string(&path) {
+            Ok(input) => input,
+            Err(error) => {
+                eprintln!("  Error reading {}: {}", path.display(), error);
+                succeeded = false;
+                continue;
+            }
+        };
+
+        for part in parts.iter() {
+            let solution = match day.solve(*part, &input) {
+                Some(solution) => solution,
+                None => continue,
+            };
+            let label = match part {
+                Part::One => "Part one",
+                Part::Two => "Part two",
+            };
+
+            match &solution.answer {
+                Ok(answer) if answer.contains('\n') => {
+                    println!("  {} ({:?}):\n{}", label, solution.elapsed, answer)
+                }
+                Ok(answer) => println!("  {}: {} ({:?})", label, answer, solution.elapsed),
+                Err(error) => {
+                    eprintln!("  {} failed: {} ({:?})", label, error, solution.elapsed);
+                    succeeded = false;
+                }
+            }
+
+            let Some(answers) = answers.get_mut(&day.year) else {
+                continue;
+            };
+            if args.record {
+                if let Ok(answer) = solution.answer {
+                    answers.set(day.day, *part, answer);
+                }
+                continue;
+            }
+            match answers.check(day.day, *part, &solution.answer) {
+                Verdict::Incorrect { expected } => {
+                    eprintln!("    Expected: {}", expected);
+                    succeeded = false;
+                }
+                Verdict::Unrecorded => println!("    No answer recorded"),
+                Verdict::Correct | Verdict::Failed(_) => {}
+            }
+        }
+    }
+
+    if args.record {
+        for (year, answers) in answers {
+            answers.save(year)?;
+            println!("Recorded answers in {}", Answers::path(year).display());
+        }
+    }
+
+    Ok(succeeded)
+}
+
+fn main() -> ExitCode {
+    let args: Args = argh::from_env();
+
+    let result = match args.command {
+        Command::Run(args) => run(args),
+    };
+
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(error) => {
+            eprintln!("{}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use std::collections::BTreeSet;
+use std::fs;
+
+use ::aoc::*;
+
+/// Runs every day that has both a local `input.txt` and recorded answers,
+/// and fails on any answer that's changed. Slow, hence ignored by default:
+///
+/// ```sh
+/// cargo test --release -p aoc -- --ignored
+/// ```
+#[test]
+#[ignore]
+fn test_recorded_answers() {
+    let years = get_days()
+        .iter()
+        .map(|day| day.year)
+        .collect::<BTreeSet<_>>();
+    let mut failures = Vec::new();
+
+    for year in years {
+        let answers = match Answers::load(year) {
+            Ok(answers) if answers.is_empty() => continue,
+            Ok(answers) => answers,
+            Err(error) => {
+                failures.push(error);
+                continue;
+            }
+        };
+
+        for day in get_selected_days(Some(year), None) {
+            let Ok(input) = fs::read_to_string(day.default_input()) else {
+                continue;
+            };
+
+            for part in [Part::One, Part::Two] {
+                if answers.get(day.day, part).is_none() {
+                    continue;
+                }
+                let Some(solution) = day.solve(part, &input) else {
+                    continue;
+                };
+
+                match answers.check(day.day, part, &solution.answer) {
+                    Verdict::Correct | Verdict::Unrecorded => {}
+                    Verdict::Incorrect { expected } => failures.push(format!(
+                        "{} day {} {:?}: expected {}, got {}",
+                        day.year,
+                        day.day,
+                        part,
+                        expected,
+                        solution.answer.unwrap_or_default(),
+                    )),
+                    Verdict::Failed(error) => failures.push(format!(
+                        "{} day {} {:?}: {}",
+                        day.year, day.day, part, error
+                    )),
+                }
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}